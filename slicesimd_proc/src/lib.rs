@@ -1,19 +1,46 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
-    parse_macro_input, parse_quote, punctuated::Punctuated, FnArg, PatType, Receiver, TraitItem,
-    TraitItemConst, TraitItemMethod, TraitItemType,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote, parse_quote_spanned, punctuated::Punctuated, FnArg, Ident, Pat, PatType, Path, Receiver, ReturnType, Token,
+    TraitItem, TraitItemConst, TraitItemMethod, TraitItemType,
 };
 
+/// Arguments of `simd_trait`
+struct Args {
+    /// Path to the `slicesimd` crate, `::slicesimd` by default
+    krate: Path,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self {
+                krate: parse_quote! { ::slicesimd },
+            });
+        }
+
+        input.parse::<Token![crate]>()?;
+        input.parse::<Token![=]>()?;
+        let krate = input.parse()?;
+        return Ok(Self { krate })
+    }
+}
+
 #[proc_macro_attribute]
 pub fn simd_trait(
-    _attrs: proc_macro::TokenStream,
+    attrs: proc_macro::TokenStream,
     items: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    let Args { krate } = parse_macro_input!(attrs as Args);
     let mut items = parse_macro_input!(items as syn::ItemTrait);
+    items.items = match expand_kernels(&krate, items.items) {
+        Ok(items) => items,
+        Err(e) => return e.into_compile_error().into(),
+    };
     items
         .supertraits
-        .push(parse_quote! { crate::sealed::Slice });
+        .push(parse_quote! { #krate::__private::Slice });
     if items.colon_token.is_none() {
         items.colon_token = Some(Default::default());
     }
@@ -21,26 +48,285 @@ pub fn simd_trait(
     let mut simd_items = items.clone();
     simd_items.ident = format_ident!("Simd{}", items.ident);
 
+    // Tells the SIMD implementations apart from the scalar ones, for `kernels`
+    items.items.push(parse_quote! {
+        #[doc(hidden)]
+        const SIMD: bool = false;
+    });
+
+    // Keeps a copy of every default body, for when the SIMD kernels are ruled out at runtime
+    let scalar_items = simd_items
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(item) if item.default.is_some() => Some(scalar_method(item.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    items.items.extend(scalar_items);
+
     let ident = &items.ident;
     let simd_ident = &simd_items.ident;
 
-    let impls = items
+    // Generic traits get a blanket impl for every instantiation of their SIMD counterpart
+    let (_, ty_generics, where_clause) = items.generics.split_for_impl();
+    let simd_path = quote! { #simd_ident #ty_generics };
+    let mut impl_generics = items.generics.clone();
+    impl_generics
+        .params
+        .push(parse_quote! { __Slice: ?Sized + #simd_path });
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+
+    let trait_path = quote! { #ident #ty_generics };
+    let impls = simd_items
         .items
         .iter()
         .cloned()
-        .map(|item| adapt_trait_item(&simd_ident, item));
+        .map(|item| adapt_trait_item(&krate, &trait_path, &simd_path, item));
 
     return quote! {
         #items
         #simd_items
 
-        impl<T: ?Sized + #simd_ident> #ident for T {
+        impl #impl_generics #ident #ty_generics for __Slice #where_clause {
+            const SIMD: bool = true;
             #(#impls)*
         }
     }
     .into();
 }
 
+/// Check a kernel is wrapped in, as listed in `#[binary(..)]` or `#[unary(..)]`
+enum Check {
+    /// `rhs`: the slice must be as long as `self`
+    Len(Ident),
+    /// `covers(mask)`, `pattern(rhs)`, ...: calls the function of the same name in `__private`, after `self.len()`
+    Call(Ident, Vec<Ident>),
+}
+
+impl Parse for Check {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        if !input.peek(syn::token::Paren) {
+            return Ok(Self::Len(ident));
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+        let args = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+        return Ok(Self::Call(ident, args.into_iter().collect()))
+    }
+}
+
+impl Check {
+    fn tokens(&self, krate: &Path) -> syn::Result<TokenStream> {
+        return Ok(match self {
+            Self::Len(arg) => quote! { #krate::__private::same_len(self.len(), #arg.len())?; },
+            Self::Call(check, args) => {
+                // Fails here, instead of with an unresolved path in the expansion
+                self.safety(krate)?;
+                quote! { #krate::__private::#check(self.len(), #(#args),*)?; }
+            }
+        })
+    }
+
+    /// Describes what the check guarantees, for the `# Safety` section of the unchecked variant
+    fn safety(&self, krate: &Path) -> syn::Result<String> {
+        let (check, args) = match self {
+            Self::Len(arg) => return Ok(format!("`{}` must be at least as long as `self`", arg)),
+            Self::Call(check, args) => (check, args),
+        };
+
+        let krate = krate.to_token_stream().to_string().replace(' ', "");
+        let krate = krate.trim_start_matches("::");
+        return Ok(match (check.to_string().as_str(), args.as_slice()) {
+            ("covers", [mask]) => format!("`{}` must cover the slice", mask),
+            ("words", [out]) => format!("`{}` must hold at least [`words_for(self.len())`]({}::mask::words_for) words", out, krate),
+            ("pattern", [rhs]) => format!("`{}` mustn't be empty, and it's length must evenly divide the slice's", rhs),
+            ("each", [sources]) => format!("every slice in `{}` must be at least as long as `self`", sources),
+            ("nonempty", [sources]) => format!("`{}` mustn't be empty", sources),
+            ("bounds", [lo, hi]) => format!("`{}` must not be greater than `{}`, and neither of them may be NaN", lo, hi),
+            _ => return Err(syn::Error::new_spanned(check, "Unknown check, or wrong number of arguments")),
+        })
+    }
+}
+
+/// Checks a kernel is wrapped in, picked with `#[binary]` or `#[unary]`.
+///
+/// Both check that their first argument (`rhs` or `out`) is as long as the slice, unless they list their own checks.
+struct Kernel {
+    checks: Option<Vec<Check>>,
+}
+
+impl Kernel {
+    fn take(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Option<Self>> {
+        let mut kernel = None;
+        for attr in core::mem::take(attrs) {
+            if !attr.path.is_ident("binary") && !attr.path.is_ident("unary") {
+                attrs.push(attr);
+                continue;
+            }
+
+            let checks = match attr.tokens.is_empty() {
+                true => None,
+                false => Some(attr.parse_args_with(Punctuated::<Check, Token![,]>::parse_terminated)?.into_iter().collect()),
+            };
+            if kernel.replace(Self { checks }).is_some() {
+                return Err(syn::Error::new_spanned(attr, "Duplicate kernel attribute"));
+            }
+        }
+        return Ok(kernel)
+    }
+}
+
+/// Replaces every `#[binary]` or `#[unary]` kernel with the panicking, `_checked`, `try_` and `_unchecked` variants
+fn expand_kernels(krate: &Path, items: Vec<TraitItem>) -> syn::Result<Vec<TraitItem>> {
+    let mut expanded = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            TraitItem::Method(mut item) => match Kernel::take(&mut item.attrs)? {
+                Some(kernel) => expanded.extend(expand_kernel(krate, kernel, item)?),
+                None => expanded.push(TraitItem::Method(item)),
+            },
+            other => expanded.push(other),
+        }
+    }
+    return Ok(expanded)
+}
+
+/// Joins the descriptions of every check into a sentence, merging the length checks into a single clause
+fn safety(krate: &Path, checks: &[Check]) -> syn::Result<String> {
+    let lens = checks
+        .iter()
+        .filter_map(|check| match check {
+            Check::Len(arg) => Some(format!("`{}`", arg)),
+            Check::Call(..) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut clauses = Vec::with_capacity(checks.len());
+    if let Some((last, init)) = lens.split_last() {
+        let subject = match init.is_empty() {
+            true => last.clone(),
+            false => format!("{} and {}", init.join(", "), last),
+        };
+        clauses.push(format!("{} must be at least as long as `self`", subject));
+    }
+    for check in checks.iter().filter(|check| matches!(check, Check::Call(..))) {
+        clauses.push(check.safety(krate)?);
+    }
+
+    let mut sentence = match clauses.split_last() {
+        Some((last, init)) if !init.is_empty() => format!("{}, and {}.", init.join(", "), last),
+        _ => format!("{}.", clauses.concat()),
+    };
+    if let Some(first) = sentence.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    return Ok(format!(" {}", sentence))
+}
+
+fn expand_kernel(krate: &Path, kernel: Kernel, mut item: TraitItemMethod) -> syn::Result<[TraitItem; 4]> {
+    if item.sig.unsafety.is_none() {
+        return Err(syn::Error::new_spanned(&item.sig, "Kernels must be `unsafe fn`"));
+    }
+    if !matches!(item.sig.output, ReturnType::Default) {
+        return Err(syn::Error::new_spanned(&item.sig.output, "Kernels can't return anything"));
+    }
+    let unchecked = item.sig.ident.clone();
+    let name = match unchecked.to_string().strip_suffix("_unchecked") {
+        Some(name) => format_ident!("{}", name, span = unchecked.span()),
+        None => return Err(syn::Error::new_spanned(&unchecked, "Kernel names must end in `_unchecked`")),
+    };
+
+    let mut inputs = item.sig.inputs.iter();
+    if !matches!(inputs.next(), Some(FnArg::Receiver(_))) {
+        return Err(syn::Error::new_spanned(&item.sig, "Kernels must take `self`"));
+    }
+    let args = inputs
+        .map(|arg| match arg {
+            FnArg::Typed(PatType { pat, .. }) => match &**pat {
+                Pat::Ident(pat) => Ok(pat.ident.clone()),
+                other => Err(syn::Error::new_spanned(other, "Kernel arguments must be plain identifiers")),
+            },
+            FnArg::Receiver(other) => Err(syn::Error::new_spanned(other, "Unexpected receiver")),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let checks = match kernel.checks {
+        Some(checks) if !checks.is_empty() => checks,
+        Some(_) => return Err(syn::Error::new_spanned(&item.sig, "Kernels must check at least one argument")),
+        None => match args.first() {
+            Some(arg) => vec![Check::Len(arg.clone())],
+            None => return Err(syn::Error::new_spanned(&item.sig, "Kernels must take the slice to check against `self`")),
+        },
+    };
+    for check in &checks {
+        let used = match check {
+            Check::Len(arg) => core::slice::from_ref(arg),
+            Check::Call(_, used) => used.as_slice(),
+        };
+        if let Some(arg) = used.iter().find(|arg| !args.contains(arg)) {
+            return Err(syn::Error::new_spanned(arg, "Not an argument of the kernel"));
+        }
+    }
+    let check_tokens = checks
+        .iter()
+        .map(|check| check.tokens(krate))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // The documentation describes the panicking variant, while the rest of the attributes apply to every variant
+    let (docs, attrs): (Vec<_>, Vec<_>) = item.attrs.drain(..).partition(|attr| attr.path.is_ident("doc"));
+    let safety = safety(krate, &checks)?;
+
+    let mut sig = item.sig.clone();
+    sig.unsafety = None;
+    sig.ident = name.clone();
+
+    let mut checked_sig = sig.clone();
+    checked_sig.ident = format_ident!("{}_checked", name);
+    checked_sig.output = parse_quote! { -> bool };
+
+    let mut try_sig = sig.clone();
+    try_sig.ident = format_ident!("try_{}", name);
+    try_sig.output = parse_quote! { -> ::core::result::Result<(), #krate::Error> };
+    let try_ident = &try_sig.ident;
+
+    // Spanned like the kernel, or clippy skips it as macro-generated documentation
+    let span = unchecked.span();
+    item.attrs = attrs.clone();
+    item.attrs.push(parse_quote_spanned! {span=> #[doc = " # Safety"] });
+    item.attrs.push(parse_quote_spanned! {span=> #[doc = #safety] });
+
+    return Ok([
+        parse_quote! {
+            #(#docs)*
+            #(#attrs)*
+            #[track_caller]
+            #sig {
+                if let ::core::result::Result::Err(e) = self.#try_ident(#(#args),*) {
+                    ::core::panic!("{e}")
+                }
+            }
+        },
+        parse_quote! {
+            #(#attrs)*
+            #checked_sig {
+                return self.#try_ident(#(#args),*).is_ok()
+            }
+        },
+        parse_quote! {
+            #(#attrs)*
+            #try_sig {
+                #(#check_tokens)*
+                unsafe { self.#unchecked(#(#args),*) };
+                return ::core::result::Result::Ok(())
+            }
+        },
+        TraitItem::Method(item),
+    ])
+}
+
 fn adapt_default_trait_item(item: &mut TraitItem) -> TokenStream {
     match item {
         TraitItem::Method(item) => {
@@ -52,10 +338,15 @@ fn adapt_default_trait_item(item: &mut TraitItem) -> TokenStream {
     }
 }
 
-fn adapt_trait_item(name: &Ident, item: TraitItem) -> TokenStream {
+fn adapt_trait_item(
+    krate: &Path,
+    trait_path: &TokenStream,
+    name: &TokenStream,
+    item: TraitItem,
+) -> TokenStream {
     match item {
         TraitItem::Const(item) => adapt_trait_const(name, item),
-        TraitItem::Method(item) => adapt_trait_method(name, item),
+        TraitItem::Method(item) => adapt_trait_method(krate, trait_path, name, item),
         TraitItem::Type(item) => adapt_trait_type(name, item),
         TraitItem::Macro(item) => item.mac.to_token_stream(),
         TraitItem::Verbatim(item) => item,
@@ -63,26 +354,59 @@ fn adapt_trait_item(name: &Ident, item: TraitItem) -> TokenStream {
     }
 }
 
-fn adapt_trait_const(name: &Ident, mut item: TraitItemConst) -> TokenStream {
+fn adapt_trait_const(name: &TokenStream, mut item: TraitItemConst) -> TokenStream {
     let ident = &item.ident;
     item.default = Some((Default::default(), parse_quote! { <Self as #name>::#ident }));
     item.into_token_stream()
 }
 
-fn adapt_trait_method(name: &Ident, mut item: TraitItemMethod) -> TokenStream {
+fn adapt_trait_method(
+    krate: &Path,
+    trait_path: &TokenStream,
+    name: &TokenStream,
+    mut item: TraitItemMethod,
+) -> TokenStream {
     let ident = &item.sig.ident;
-    let inputs = item.sig.inputs.iter().map(|x| match x {
-        FnArg::Receiver(Receiver {
-            attrs, self_token, ..
-        }) => quote! { #(#attrs)* #self_token },
-        FnArg::Typed(PatType { attrs, pat, .. }) => quote! { #(#attrs)* #pat },
-    });
+    let inputs = item
+        .sig
+        .inputs
+        .iter()
+        .map(|x| match x {
+            FnArg::Receiver(Receiver {
+                attrs, self_token, ..
+            }) => quote! { #(#attrs)* #self_token },
+            FnArg::Typed(PatType { attrs, pat, .. }) => quote! { #(#attrs)* #pat },
+        })
+        .collect::<Vec<_>>();
 
-    item.default = Some(parse_quote! {{ <Self as #name>::#ident(#(#inputs),*) }});
+    item.default = Some(match item.default {
+        Some(_) => {
+            let scalar = scalar_ident(ident);
+            parse_quote! {{
+                if #krate::__private::is_scalar() {
+                    return <Self as #trait_path>::#scalar(#(#inputs),*)
+                }
+                <Self as #name>::#ident(#(#inputs),*)
+            }}
+        }
+        None => parse_quote! {{ <Self as #name>::#ident(#(#inputs),*) }},
+    });
     item.into_token_stream()
 }
 
-fn adapt_trait_type(name: &Ident, mut item: TraitItemType) -> TokenStream {
+/// Hidden copy of a default method, called by the SIMD implementations when capped to scalar code
+fn scalar_method(mut item: TraitItemMethod) -> TraitItem {
+    item.attrs.retain(|attr| !attr.path.is_ident("doc"));
+    item.attrs.push(parse_quote! { #[doc(hidden)] });
+    item.sig.ident = scalar_ident(&item.sig.ident);
+    return TraitItem::Method(item)
+}
+
+fn scalar_ident(ident: &Ident) -> Ident {
+    return format_ident!("__scalar_{}", ident)
+}
+
+fn adapt_trait_type(name: &TokenStream, mut item: TraitItemType) -> TokenStream {
     let ident = &item.ident;
     item.colon_token = None;
     item.bounds = Punctuated::new();
@@ -0,0 +1,205 @@
+//! Element types, for writing code that is generic over them.
+//!
+//! Bounds on `[T]` can't be implied by a bound on `T`, so every element type names it's slice type through
+//! [`SimdElement::Slice`], which is bound by the traits shared by every element of it's kind (with [`SimdSigned`],
+//! [`SimdFloat`] and [`SimdInt`] adding to them). [`SimdElement::slice`] and [`SimdElement::slice_mut`] then give access to them.
+//! The one trait left out is [`HorizontalSlice`] on `[i32]`, see [`SimdInt`].
+//!
+//! # Example
+//! ```rust
+//! use slicesimd::*;
+//!
+//! /// Maps `[lo, hi]` onto `[0, 1]`, clamping the values outside of it
+//! fn rescale<T: SimdFloat> (values: &mut [T], lo: T, hi: T) {
+//!     let scale = T::ONE / (hi - lo);
+//!     let values = T::slice_mut(values);
+//!     values.affine_assign(scale, T::ZERO - lo * scale);
+//!     values.clamp_assign(T::ZERO, T::ONE);
+//! }
+//!
+//! let mut single = [1.0f32, 2.0, 3.0, 4.0];
+//! rescale(&mut single, 1.0, 3.0);
+//! assert_eq!(single, [0.0, 0.5, 1.0, 1.0]);
+//!
+//! let mut double = [1.0f64, 2.0, 3.0, 4.0];
+//! rescale(&mut double, 1.0, 3.0);
+//! assert_eq!(double, [0.0, 0.5, 1.0, 1.0]);
+//! ```
+
+use core::fmt::Debug;
+use bytemuck::Pod;
+use num_traits::{NumOps, NumAssignOps};
+#[cfg(feature = "half")]
+use half::{f16, bf16};
+#[allow(unused_imports)]
+use crate::kernel::Element;
+use crate::sealed::{Slice, Sealed};
+use crate::*;
+
+/// Defines a trait that stands for a set of bounds, implemented by every type that meets them.
+macro_rules! bounds {
+    ($(#[$attr:meta])* $name:ident: $($bound:tt)+) => {
+        $(#[$attr])*
+        #[doc(hidden)]
+        pub trait $name: $($bound)+ {}
+        impl<S: ?Sized + $($bound)+> $name for S {}
+    };
+}
+
+#[cfg(not(feature = "half"))]
+bounds! {
+    /// Conversions to every element type.
+    ConvertSlice:
+        VerticalConvert<i8> + VerticalConvert<u8> + VerticalConvert<i16> + VerticalConvert<u16> +
+        VerticalConvert<i32> + VerticalConvert<u32> + VerticalConvert<i64> + VerticalConvert<u64> +
+        VerticalConvert<f32> + VerticalConvert<f64>
+}
+
+#[cfg(feature = "half")]
+bounds! {
+    /// Conversions to every element type.
+    ConvertSlice:
+        VerticalConvert<i8> + VerticalConvert<u8> + VerticalConvert<i16> + VerticalConvert<u16> +
+        VerticalConvert<i32> + VerticalConvert<u32> + VerticalConvert<i64> + VerticalConvert<u64> +
+        VerticalConvert<f32> + VerticalConvert<f64> + VerticalConvert<f16> + VerticalConvert<bf16>
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+bounds! {
+    /// Floating point operations that need a math library.
+    MathSlice: VerticalSqrt + VerticalRsqrt + VerticalMath
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+bounds! {
+    /// Floating point operations that need a math library.
+    MathSlice: Slice
+}
+
+/// Element types the crate operates on.
+///
+/// The slices of every element type implement the operations that don't depend on the kind of element,
+/// as well as conversions to every other element type. This trait is sealed.
+///
+/// # Example
+/// ```rust
+/// use slicesimd::*;
+///
+/// /// Adds up `values` into `sums`, as long as there's room for them
+/// fn accumulate<T: SimdElement> (sums: &mut [T], values: &[T]) -> bool {
+///     return T::slice_mut(sums).add_assign_checked(T::slice(values))
+/// }
+///
+/// let mut sums = [1u8, 2, 3];
+/// assert!(accumulate(&mut sums, &[1, 1, 1]));
+/// assert!(!accumulate(&mut sums, &[1]));
+/// assert_eq!(sums, [2, 3, 4]);
+///
+/// assert_eq!(i16::LANES_256, 16);
+/// assert_eq!(f64::ONE, 1.0);
+/// ```
+pub trait SimdElement: Sealed + Pod + PartialOrd + Default + Debug + Send + Sync + NumOps + NumAssignOps {
+    /// Slice of this element type, that is `[Self]`.
+    type Slice: ?Sized + Slice<Element = Self>
+        + VerticalAdd + VerticalSub + VerticalMul + VerticalDiv + VerticalAccumulate + VerticalMulAdd
+        + VerticalMin + VerticalMax + VerticalClamp + VerticalCmp + VerticalSelect + ConvertSlice;
+
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Number of elements that fit in a 128-bit register.
+    const LANES_128: usize = 16 / core::mem::size_of::<Self>();
+    /// Number of elements that fit in a 256-bit register.
+    const LANES_256: usize = 32 / core::mem::size_of::<Self>();
+    /// Number of elements that fit in a 512-bit register.
+    const LANES_512: usize = 64 / core::mem::size_of::<Self>();
+    /// Number of elements processed at a time by the widest kernels enabled at compile time.
+    /// On targets without a SIMD backend for the element type, where every kernel is scalar, this is `1`.
+    ///
+    /// Half-precision elements are processed in `f32` registers. See [`width`](crate::width) to narrow the kernels at runtime.
+    const LANES: usize;
+
+    /// Returns `values` as [`Self::Slice`](SimdElement::Slice).
+    fn slice (values: &[Self]) -> &Self::Slice;
+    /// Returns `values` as [`Self::Slice`](SimdElement::Slice).
+    fn slice_mut (values: &mut [Self]) -> &mut Self::Slice;
+}
+
+/// Signed element types, whose slices also implement [`VerticalAbs`] and [`VerticalNeg`].
+pub trait SimdSigned: SimdElement<Slice: VerticalAbs + VerticalNeg> {}
+
+/// Floating point element types, whose slices also implement the floating point operations and [`HorizontalSlice`].
+///
+/// [`VerticalSqrt`], [`VerticalRsqrt`] and [`VerticalMath`] are only implied with the `std` or `libm` features.
+pub trait SimdFloat: SimdSigned<Slice: VerticalMean + VerticalRecip + VerticalSquare + VerticalRound + MathSlice + HorizontalSlice<Scalar = Self>> {}
+
+/// Integer element types, whose slices also implement the bitwise operations.
+///
+/// Unlike [`SimdFloat`], this doesn't imply [`HorizontalSlice`], which only `[i32]` implements among the integer slices.
+/// Generic code that adds up integers horizontally has to name that bound itself.
+///
+/// # Example
+/// ```rust
+/// use slicesimd::*;
+///
+/// /// Scales `values` and adds them up, leaving the sum in the first element
+/// fn scaled_sum<T: SimdInt> (values: &mut [T], scale: T) -> T where T::Slice: HorizontalSlice<Scalar = T> {
+///     let values = T::slice_mut(values);
+///     values.affine_assign(scale, T::ZERO);
+///     return values.reduce_add_in_place()
+/// }
+///
+/// assert_eq!(scaled_sum(&mut [1i32, 2, 3], 2), 12);
+/// ```
+pub trait SimdInt: SimdElement<Slice: VerticalAnd + VerticalOr + VerticalXor + VerticalAndNot + VerticalNot + VerticalShift + VerticalRotate> {}
+
+macro_rules! impl_element {
+    ($($t:ident: $zero:expr, $one:expr, $lanes:expr $(=> $($kind:ident),+)?);+ $(;)?) => {
+        $(
+            impl Sealed for $t {}
+
+            impl SimdElement for $t {
+                type Slice = [$t];
+
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+                const LANES: usize = $lanes;
+
+                #[inline(always)]
+                fn slice (values: &[Self]) -> &Self::Slice {
+                    values
+                }
+
+                #[inline(always)]
+                fn slice_mut (values: &mut [Self]) -> &mut Self::Slice {
+                    values
+                }
+            }
+
+            $($(
+                impl $kind for $t {}
+            )+)?
+        )+
+    };
+}
+
+impl_element! {
+    i8: 0, 1, <i8 as Element>::WIDTH / 8 => SimdSigned, SimdInt;
+    u8: 0, 1, <u8 as Element>::WIDTH / 8 => SimdInt;
+    i16: 0, 1, <i16 as Element>::WIDTH / 16 => SimdSigned, SimdInt;
+    u16: 0, 1, <u16 as Element>::WIDTH / 16 => SimdInt;
+    i32: 0, 1, <i32 as Element>::WIDTH / 32 => SimdSigned, SimdInt;
+    u32: 0, 1, <u32 as Element>::WIDTH / 32 => SimdInt;
+    i64: 0, 1, <i64 as Element>::WIDTH / 64 => SimdSigned, SimdInt;
+    u64: 0, 1, <u64 as Element>::WIDTH / 64 => SimdInt;
+    f32: 0.0, 1.0, <f32 as Element>::WIDTH / 32 => SimdSigned, SimdFloat;
+    f64: 0.0, 1.0, <f64 as Element>::WIDTH / 64 => SimdSigned, SimdFloat
+}
+
+#[cfg(feature = "half")]
+impl_element! {
+    f16: f16::ZERO, f16::ONE, <f32 as SimdElement>::LANES => SimdSigned, SimdFloat;
+    bf16: bf16::ZERO, bf16::ONE, <f32 as SimdElement>::LANES => SimdSigned, SimdFloat
+}
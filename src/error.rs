@@ -0,0 +1,113 @@
+//! Errors reported by the `try_*` methods.
+//!
+//! Every fallible operation comes in four flavours: a panicking one (`add_assign`), a `try_*` one returning
+//! a [`Result`] (`try_add_assign`), a `*_checked` one returning whether it succeeded (`add_assign_checked`),
+//! and an unsafe `*_unchecked` one, which skips the checks altogether (`add_assign_unchecked`).
+//!
+//! # Example
+//! ```rust
+//! use slicesimd::*;
+//!
+//! let mut values = [1, 2, 3];
+//! assert_eq!(values.try_add_assign(&[1, 2]), Err(Error::LengthMismatch { lhs: 3, rhs: 2 }));
+//! assert_eq!(values.try_add_assign(&[1, 2, 3]), Ok(()));
+//! assert_eq!(values, [2, 4, 6]);
+//! ```
+
+use core::{fmt, cmp::Ordering};
+use docfg::docfg;
+use crate::sealed::{Slice, Selector};
+use crate::mask::words_for;
+
+/// Invalid input to one of the crate's operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// Two slices that should have had the same length didn't.
+    LengthMismatch { lhs: usize, rhs: usize },
+    /// An output or scratch buffer is too short: `needed` elements (or mask words) are needed, but only `got` were given.
+    InsufficientSpace { needed: usize, got: usize },
+    /// A repeated pattern of `pattern` elements doesn't evenly divide a slice of `len` elements (or the pattern is empty).
+    UnevenPattern { len: usize, pattern: usize },
+    /// A selection mask doesn't cover the `len` elements of the slice.
+    UncoveredMask { len: usize },
+    /// No slices were given where at least one is needed.
+    NoSources,
+    /// The lower bound of a clamp is greater than the upper one, or either of them is NaN.
+    InvalidBounds
+}
+
+impl fmt::Display for Error {
+    fn fmt (&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { lhs, rhs } => write!(f, "Slice sizes don't match: {lhs} v. {rhs}"),
+            Self::InsufficientSpace { needed, got } => write!(f, "Not enough space: {needed} elements needed, but only {got} given"),
+            Self::UnevenPattern { len, pattern } => write!(f, "Pattern of {pattern} elements doesn't evenly divide the {len} elements of the slice"),
+            Self::UncoveredMask { len } => write!(f, "Mask doesn't cover the {len} elements of the slice"),
+            Self::NoSources => f.write_str("No slices were given"),
+            Self::InvalidBounds => f.write_str("Invalid clamp bounds: lo > hi, or either of them is NaN")
+        }
+    }
+}
+
+#[docfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Returns an error if both lengths are different.
+#[doc(hidden)]
+#[inline]
+pub fn same_len (lhs: usize, rhs: usize) -> Result<(), Error> {
+    if lhs != rhs { return Err(Error::LengthMismatch { lhs, rhs }) }
+    return Ok(())
+}
+
+// The checks below are named after their `#[binary(..)]` and `#[unary(..)]` arguments, and take the length of the slice first
+
+/// Returns an error if `mask` doesn't cover `len` elements.
+#[doc(hidden)]
+#[inline]
+pub fn covers<M: ?Sized + Selector> (len: usize, mask: &M) -> Result<(), Error> {
+    if !mask.covers(len) { return Err(Error::UncoveredMask { len }) }
+    return Ok(())
+}
+
+/// Returns an error if `out` can't hold a mask of `len` elements.
+#[doc(hidden)]
+#[inline]
+pub fn words (len: usize, out: &[u64]) -> Result<(), Error> {
+    let needed = words_for(len);
+    if out.len() < needed { return Err(Error::InsufficientSpace { needed, got: out.len() }) }
+    return Ok(())
+}
+
+/// Returns an error if `pattern` can't be repeated to cover exactly `len` elements.
+#[doc(hidden)]
+#[inline]
+pub fn pattern<S: ?Sized + Slice> (len: usize, pattern: &S) -> Result<(), Error> {
+    if pattern.len() == 0 || !len.is_multiple_of(pattern.len()) { return Err(Error::UnevenPattern { len, pattern: pattern.len() }) }
+    return Ok(())
+}
+
+/// Returns an error if any of `sources` isn't `len` elements long.
+#[doc(hidden)]
+#[inline]
+pub fn each<S: ?Sized + Slice> (len: usize, sources: &[&S]) -> Result<(), Error> {
+    for source in sources { same_len(len, source.len())? }
+    return Ok(())
+}
+
+/// Returns an error if `sources` is empty.
+#[doc(hidden)]
+#[inline]
+pub fn nonempty<T> (_len: usize, sources: &[T]) -> Result<(), Error> {
+    if sources.is_empty() { return Err(Error::NoSources) }
+    return Ok(())
+}
+
+/// Returns an error if `lo > hi`, or if either of them is NaN.
+#[doc(hidden)]
+#[inline]
+pub fn bounds<T: PartialOrd> (_len: usize, lo: T, hi: T) -> Result<(), Error> {
+    if !matches!(lo.partial_cmp(&hi), Some(Ordering::Less | Ordering::Equal)) { return Err(Error::InvalidBounds) }
+    return Ok(())
+}
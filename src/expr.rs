@@ -0,0 +1,322 @@
+//! Lazy element-wise expressions, evaluated in a single fused pass.
+//!
+//! Every vertical operation makes a full pass over memory, so formulas made of several of them end up memory-bound.
+//! Expressions instead build a tree of operations, which [`Expr::eval_into`] evaluates one register at a time,
+//! without intermediate results ever leaving the registers.
+//!
+//! Expressions are started with [`expr`], from a slice (or a reference to an array or vector), and combined
+//! with the arithmetic operators, taking other expressions, slices and scalars as operands.
+//! They're available for `f32` and `f64` elements.
+//!
+//! # Example
+//! ```rust
+//! use core::mem::MaybeUninit;
+//! use slicesimd::*;
+//!
+//! let a = [1.0f32, 2.0, 3.0, 4.0, 5.0];
+//! let b = [2.0f32, 2.0, 2.0, 2.0, 2.0];
+//! let c = [0.5f32, 1.0, 1.5, 2.0, 2.5];
+//!
+//! let mut out = [MaybeUninit::<f32>::uninit(); 5];
+//! (expr(&a) * &b + expr(&c) * 2.0 - 1.0).eval_into(&mut out);
+//! assert_eq!(out.map(|x| unsafe { x.assume_init() }), [2.0, 5.0, 8.0, 11.0, 14.0]);
+//!
+//! // Expressions can be evaluated more than once
+//! let half = -(expr(&a) / 2.0);
+//! half.eval_into(&mut out);
+//! assert_eq!(out.map(|x| unsafe { x.assume_init() }), [-0.5, -1.0, -1.5, -2.0, -2.5]);
+//! ```
+
+use core::{mem::MaybeUninit, ops::*};
+use crate::sealed::{Lane, Lanes};
+use crate::error::{Error, same_len};
+
+/// Starts an expression from a slice, or a reference to an array or vector.
+#[inline]
+pub fn expr<E: IntoExpr> (value: E) -> E::Expr {
+    value.into_expr()
+}
+
+/// Lazy element-wise expression, over slices of [`Element`](Expr::Element).
+pub trait Expr: Sized {
+    type Element: Lane;
+
+    /// Returns an error unless every slice in the expression has `len` elements.
+    #[doc(hidden)]
+    fn check_len (&self, len: usize) -> Result<(), Error>;
+
+    /// Evaluates the `LANES` elements starting at `offset`.
+    #[doc(hidden)]
+    unsafe fn load<V: Lanes<Self::Element>> (&self, offset: usize) -> V;
+
+    /// Evaluates the `len` elements starting at `offset`, leaving the remaining lanes unspecified.
+    #[doc(hidden)]
+    unsafe fn load_partial<V: Lanes<Self::Element>> (&self, offset: usize, len: usize) -> V;
+
+    /// Evaluates the expression into `out`, in a single pass.
+    ///
+    /// # Panics
+    /// This method panics if any of the slices in the expression has a different length than `out`.
+    #[inline]
+    #[track_caller]
+    fn eval_into (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        if let Err(e) = self.try_eval_into(out) {
+            panic!("{e}")
+        }
+    }
+
+    #[inline]
+    fn eval_into_checked (&self, out: &mut [MaybeUninit<Self::Element>]) -> bool {
+        return self.try_eval_into(out).is_ok()
+    }
+
+    #[inline]
+    fn try_eval_into (&self, out: &mut [MaybeUninit<Self::Element>]) -> Result<(), Error> {
+        self.check_len(out.len())?;
+        unsafe { self.eval_into_unchecked(out) };
+        return Ok(())
+    }
+
+    /// # Safety
+    /// Every slice in the expression must be at least as long as `out`.
+    #[inline]
+    unsafe fn eval_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        <Self::Element as Lane>::eval(self, out)
+    }
+}
+
+/// Evaluates `expr` into `out`, one register of type `V` at a time.
+#[inline(always)]
+pub(crate) unsafe fn eval_lanes<E: Expr, V: Lanes<E::Element>> (expr: &E, out: &mut [MaybeUninit<E::Element>]) {
+    let len = out.len();
+    let ptr = out.as_mut_ptr().cast::<E::Element>();
+
+    let mut i = 0;
+    while i + V::LANES <= len {
+        V::store(expr.load(i), ptr.add(i));
+        i += V::LANES;
+    }
+
+    if i < len {
+        V::store_partial(expr.load_partial(i, len - i), ptr.add(i), len - i);
+    }
+}
+
+/// Values that can be used as operands of an expression.
+pub trait IntoExpr {
+    type Expr: Expr;
+
+    fn into_expr (self) -> Self::Expr;
+}
+
+impl<E: Expr> IntoExpr for E {
+    type Expr = E;
+
+    #[inline]
+    fn into_expr (self) -> E {
+        self
+    }
+}
+
+impl<'a, T: Lane> IntoExpr for &'a [T] {
+    type Expr = SliceExpr<'a, T>;
+
+    #[inline]
+    fn into_expr (self) -> Self::Expr {
+        SliceExpr(self)
+    }
+}
+
+impl<'a, T: Lane, const N: usize> IntoExpr for &'a [T; N] {
+    type Expr = SliceExpr<'a, T>;
+
+    #[inline]
+    fn into_expr (self) -> Self::Expr {
+        SliceExpr(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: Lane> IntoExpr for &'a alloc::vec::Vec<T> {
+    type Expr = SliceExpr<'a, T>;
+
+    #[inline]
+    fn into_expr (self) -> Self::Expr {
+        SliceExpr(self)
+    }
+}
+
+/// Slice operand of an expression.
+#[derive(Debug, Clone, Copy)]
+pub struct SliceExpr<'a, T>(&'a [T]);
+
+/// Scalar operand of an expression, broadcast to every element.
+#[derive(Debug, Clone, Copy)]
+pub struct SplatExpr<T>(T);
+
+/// Negation of an expression.
+#[derive(Debug, Clone, Copy)]
+pub struct NegExpr<E>(E);
+
+impl<T: Lane> Expr for SliceExpr<'_, T> {
+    type Element = T;
+
+    #[inline(always)]
+    fn check_len (&self, len: usize) -> Result<(), Error> {
+        same_len(self.0.len(), len)
+    }
+
+    #[inline(always)]
+    unsafe fn load<V: Lanes<T>> (&self, offset: usize) -> V {
+        V::load(self.0.as_ptr().add(offset))
+    }
+
+    #[inline(always)]
+    unsafe fn load_partial<V: Lanes<T>> (&self, offset: usize, len: usize) -> V {
+        V::load_partial(self.0.as_ptr().add(offset), len)
+    }
+}
+
+impl<T: Lane> Expr for SplatExpr<T> {
+    type Element = T;
+
+    #[inline(always)]
+    fn check_len (&self, _len: usize) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    unsafe fn load<V: Lanes<T>> (&self, _offset: usize) -> V {
+        V::splat(self.0)
+    }
+
+    #[inline(always)]
+    unsafe fn load_partial<V: Lanes<T>> (&self, _offset: usize, _len: usize) -> V {
+        V::splat(self.0)
+    }
+}
+
+impl<E: Expr> Expr for NegExpr<E> {
+    type Element = E::Element;
+
+    #[inline(always)]
+    fn check_len (&self, len: usize) -> Result<(), Error> {
+        self.0.check_len(len)
+    }
+
+    #[inline(always)]
+    unsafe fn load<V: Lanes<E::Element>> (&self, offset: usize) -> V {
+        V::neg(self.0.load(offset))
+    }
+
+    #[inline(always)]
+    unsafe fn load_partial<V: Lanes<E::Element>> (&self, offset: usize, len: usize) -> V {
+        V::neg(self.0.load_partial(offset, len))
+    }
+}
+
+macro_rules! impl_binary {
+    ($($(#[doc = $doc:literal])* $name:ident => $f:ident),+) => {
+        $(
+            $(#[doc = $doc])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name<L, R>(L, R);
+
+            impl<L: Expr, R: Expr<Element = L::Element>> Expr for $name<L, R> {
+                type Element = L::Element;
+
+                #[inline(always)]
+                fn check_len (&self, len: usize) -> Result<(), Error> {
+                    self.0.check_len(len)?;
+                    self.1.check_len(len)
+                }
+
+                #[inline(always)]
+                unsafe fn load<V: Lanes<L::Element>> (&self, offset: usize) -> V {
+                    V::$f(self.0.load(offset), self.1.load(offset))
+                }
+
+                #[inline(always)]
+                unsafe fn load_partial<V: Lanes<L::Element>> (&self, offset: usize, len: usize) -> V {
+                    V::$f(self.0.load_partial(offset, len), self.1.load_partial(offset, len))
+                }
+            }
+        )+
+    };
+}
+
+impl_binary! {
+    /// Sum of two expressions.
+    AddExpr => add,
+    /// Difference of two expressions.
+    SubExpr => sub,
+    /// Product of two expressions.
+    MulExpr => mul,
+    /// Quotient of two expressions.
+    DivExpr => div
+}
+
+/// Implements the arithmetic operators for an expression node, with expressions, slices and scalars on either side.
+macro_rules! impl_ops {
+    ($(<$($gen:tt),*> $node:ty),+) => {
+        $(
+            impl_ops! { @binary <$($gen),*> $node: Add as add => AddExpr }
+            impl_ops! { @binary <$($gen),*> $node: Sub as sub => SubExpr }
+            impl_ops! { @binary <$($gen),*> $node: Mul as mul => MulExpr }
+            impl_ops! { @binary <$($gen),*> $node: Div as div => DivExpr }
+
+            impl<$($gen),*> Neg for $node where $node: Expr {
+                type Output = NegExpr<Self>;
+
+                #[inline]
+                fn neg (self) -> Self::Output {
+                    NegExpr(self)
+                }
+            }
+        )+
+    };
+
+    (@binary <$($gen:tt),*> $node:ty: $trait:ident as $f:ident => $out:ident) => {
+        impl<$($gen,)* Rhs: IntoExpr> $trait<Rhs> for $node where $node: Expr, Rhs::Expr: Expr<Element = <$node as Expr>::Element> {
+            type Output = $out<Self, Rhs::Expr>;
+
+            #[inline]
+            fn $f (self, rhs: Rhs) -> Self::Output {
+                $out(self, rhs.into_expr())
+            }
+        }
+
+        impl_ops! { @scalar <$($gen),*> $node: $trait as $f => $out; f32 }
+        impl_ops! { @scalar <$($gen),*> $node: $trait as $f => $out; f64 }
+    };
+
+    (@scalar <$($gen:tt),*> $node:ty: $trait:ident as $f:ident => $out:ident; $t:ident) => {
+        impl<$($gen),*> $trait<$t> for $node where $node: Expr<Element = $t> {
+            type Output = $out<Self, SplatExpr<$t>>;
+
+            #[inline]
+            fn $f (self, rhs: $t) -> Self::Output {
+                $out(self, SplatExpr(rhs))
+            }
+        }
+
+        impl<$($gen),*> $trait<$node> for $t where $node: Expr<Element = $t> {
+            type Output = $out<SplatExpr<$t>, $node>;
+
+            #[inline]
+            fn $f (self, rhs: $node) -> Self::Output {
+                $out(SplatExpr(self), rhs)
+            }
+        }
+    };
+}
+
+impl_ops! {
+    <'a, T> SliceExpr<'a, T>,
+    <T> SplatExpr<T>,
+    <E> NegExpr<E>,
+    <L, R> AddExpr<L, R>,
+    <L, R> SubExpr<L, R>,
+    <L, R> MulExpr<L, R>,
+    <L, R> DivExpr<L, R>
+}
@@ -2,7 +2,7 @@ use bytemuck::Pod;
 #[allow(unused_imports)]
 use concat_idents::concat_idents;
 use core::mem::MaybeUninit;
-use docfg::docfg;
+use crate::error::Error;
 
 #[allow(unused_macros)]
 macro_rules! flat_mod {
@@ -15,11 +15,21 @@ macro_rules! flat_mod {
 }
 
 cfg_if::cfg_if! {
-    if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))] {
+    if #[cfg(feature = "portable")] {
+        mod portable;
+        pub(crate) use portable::*;
+    } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))] {
         mod x86;
         pub(crate) use x86::*;
+    } else if #[cfg(all(target_arch = "aarch64", target_feature = "neon"))] {
+        mod neon;
+        pub(crate) use neon::*;
+    } else if #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))] {
+        mod wasm;
+        pub(crate) use wasm::*;
     } else {
-        todo!()
+        mod scalar;
+        pub(crate) use scalar::*;
     }
 }
 
@@ -28,56 +38,48 @@ pub trait HorizontalSlice {
 
     /// Adds up all the values in the slice horizontally.
     ///
-    /// Since this method doesn't have mutable access to it's target, it may use a thread local "compute space"
-    /// to store the temporary results of the operations.
+    /// The partial sums are kept in registers or on the stack, so no temporary storage is allocated.
     ///
     /// If this method is called on a slice of integers (signed or unsigned), the operation will be done with wrapping addition.
     ///
-    /// > # Note
-    /// > When using naive mode, no compute space will be used
-    ///
     /// # Example
     /// ```rust
-    /// use simdslice::*;
+    /// use slicesimd::*;
     ///
     /// let values = [1, 2, 3, 4, 5];
     /// assert_eq!(values.reduce_add(), 15);
     /// ```
-    #[docfg(feature = "std")]
     fn reduce_add(&self) -> Self::Scalar;
 
-    /// Adds up all the values in the slice horizontaly, using `space` to store temporary data.
-    ///
-    /// Since this method doesn't have mutable access to it's target, it may use `space` as a "compute space"
-    /// to store the temporary results of the operations.
-    ///
-    /// If this method is called on a slice of integers (signed or unsigned), the operation will be done with wrapping addition.
-    ///
-    /// > # Note
-    /// > When using naive mode, `space` will be used
+    /// Adds up all the values in the slice horizontally, like [`reduce_add`](HorizontalSlice::reduce_add).
     ///
-    /// # Example
-    /// ```rust
-    /// #![feature(maybe_uninit_uninit_array)]
-    ///
-    /// use slicesimd::*;
-    /// use core::mem::MaybeUninit;
-    ///
-    /// let values = [1, 2, 3, 4, 5];
-    /// let mut blank_space = MaybeUninit::uninit_array();
-    /// let sum = values.reduce_add_in_place(&mut blank_space);
-    ///
-    /// assert_eq!(values[0], sum);
-    /// assert_eq!(sum, 15);
-    /// ```
-    fn reduce_add_in_space(&self, space: &mut [MaybeUninit<Self::Scalar>]) -> Self::Scalar;
+    /// No backend needs to store temporary data anymore, so `space` is ignored.
+    #[deprecated(note = "no backend needs temporary space anymore, use `reduce_add` instead")]
+    #[inline]
+    fn reduce_add_in_space(&self, _space: &mut [MaybeUninit<Self::Scalar>]) -> Self::Scalar {
+        return self.reduce_add()
+    }
 
-    /// Adds up all the values in the slice horizontally, storing temporary data in the same slice.
+    /// Adds up all the values in the slice horizontally, like [`reduce_add`](HorizontalSlice::reduce_add).
     ///
-    /// Since this method has mutable access to it's target, it will use the target itself
-    /// to store the temporary results of the operations.
+    /// No backend needs to store temporary data anymore, so `space` is ignored and this never fails.
+    #[deprecated(note = "no backend needs temporary space anymore, use `reduce_add` instead")]
+    #[inline]
+    fn try_reduce_add_in_space(&self, _space: &mut [MaybeUninit<Self::Scalar>]) -> Result<Self::Scalar, Error> {
+        return Ok(self.reduce_add())
+    }
+
+    /// Returns the minimum length of the `space` given to [`reduce_add_in_space`](HorizontalSlice::reduce_add_in_space),
+    /// which is always `0`.
+    #[deprecated(note = "no backend needs temporary space anymore, use `reduce_add` instead")]
+    #[inline]
+    fn reduce_add_space(&self) -> usize {
+        return 0
+    }
+
+    /// Adds up all the values in the slice horizontally, storing the result in it's first index.
     ///
-    /// The resulting slice will have the result of the operation in it's first index, but the remaining values are undefined.
+    /// The remaining values are left untouched, but that isn't guaranteed, so they should be treated as undefined.
     ///
     /// If this method is called on a slice of integers (signed or unsigned), the operation will be done with wrapping addition.
     ///
@@ -100,7 +102,6 @@ macro_rules! impl_slice_ext {
             impl HorizontalSlice for [$t] {
                 type Scalar = $t;
 
-                #[cfg(feature = "std")]
                 #[inline]
                 fn reduce_add (&self) -> Self::Scalar {
                     concat_idents!(f = reduce_add_, $t {
@@ -108,13 +109,6 @@ macro_rules! impl_slice_ext {
                     })
                 }
 
-                #[inline]
-                fn reduce_add_in_space (&self, space: &mut [MaybeUninit<$t>]) -> Self::Scalar {
-                    concat_idents!(f = reduce_add_, $t, _in_space {
-                        f(self, space)
-                    })
-                }
-
                 #[inline]
                 fn reduce_add_in_place (&mut self) -> Self::Scalar {
                     concat_idents!(f = reduce_add_, $t, _in_place {
@@ -130,3 +124,50 @@ impl_slice_ext! {
     i32,
     f32, f64
 }
+
+// Half-precision sums are accumulated in `f32`, a chunk at a time, and rounded once at the end
+#[cfg(feature = "half")]
+macro_rules! impl_half_slice_ext {
+    ($($t:ident),+) => {
+        $(
+            impl HorizontalSlice for [half::$t] {
+                type Scalar = half::$t;
+
+                #[inline]
+                fn reduce_add (&self) -> Self::Scalar {
+                    half::$t::from_f32(reduce_add_half(self))
+                }
+
+                #[inline]
+                fn reduce_add_in_place (&mut self) -> Self::Scalar {
+                    let sum = half::$t::from_f32(reduce_add_half(self));
+                    if let Some(first) = self.first_mut() {
+                        *first = sum
+                    }
+                    return sum
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half_slice_ext! {
+    f16, bf16
+}
+
+#[cfg(feature = "half")]
+#[inline]
+fn reduce_add_half<T> (iter: &[T]) -> f32 where [T]: crate::VerticalConvert<f32> {
+    const CHUNK: usize = 256;
+    let mut buf = [MaybeUninit::<f32>::uninit(); CHUNK];
+
+    let mut sum = 0.0;
+    for chunk in iter.chunks(CHUNK) {
+        let buf = &mut buf[..chunk.len()];
+        crate::VerticalConvert::convert_into(chunk, buf);
+        // SAFETY: `convert_into` has initialized every element of `buf`
+        sum += unsafe { &mut *(buf as *mut [MaybeUninit<f32>] as *mut [f32]) }.reduce_add_in_place();
+    }
+    return sum
+}
@@ -0,0 +1,59 @@
+use core::arch::aarch64::*;
+#[allow(unused_imports)]
+use docfg::docfg;
+
+macro_rules! impl_reduce_add {
+    (
+        $t:ident as $fn:ident + $compute:ident {
+            $load:ident, $add:ident, $reduce:ident, $scalar_add:expr
+        }
+    ) => {
+        pub fn $fn (iter: &mut [$t]) -> $t {
+            let sum = $compute(iter);
+            if let Some(first) = iter.first_mut() {
+                *first = sum
+            }
+            return sum
+        }
+
+        pub fn $compute (iter: &[$t]) -> $t {
+            if crate::width::is_scalar() {
+                return iter.iter().copied().fold(0 as $t, $scalar_add)
+            }
+
+            const LANES: usize = 16 / core::mem::size_of::<$t>();
+
+            unsafe {
+                let mut chunks = iter.chunks_exact(LANES);
+                let mut sums = match chunks.next() {
+                    Some(chunk) => $load(chunk.as_ptr()),
+                    None => return iter.iter().copied().fold(0 as $t, $scalar_add)
+                };
+
+                for chunk in chunks.by_ref() {
+                    sums = $add(sums, $load(chunk.as_ptr()))
+                }
+
+                return chunks.remainder().iter().copied().fold($reduce(sums), $scalar_add)
+            }
+        }
+    };
+}
+
+impl_reduce_add! {
+    f32 as reduce_add_f32_in_place + reduce_add_f32 {
+        vld1q_f32, vaddq_f32, vaddvq_f32, |x, y| x + y
+    }
+}
+
+impl_reduce_add! {
+    i32 as reduce_add_i32_in_place + reduce_add_i32 {
+        vld1q_s32, vaddq_s32, vaddvq_s32, i32::wrapping_add
+    }
+}
+
+impl_reduce_add! {
+    f64 as reduce_add_f64_in_place + reduce_add_f64 {
+        vld1q_f64, vaddq_f64, vaddvq_f64, |x, y| x + y
+    }
+}
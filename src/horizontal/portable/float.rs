@@ -0,0 +1,57 @@
+use std::simd::{Simd, num::{SimdFloat, SimdInt}};
+#[allow(unused_imports)]
+use docfg::docfg;
+
+macro_rules! impl_reduce_add {
+    (
+        $t:ident as $fn:ident + $compute:ident {
+            $scalar_add:expr
+        }
+    ) => {
+        pub fn $fn (iter: &mut [$t]) -> $t {
+            let sum = $compute(iter);
+            if let Some(first) = iter.first_mut() {
+                *first = sum
+            }
+            return sum
+        }
+
+        pub fn $compute (iter: &[$t]) -> $t {
+            if crate::width::is_scalar() {
+                return iter.iter().copied().fold(0 as $t, $scalar_add)
+            }
+
+            const LANES: usize = 32 / core::mem::size_of::<$t>();
+
+            let mut chunks = iter.chunks_exact(LANES);
+            let mut sums = match chunks.next() {
+                Some(chunk) => Simd::<$t, LANES>::from_slice(chunk),
+                None => return iter.iter().copied().fold(0 as $t, $scalar_add)
+            };
+
+            for chunk in chunks.by_ref() {
+                sums += Simd::<$t, LANES>::from_slice(chunk)
+            }
+
+            return chunks.remainder().iter().copied().fold(sums.reduce_sum(), $scalar_add)
+        }
+    };
+}
+
+impl_reduce_add! {
+    f32 as reduce_add_f32_in_place + reduce_add_f32 {
+        |x, y| x + y
+    }
+}
+
+impl_reduce_add! {
+    i32 as reduce_add_i32_in_place + reduce_add_i32 {
+        i32::wrapping_add
+    }
+}
+
+impl_reduce_add! {
+    f64 as reduce_add_f64_in_place + reduce_add_f64 {
+        |x, y| x + y
+    }
+}
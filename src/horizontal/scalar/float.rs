@@ -0,0 +1,37 @@
+macro_rules! impl_reduce_add {
+    (
+        $t:ident as $fn:ident + $compute:ident {
+            $scalar_add:expr
+        }
+    ) => {
+        pub fn $fn (iter: &mut [$t]) -> $t {
+            let sum = $compute(iter);
+            if let Some(first) = iter.first_mut() {
+                *first = sum
+            }
+            return sum
+        }
+
+        pub fn $compute (iter: &[$t]) -> $t {
+            return iter.iter().copied().fold(0 as $t, $scalar_add)
+        }
+    };
+}
+
+impl_reduce_add! {
+    f32 as reduce_add_f32_in_place + reduce_add_f32 {
+        |x, y| x + y
+    }
+}
+
+impl_reduce_add! {
+    i32 as reduce_add_i32_in_place + reduce_add_i32 {
+        i32::wrapping_add
+    }
+}
+
+impl_reduce_add! {
+    f64 as reduce_add_f64_in_place + reduce_add_f64 {
+        |x, y| x + y
+    }
+}
@@ -0,0 +1,77 @@
+use core::arch::wasm32::*;
+#[allow(unused_imports)]
+use docfg::docfg;
+
+macro_rules! impl_reduce_add {
+    (
+        $t:ident as $fn:ident + $compute:ident {
+            $add:ident, $reduce:ident, $scalar_add:expr
+        }
+    ) => {
+        pub fn $fn (iter: &mut [$t]) -> $t {
+            let sum = $compute(iter);
+            if let Some(first) = iter.first_mut() {
+                *first = sum
+            }
+            return sum
+        }
+
+        pub fn $compute (iter: &[$t]) -> $t {
+            if crate::width::is_scalar() {
+                return iter.iter().copied().fold(0 as $t, $scalar_add)
+            }
+
+            const LANES: usize = 16 / core::mem::size_of::<$t>();
+
+            unsafe {
+                let mut chunks = iter.chunks_exact(LANES);
+                let mut sums = match chunks.next() {
+                    Some(chunk) => v128_load(chunk.as_ptr().cast()),
+                    None => return iter.iter().copied().fold(0 as $t, $scalar_add)
+                };
+
+                for chunk in chunks.by_ref() {
+                    sums = $add(sums, v128_load(chunk.as_ptr().cast()))
+                }
+
+                return chunks.remainder().iter().copied().fold($reduce(sums), $scalar_add)
+            }
+        }
+    };
+}
+
+impl_reduce_add! {
+    f32 as reduce_add_f32_in_place + reduce_add_f32 {
+        f32x4_add, reduce_f32, |x, y| x + y
+    }
+}
+
+impl_reduce_add! {
+    i32 as reduce_add_i32_in_place + reduce_add_i32 {
+        i32x4_add, reduce_i32, i32::wrapping_add
+    }
+}
+
+impl_reduce_add! {
+    f64 as reduce_add_f64_in_place + reduce_add_f64 {
+        f64x2_add, reduce_f64, |x, y| x + y
+    }
+}
+
+// There are no horizontal additions, so the lanes are added up pairwise
+#[inline(always)]
+unsafe fn reduce_f32 (v: v128) -> f32 {
+    let v = f32x4_add(v, i64x2_shuffle::<1, 1>(v, v));
+    return f32x4_extract_lane::<0>(v) + f32x4_extract_lane::<1>(v)
+}
+
+#[inline(always)]
+unsafe fn reduce_i32 (v: v128) -> i32 {
+    let v = i32x4_add(v, i64x2_shuffle::<1, 1>(v, v));
+    return i32x4_extract_lane::<0>(v).wrapping_add(i32x4_extract_lane::<1>(v))
+}
+
+#[inline(always)]
+unsafe fn reduce_f64 (v: v128) -> f64 {
+    return f64x2_extract_lane::<0>(v) + f64x2_extract_lane::<1>(v)
+}
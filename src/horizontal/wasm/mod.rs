@@ -0,0 +1 @@
+flat_mod! { float }
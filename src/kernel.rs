@@ -0,0 +1,302 @@
+//! Introspection of the kernels picked for every operation.
+
+use core::fmt;
+#[allow(unused_imports)]
+use crate::vertical::*;
+#[allow(unused_imports)]
+use crate::vertical::simd::{Vector, Vectorize};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::vertical::math::VerticalMath;
+#[cfg(feature = "half")]
+use half::{f16, bf16};
+
+/// The kernel serving one operation on one element type.
+///
+/// # Example
+/// ```rust
+/// use slicesimd::*;
+///
+/// for kernel in kernels() {
+///     println!("{kernel}");
+/// }
+///
+/// let add = kernels().find(|k| k.op == "VerticalAdd" && k.element == "i16");
+/// if checks::is_x86_avx2() && !checks::is_x86_avx512bw() && width::max_width().is_none() {
+///     assert_eq!(add.map(|k| (k.isa, k.width)), Some(("avx2", 256)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Kernel {
+    /// Name of the trait implementing the operation, such as `"VerticalAdd"` or `"VerticalConvert<f32>"`.
+    pub op: &'static str,
+    /// Name of the element type, such as `"i16"`.
+    pub element: &'static str,
+    /// Instruction set the kernel was compiled for (`"avx2"`, `"neon"`, ...), or `"scalar"` for the fallbacks.
+    pub isa: &'static str,
+    /// Width, in bits, of the registers the elements are processed in.
+    /// For the scalar fallbacks, this is the width of the element itself.
+    pub width: usize,
+}
+
+impl Kernel {
+    /// Checks if the operation is vectorized, instead of falling back to scalar code.
+    #[inline]
+    pub fn is_simd (&self) -> bool {
+        return self.isa != SCALAR
+    }
+}
+
+impl fmt::Display for Kernel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on [{}]: {} ({}-bit)", self.op, self.element, self.isa, self.width)
+    }
+}
+
+/// Returns every pair of operation and element type, with the kernel selected for it.
+///
+/// Kernels are picked at compile time from the target features the crate was built with, and then narrowed down
+/// by the runtime cap on their width (see [`width`](crate::width)), as it stands when this function is called.
+/// Half-precision elements are processed in `f32` registers. The portable backend ignores caps of at least 128 bits,
+/// and on targets without a SIMD backend, every kernel is scalar.
+#[inline]
+pub fn kernels () -> impl Iterator<Item = Kernel> + Clone {
+    let cap = crate::width::max_width().unwrap_or(usize::MAX);
+    return KERNELS.iter().map(move |row| row.resolve(cap))
+}
+
+const SCALAR: &str = "scalar";
+
+/// Registers an element type is processed in, from the widest to the narrowest one it can be capped to.
+#[allow(dead_code)]
+pub(crate) trait Element {
+    const ISA: &'static str;
+    const WIDTH: usize;
+    const ISA256: &'static str = Self::ISA;
+    const WIDTH256: usize = Self::WIDTH;
+    const ISA128: &'static str = Self::ISA;
+    const WIDTH128: usize = Self::WIDTH;
+}
+
+macro_rules! impl_element {
+    ($(#[cfg($meta:meta)] $($t:ident),+);+ $(;)?) => {
+        $($(
+            #[cfg($meta)]
+            impl Element for $t {
+                const ISA: &'static str = <$t as Vectorize>::ISA;
+                const WIDTH: usize = 8 * core::mem::size_of::<Vector<$t>>();
+                const ISA256: &'static str = <$t as Vectorize>::ISA256;
+                const WIDTH256: usize = 8 * core::mem::size_of::<<$t as Vectorize>::Vector256>();
+                const ISA128: &'static str = <$t as Vectorize>::ISA128;
+                const WIDTH128: usize = 8 * core::mem::size_of::<<$t as Vectorize>::Vector128>();
+            }
+
+            #[cfg(not($meta))]
+            impl Element for $t {
+                const ISA: &'static str = SCALAR;
+                const WIDTH: usize = 8 * core::mem::size_of::<$t>();
+            }
+        )+)+
+    };
+}
+
+impl_element! {
+    #[cfg(any(
+        feature = "portable",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(target_arch = "aarch64", target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    ))]
+    f32;
+    #[cfg(any(
+        feature = "portable",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse", target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    ))]
+    f64, i8, u8, i16, u16, i32, u32, i64, u64
+}
+
+// Half-precision kernels run on `f32` registers
+#[cfg(feature = "half")]
+impl Element for f16 {
+    const ISA: &'static str = <f32 as Element>::ISA;
+    const WIDTH: usize = <f32 as Element>::WIDTH;
+    const ISA256: &'static str = <f32 as Element>::ISA256;
+    const WIDTH256: usize = <f32 as Element>::WIDTH256;
+    const ISA128: &'static str = <f32 as Element>::ISA128;
+    const WIDTH128: usize = <f32 as Element>::WIDTH128;
+}
+
+#[cfg(feature = "half")]
+impl Element for bf16 {
+    const ISA: &'static str = <f32 as Element>::ISA;
+    const WIDTH: usize = <f32 as Element>::WIDTH;
+    const ISA256: &'static str = <f32 as Element>::ISA256;
+    const WIDTH256: usize = <f32 as Element>::WIDTH256;
+    const ISA128: &'static str = <f32 as Element>::ISA128;
+    const WIDTH128: usize = <f32 as Element>::WIDTH128;
+}
+
+/// A kernel at every width it can be capped to.
+#[allow(dead_code)]
+struct Row {
+    op: &'static str,
+    element: &'static str,
+    /// Width of the element, for the scalar fallback
+    scalar: usize,
+    /// Instruction sets and widths of the registers, from the widest to the narrowest, if vectorized
+    tiers: Option<[(&'static str, usize); 3]>
+}
+
+impl Row {
+    /// Picks the widest register that fits in `cap`, the same way the kernels do.
+    #[allow(dead_code)]
+    fn resolve (&self, cap: usize) -> Kernel {
+        let (isa, width) = match self.tiers {
+            Some(tiers) if cap >= 128 => tiers.into_iter().find(|&(_, width)| width <= cap).unwrap_or(tiers[0]),
+            _ => (SCALAR, self.scalar)
+        };
+        return Kernel { op: self.op, element: self.element, isa, width }
+    }
+}
+
+#[allow(dead_code)]
+const fn kernel<T: Element> (op: &'static str, element: &'static str, simd: bool) -> Row {
+    let scalar = 8 * core::mem::size_of::<T>();
+    if !simd {
+        return Row { op, element, scalar, tiers: None }
+    }
+    return Row { op, element, scalar, tiers: Some([(T::ISA, T::WIDTH), (T::ISA256, T::WIDTH256), (T::ISA128, T::WIDTH128)]) }
+}
+
+macro_rules! kernels {
+    ($(
+        $(#[cfg($meta:meta)])?
+        $trait:ident $(<$u:ident>)? => $($t:ident),+;
+    )+) => {
+        kernels! {
+            @rows $(
+                (all($($meta)?)) concat!(stringify!($trait) $(, "<", stringify!($u), ">")?),
+                $trait $(<$u>)? => $($t),+;
+            )+
+        }
+    };
+
+    (@rows $($cfg:tt $name:expr, $path:path => $($t:ident),+;)+) => {
+        &[$($(
+            #[cfg $cfg]
+            kernel::<$t>($name, stringify!($t), <[$t] as $path>::SIMD),
+        )+)+]
+    };
+}
+
+static KERNELS: &[Row] = kernels! {
+    VerticalAdd => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalSub => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalMul => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalDiv => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalAccumulate => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalMean => f32, f64;
+    VerticalMin => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalMax => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalClamp => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalMulAdd => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalAbs => i8, i16, i32, i64, f32, f64;
+    VerticalNeg => i8, i16, i32, i64, f32, f64;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    VerticalSqrt => f32, f64;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    VerticalRsqrt => f32, f64;
+    VerticalRecip => f32, f64;
+    VerticalSquare => f32, f64;
+    VerticalRound => f32, f64;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    VerticalMath => f32, f64;
+    VerticalAnd => i8, u8, i16, u16, i32, u32, i64, u64;
+    VerticalOr => i8, u8, i16, u16, i32, u32, i64, u64;
+    VerticalXor => i8, u8, i16, u16, i32, u32, i64, u64;
+    VerticalAndNot => i8, u8, i16, u16, i32, u32, i64, u64;
+    VerticalNot => i8, u8, i16, u16, i32, u32, i64, u64;
+    VerticalShift => i8, u8, i16, u16, i32, u32, i64, u64;
+    VerticalRotate => i8, u8, i16, u16, i32, u32, i64, u64;
+    VerticalCmp => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalSelect => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<i8> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<u8> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<i16> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<u16> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<i32> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<u32> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<i64> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<u64> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<f32> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    VerticalConvert<f64> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    #[cfg(feature = "half")]
+    VerticalAdd => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalSub => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalMul => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalDiv => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalAccumulate => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalMean => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalMin => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalMax => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalClamp => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalMulAdd => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalAbs => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalNeg => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalRecip => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalSquare => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalRound => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalCmp => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalSelect => f16, bf16;
+    #[cfg(all(feature = "half", any(feature = "std", feature = "libm")))]
+    VerticalSqrt => f16, bf16;
+    #[cfg(all(feature = "half", any(feature = "std", feature = "libm")))]
+    VerticalRsqrt => f16, bf16;
+    #[cfg(all(feature = "half", any(feature = "std", feature = "libm")))]
+    VerticalMath => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<i8> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<u8> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<i16> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<u16> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<i32> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<u32> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<i64> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<u64> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<f32> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<f64> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<f16> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<bf16> => f16, bf16;
+    #[cfg(feature = "half")]
+    VerticalConvert<f16> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+    #[cfg(feature = "half")]
+    VerticalConvert<bf16> => i8, u8, i16, u16, i32, u32, i64, u64, f32, f64;
+};
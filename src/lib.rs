@@ -1,14 +1,23 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(feature = "nightly", feature(stdsimd))]
+#![cfg_attr(feature = "portable", feature(portable_simd))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+// Lets `simd_trait` name this crate the same way from the inside as from the outside
+extern crate self as slicesimd;
+
 pub(crate) mod sealed {
     use bytemuck::Pod;
-    use num_traits::{NumOps, NumAssignOps};
+    use num_traits::{NumOps, NumAssignOps, float::FloatCore};
+    #[cfg(feature = "half")]
+    use half::{f16, bf16};
+
+    /// Element types, which only this crate can add to.
+    #[doc(hidden)]
+    pub trait Sealed {}
 
     #[doc(hidden)]
     pub trait Slice {
-        type Element: Pod + NumOps + NumAssignOps + for<'a> NumAssignOps<&'a Self::Element>;
+        type Element: Pod + PartialOrd + Wrapping + NumOps + NumAssignOps + for<'a> NumAssignOps<&'a Self::Element>;
         type Iter<'a>: Iterator<Item = &'a Self::Element> where Self: 'a;
         type IterMut<'a>: Iterator<Item = &'a mut Self::Element> where Self: 'a;
         
@@ -41,17 +50,553 @@ pub(crate) mod sealed {
         i8, i16, i32, i64,
         f32, f64
     }
+
+    #[cfg(feature = "half")]
+    impl_slice! {
+        f16, bf16
+    }
+
+    /// Signed elements. Integer operations wrap around on overflow.
+    #[doc(hidden)]
+    pub trait Signed: Copy {
+        fn abs (self) -> Self;
+        fn neg (self) -> Self;
+    }
+
+    /// Floating point elements.
+    #[doc(hidden)]
+    pub trait Float: Signed {
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn sqrt (self) -> Self;
+        fn recip (self) -> Self;
+        fn floor (self) -> Self;
+        fn ceil (self) -> Self;
+        fn trunc (self) -> Self;
+        fn round (self) -> Self;
+        fn round_ties_even (self) -> Self;
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn exp (self) -> Self;
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn ln (self) -> Self;
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn log2 (self) -> Self;
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn sin (self) -> Self;
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn cos (self) -> Self;
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn tanh (self) -> Self;
+        #[cfg(any(feature = "std", feature = "libm"))]
+        fn powf (self, n: Self) -> Self;
+    }
+
+    macro_rules! impl_signed {
+        ($($t:ident),+) => {
+            $(
+                impl Signed for $t {
+                    #[inline]
+                    fn abs (self) -> Self { self.wrapping_abs() }
+                    #[inline]
+                    fn neg (self) -> Self { self.wrapping_neg() }
+                }
+            )+
+        };
+    }
+
+    macro_rules! impl_float {
+        ($($t:ident),+) => {
+            $(
+                impl Signed for $t {
+                    #[inline]
+                    fn abs (self) -> Self { <$t>::abs(self) }
+                    #[inline]
+                    fn neg (self) -> Self { -self }
+                }
+
+                impl Float for $t {
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn sqrt (self) -> Self { num_traits::Float::sqrt(self) }
+                    #[inline]
+                    fn recip (self) -> Self { 1.0 / self }
+                    #[inline]
+                    fn floor (self) -> Self { FloatCore::floor(self) }
+                    #[inline]
+                    fn ceil (self) -> Self { FloatCore::ceil(self) }
+                    #[inline]
+                    fn trunc (self) -> Self { FloatCore::trunc(self) }
+                    #[inline]
+                    fn round (self) -> Self { FloatCore::round(self) }
+
+                    #[inline]
+                    fn round_ties_even (self) -> Self {
+                        // `round` takes half-way cases away from zero, so we halve them to land on an even number instead
+                        if FloatCore::abs(self - FloatCore::trunc(self)) == 0.5 {
+                            return 2.0 * FloatCore::round(0.5 * self)
+                        }
+                        return FloatCore::round(self)
+                    }
+
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn exp (self) -> Self { num_traits::Float::exp(self) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn ln (self) -> Self { num_traits::Float::ln(self) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn log2 (self) -> Self { num_traits::Float::log2(self) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn sin (self) -> Self { num_traits::Float::sin(self) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn cos (self) -> Self { num_traits::Float::cos(self) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn tanh (self) -> Self { num_traits::Float::tanh(self) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn powf (self, n: Self) -> Self { num_traits::Float::powf(self, n) }
+                }
+            )+
+        };
+    }
+
+    impl_signed! {
+        i8, i16, i32, i64
+    }
+
+    /// Integer elements. Shift counts are taken modulo the width of the element.
+    #[doc(hidden)]
+    pub trait Integer: num_traits::PrimInt {
+        /// Returns the low bits of the element, as a shift count.
+        fn count (self) -> u32;
+        fn wrapping_shl (self, n: u32) -> Self;
+        fn wrapping_shr (self, n: u32) -> Self;
+    }
+
+    macro_rules! impl_integer {
+        ($($t:ident),+) => {
+            $(
+                impl Integer for $t {
+                    #[inline]
+                    fn count (self) -> u32 { self as u32 }
+                    #[inline]
+                    fn wrapping_shl (self, n: u32) -> Self { <$t>::wrapping_shl(self, n) }
+                    #[inline]
+                    fn wrapping_shr (self, n: u32) -> Self { <$t>::wrapping_shr(self, n) }
+                }
+            )+
+        };
+    }
+
+    impl_integer! {
+        u8, u16, u32, u64,
+        i8, i16, i32, i64
+    }
+
+    /// Arithmetic as the registers do it, for the scalar defaults: integers wrap around on overflow,
+    /// and `mul_add` rounds once wherever the backend's multiply-add is fused.
+    #[doc(hidden)]
+    pub trait Wrapping: Copy {
+        fn add (self, rhs: Self) -> Self;
+        fn sub (self, rhs: Self) -> Self;
+        fn mul (self, rhs: Self) -> Self;
+        /// Returns `self * a + b`.
+        fn mul_add (self, a: Self, b: Self) -> Self;
+    }
+
+    macro_rules! impl_wrapping {
+        ($($t:ident),+) => {
+            $(
+                impl Wrapping for $t {
+                    #[inline]
+                    fn add (self, rhs: Self) -> Self { self.wrapping_add(rhs) }
+                    #[inline]
+                    fn sub (self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
+                    #[inline]
+                    fn mul (self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
+                    #[inline]
+                    fn mul_add (self, a: Self, b: Self) -> Self { self.wrapping_mul(a).wrapping_add(b) }
+                }
+            )+
+        };
+        (@float $($t:ident),+) => {
+            $(
+                impl Wrapping for $t {
+                    #[inline]
+                    fn add (self, rhs: Self) -> Self { self + rhs }
+                    #[inline]
+                    fn sub (self, rhs: Self) -> Self { self - rhs }
+                    #[inline]
+                    fn mul (self, rhs: Self) -> Self { self * rhs }
+
+                    #[inline]
+                    fn mul_add (self, a: Self, b: Self) -> Self {
+                        // Matches the backends, which fuse with FMA on x86 and always on NEON
+                        #[cfg(all(
+                            any(feature = "std", feature = "libm"),
+                            any(target_feature = "fma", all(target_arch = "aarch64", target_feature = "neon"))
+                        ))]
+                        return num_traits::Float::mul_add(self, a, b);
+                        #[cfg(not(all(
+                            any(feature = "std", feature = "libm"),
+                            any(target_feature = "fma", all(target_arch = "aarch64", target_feature = "neon"))
+                        )))]
+                        return self * a + b
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_wrapping! {
+        u8, u16, u32, u64,
+        i8, i16, i32, i64
+    }
+
+    impl_wrapping! {
+        @float f32, f64
+    }
+
+    impl_float! {
+        f32, f64
+    }
+
+    /// Half-precision floats are computed on as `f32`, and rounded back to nearest.
+    #[cfg(feature = "half")]
+    macro_rules! impl_half {
+        ($($t:ident),+) => {
+            $(
+                impl Signed for $t {
+                    #[inline]
+                    fn abs (self) -> Self { <$t>::from_bits(self.to_bits() & 0x7fff) }
+                    #[inline]
+                    fn neg (self) -> Self { -self }
+                }
+
+                impl Float for $t {
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn sqrt (self) -> Self { <$t>::from_f32(Float::sqrt(self.to_f32())) }
+                    #[inline]
+                    fn recip (self) -> Self { <$t>::from_f32(Float::recip(self.to_f32())) }
+                    #[inline]
+                    fn floor (self) -> Self { <$t>::from_f32(Float::floor(self.to_f32())) }
+                    #[inline]
+                    fn ceil (self) -> Self { <$t>::from_f32(Float::ceil(self.to_f32())) }
+                    #[inline]
+                    fn trunc (self) -> Self { <$t>::from_f32(Float::trunc(self.to_f32())) }
+                    #[inline]
+                    fn round (self) -> Self { <$t>::from_f32(Float::round(self.to_f32())) }
+                    #[inline]
+                    fn round_ties_even (self) -> Self { <$t>::from_f32(Float::round_ties_even(self.to_f32())) }
+
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn exp (self) -> Self { <$t>::from_f32(Float::exp(self.to_f32())) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn ln (self) -> Self { <$t>::from_f32(Float::ln(self.to_f32())) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn log2 (self) -> Self { <$t>::from_f32(Float::log2(self.to_f32())) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn sin (self) -> Self { <$t>::from_f32(Float::sin(self.to_f32())) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn cos (self) -> Self { <$t>::from_f32(Float::cos(self.to_f32())) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn tanh (self) -> Self { <$t>::from_f32(Float::tanh(self.to_f32())) }
+                    #[cfg(any(feature = "std", feature = "libm"))]
+                    #[inline]
+                    fn powf (self, n: Self) -> Self { <$t>::from_f32(Float::powf(self.to_f32(), n.to_f32())) }
+                }
+            )+
+        };
+    }
+
+    #[cfg(feature = "half")]
+    impl_half! {
+        f16, bf16
+    }
+
+    #[cfg(feature = "half")]
+    impl_wrapping! {
+        @float f16, bf16
+    }
+
+    /// Elements that expressions can be evaluated on.
+    #[doc(hidden)]
+    pub trait Lane: Lanes<Self> {
+        /// Evaluates `expr` into `out`, on the widest registers allowed by the cap on their width (see [`width`](crate::width)).
+        ///
+        /// # Safety
+        /// Every slice in the expression must be at least as long as `out`.
+        unsafe fn eval<E: crate::expr::Expr<Element = Self>> (expr: &E, out: &mut [core::mem::MaybeUninit<Self>]);
+    }
+
+    /// Registers of lanes of type `T` that expressions are evaluated on, including `T` itself as a single lane.
+    #[doc(hidden)]
+    pub trait Lanes<T: Copy>: Copy {
+        const LANES: usize;
+
+        unsafe fn load (ptr: *const T) -> Self;
+        unsafe fn store (v: Self, ptr: *mut T);
+        /// Loads the first `len` lanes, zeroing the remaining ones. `len` is always in `1..LANES`.
+        unsafe fn load_partial (ptr: *const T, len: usize) -> Self;
+        /// Stores the first `len` lanes. `len` is always in `1..LANES`.
+        unsafe fn store_partial (v: Self, ptr: *mut T, len: usize);
+        unsafe fn splat (value: T) -> Self;
+
+        unsafe fn add (lhs: Self, rhs: Self) -> Self;
+        unsafe fn sub (lhs: Self, rhs: Self) -> Self;
+        unsafe fn mul (lhs: Self, rhs: Self) -> Self;
+        unsafe fn div (lhs: Self, rhs: Self) -> Self;
+        unsafe fn neg (v: Self) -> Self;
+    }
+
+    /// Every element is a register of a single lane, which expressions fall back to when the width is capped
+    /// below 128 bits. Targets without SIMD support only ever evaluate expressions that way.
+    macro_rules! impl_scalar_lane {
+        ($(#[cfg($meta:meta)] $t:ident),+) => {
+            $(
+                impl Lanes<$t> for $t {
+                    const LANES: usize = 1;
+
+                    #[inline(always)]
+                    unsafe fn load (ptr: *const Self) -> Self { *ptr }
+                    #[inline(always)]
+                    unsafe fn store (v: Self, ptr: *mut Self) { *ptr = v }
+                    #[inline(always)]
+                    unsafe fn load_partial (ptr: *const Self, _len: usize) -> Self { *ptr }
+                    #[inline(always)]
+                    unsafe fn store_partial (v: Self, ptr: *mut Self, _len: usize) { *ptr = v }
+                    #[inline(always)]
+                    unsafe fn splat (value: Self) -> Self { value }
+
+                    #[inline(always)]
+                    unsafe fn add (lhs: Self, rhs: Self) -> Self { lhs + rhs }
+                    #[inline(always)]
+                    unsafe fn sub (lhs: Self, rhs: Self) -> Self { lhs - rhs }
+                    #[inline(always)]
+                    unsafe fn mul (lhs: Self, rhs: Self) -> Self { lhs * rhs }
+                    #[inline(always)]
+                    unsafe fn div (lhs: Self, rhs: Self) -> Self { lhs / rhs }
+                    #[inline(always)]
+                    unsafe fn neg (v: Self) -> Self { -v }
+                }
+
+                #[cfg($meta)]
+                impl Lane for $t {
+                    #[inline]
+                    unsafe fn eval<E: crate::expr::Expr<Element = Self>> (expr: &E, out: &mut [core::mem::MaybeUninit<Self>]) {
+                        crate::expr::eval_lanes::<E, $t>(expr, out)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_scalar_lane! {
+        #[cfg(not(any(
+            feature = "portable",
+            all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+            all(target_arch = "aarch64", target_feature = "neon"),
+            all(target_arch = "wasm32", target_feature = "simd128")
+        )))]
+        f32,
+        #[cfg(not(any(
+            feature = "portable",
+            all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse", target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon"),
+            all(target_arch = "wasm32", target_feature = "simd128")
+        )))]
+        f64
+    }
+
+    /// Per-element selection bits: packed words (`[u64]` or [`Mask`](crate::Mask)) or `[bool]`.
+    #[doc(hidden)]
+    pub trait Selector {
+        /// Returns `true` if the selector has bits for (at least) `len` elements.
+        fn covers (&self, len: usize) -> bool;
+
+        /// Returns the bits of the `n` elements starting at `offset`, which mustn't straddle two words.
+        ///
+        /// # Safety
+        /// The selector must cover those elements.
+        unsafe fn bits (&self, offset: usize, n: usize) -> u64;
+    }
+
+    impl Selector for [u64] {
+        #[inline]
+        fn covers (&self, len: usize) -> bool {
+            self.len() >= crate::mask::words_for(len)
+        }
+
+        #[inline]
+        unsafe fn bits (&self, offset: usize, n: usize) -> u64 {
+            (self.get_unchecked(offset / 64) >> (offset % 64)) & (u64::MAX >> (64 - n))
+        }
+    }
+
+    impl Selector for [bool] {
+        #[inline]
+        fn covers (&self, len: usize) -> bool {
+            self.len() == len
+        }
+
+        #[inline]
+        unsafe fn bits (&self, offset: usize, n: usize) -> u64 {
+            let mut bits = 0;
+            for (i, x) in self.get_unchecked(offset..offset + n).iter().enumerate() {
+                bits |= (*x as u64) << i;
+            }
+            bits
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Selector for crate::Mask {
+        #[inline]
+        fn covers (&self, len: usize) -> bool {
+            self.len() == len
+        }
+
+        #[inline]
+        unsafe fn bits (&self, offset: usize, n: usize) -> u64 {
+            self.words.bits(offset, n)
+        }
+    }
 }
 
 pub mod horizontal;
 pub use horizontal::HorizontalSlice;
 
 pub mod vertical;
-pub use vertical::{VerticalAdd, VerticalSub, VerticalMul, VerticalDiv};
+pub use vertical::{
+    VerticalAdd, VerticalSub, VerticalMul, VerticalDiv, VerticalMin, VerticalMax, VerticalClamp, VerticalMulAdd,
+    VerticalAbs, VerticalNeg, VerticalRecip, VerticalSquare, VerticalRound, VerticalCmp, VerticalSelect,
+    VerticalAnd, VerticalOr, VerticalXor, VerticalAndNot, VerticalNot,
+    VerticalShift, VerticalRotate, VerticalConvert, VerticalAccumulate, VerticalMean
+};
+
+pub mod mask;
+pub mod expr;
+pub mod error;
+pub use error::Error;
+pub mod element;
+pub use element::{SimdElement, SimdSigned, SimdFloat, SimdInt};
+pub mod kernel;
+pub mod width;
+/// Defines a trait over slices, along with a `Simd` copy of it for overriding its default methods.
+///
+/// `#[simd_trait] pub trait X { ... }` also declares `SimdX`, with the same items, and implements `X` for every type
+/// that implements `SimdX`. The default methods of `X` are the generic, scalar implementations: slices that only
+/// implement `X` get them, while those that implement `SimdX` get their overrides, except when
+/// [`width`](crate::width) rules out SIMD at runtime, in which case they fall back to the defaults of `X` too.
+///
+/// Methods marked `#[binary]` or `#[unary]` are kernels, named `x_unchecked`, whose first argument must be as long as
+/// the slice: `rhs: &Self` for binary ones and `out: &mut [MaybeUninit<Self::Element>]` for unary ones. They come
+/// with a panicking `x`, a `try_x` returning an [`Error`] and an `x_checked` returning whether it succeeded, which
+/// check that length first (see [`error`]). The documentation of the kernel goes to `x`.
+///
+/// Kernels with other arguments to check list them instead, as in `#[binary(a, b)]`, where every plain argument must be
+/// as long as the slice. The other checks are:
+/// - `covers(mask)`: a selection mask covers the slice.
+/// - `words(out)`: a `&mut [u64]` holds a packed mask of the slice.
+/// - `pattern(rhs)`: a slice repeats a whole number of times over this one.
+/// - `each(sources)`: every slice in a `&[&Self]` is as long as this one.
+/// - `nonempty(sources)`: a `&[T]` isn't empty.
+/// - `bounds(lo, hi)`: `lo <= hi`, and neither of them is NaN.
+///
+/// Both traits are only implemented by the slices of the element types of this crate. If the crate is renamed in
+/// `Cargo.toml`, pass its new path as `#[simd_trait(crate = path)]`.
+///
+/// # Example
+/// ```rust
+/// mod lerp {
+///     use slicesimd::simd_trait;
+///
+///     #[simd_trait]
+///     pub trait VerticalLerp {
+///         /// Moves every element `t` of the way towards it's counterpart in `rhs`
+///         ///
+///         /// # Panics
+///         /// This method panics if both slices have different lengths.
+///         #[binary]
+///         unsafe fn lerp_assign_unchecked (&mut self, rhs: &Self, t: Self::Element) {
+///             for (x, &y) in self.iter_mut().zip(rhs.iter()) {
+///                 *x += (y - *x) * t
+///             }
+///         }
+///
+///         /// Replaces every element of the slice with the sum of it's counterparts in `a` and `b`
+///         #[binary(a, b)]
+///         unsafe fn sum_of_unchecked (&mut self, a: &Self, b: &Self) {
+///             for ((x, &a), &b) in self.iter_mut().zip(a.iter()).zip(b.iter()) {
+///                 *x = a + b
+///             }
+///         }
+///     }
+///
+///     // Runs on the SIMD operations of the crate, unless SIMD is ruled out at runtime
+///     impl SimdVerticalLerp for [f32] {
+///         unsafe fn lerp_assign_unchecked (&mut self, rhs: &Self, t: f32) {
+///             use slicesimd::*;
+///             let mut delta = rhs[..self.len()].to_vec();
+///             delta.sub_assign_unchecked(self);
+///             delta.affine_assign(t, 0.0);
+///             self.add_assign_unchecked(&delta);
+///         }
+///     }
+///
+///     // Uses the default implementation
+///     impl VerticalLerp for [f64] {}
+/// }
+///
+/// // Only brings `VerticalLerp` in scope, as `SimdVerticalLerp` has the same methods
+/// use lerp::VerticalLerp;
+///
+/// let mut single = [0.0f32, 2.0, 4.0];
+/// single.lerp_assign(&[2.0, 2.0, 2.0], 0.5);
+/// assert_eq!(single, [1.0, 2.0, 3.0]);
+///
+/// let mut double = [0.0f64, 2.0, 4.0];
+/// double.lerp_assign(&[2.0, 2.0, 2.0], 0.5);
+/// assert_eq!(double, [1.0, 2.0, 3.0]);
+/// assert_eq!(double.try_lerp_assign(&[2.0], 0.5), Err(slicesimd::Error::LengthMismatch { lhs: 3, rhs: 1 }));
+///
+/// double.sum_of(&[1.0, 2.0, 3.0], &[0.5, 0.5, 0.5]);
+/// assert_eq!(double, [1.5, 2.5, 3.5]);
+/// assert!(!double.sum_of_checked(&[1.0, 2.0, 3.0], &[0.5]));
+/// ```
+pub use slicesimd_proc::simd_trait;
+pub use kernel::{kernels, Kernel};
+pub use expr::{expr, Expr, IntoExpr};
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+pub use half::{f16, bf16};
+#[cfg(feature = "alloc")]
+pub use mask::Mask;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use vertical::{VerticalSqrt, VerticalRsqrt, math::VerticalMath};
 
 #[cfg(feature = "alloc")]
 pub(crate) extern crate alloc;
 
+/// Items used by the code that [`simd_trait`] expands to. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::sealed::Slice;
+    pub use crate::error::{same_len, covers, words, pattern, each, nonempty, bounds};
+    pub use crate::width::is_scalar;
+}
+
 #[allow(unused_macros)]
 macro_rules! flat_mod {
     ($($i:ident),+) => {
@@ -73,71 +618,184 @@ pub mod checks {
     /// Returns `true` if the current target supports 128-bit SIMD types and operations, and `false` otherwise.
     #[inline]
     pub const fn is_simd_128() -> bool {
-        return is_x86_sse();
+        return X86Feature::Sse.is_enabled() || is_arm_neon() || is_wasm_simd128();
     }
 
     /// Returns `true` if the current target supports 256-bit SIMD types and operations, and `false` otherwise.
     #[inline]
     pub const fn is_simd_256() -> bool {
-        return is_x86_avx();
+        return X86Feature::Avx.is_enabled();
     }
 
     /// Returns `true` if the current target supports 512-bit SIMD types and operations, and `false` otherwise.
     #[inline]
     pub const fn is_simd_512() -> bool {
-        return is_x86_avx512();
+        return X86Feature::Avx512f.is_enabled();
     }
 
-    /// Checks if the current platform is x86 (32-bit or 64-bit) and has support for SSE instructions.
+    macro_rules! x86_features {
+        (
+            $(
+                $(#[doc = $doc:literal])*
+                $variant:ident = $name:tt as $fn:ident
+            ),+ $(,)?
+        ) => {
+            /// An x86 instruction set extension, as named by `target_feature`.
+            ///
+            /// [`is_enabled`](X86Feature::is_enabled) tells whether the extension was enabled at compile time (and may therefore be used
+            /// by this crate's kernels), while [`is_detected`](X86Feature::is_detected) asks the CPU the program is running on.
+            ///
+            /// # Example
+            /// ```rust
+            /// use slicesimd::checks::*;
+            ///
+            /// for feature in X86Feature::ALL {
+            ///     // Everything enabled at compile time has to be supported by the running CPU
+            ///     if feature.is_enabled() {
+            ///         assert!(feature.is_detected());
+            ///     }
+            /// }
+            ///
+            /// assert_eq!(X86Feature::Sse41.name(), "sse4.1");
+            /// assert_eq!(X86Feature::Avx2.is_enabled(), is_x86_avx2());
+            /// ```
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #[non_exhaustive]
+            pub enum X86Feature {
+                $(
+                    $(#[doc = $doc])*
+                    $variant
+                ),+
+            }
+
+            impl X86Feature {
+                /// Every extension known to this crate.
+                pub const ALL: &'static [X86Feature] = &[$(X86Feature::$variant),+];
+
+                /// Returns the name of the extension, as used by `target_feature` and `is_x86_feature_detected!`.
+                #[inline]
+                pub const fn name (self) -> &'static str {
+                    match self {
+                        $(X86Feature::$variant => $name),+
+                    }
+                }
+
+                /// Checks if the extension was enabled at compile time.
+                #[inline]
+                pub const fn is_enabled (self) -> bool {
+                    match self {
+                        $(X86Feature::$variant => $fn()),+
+                    }
+                }
+
+                /// Checks if the CPU the program is running on supports the extension.
+                /// Always `false` on other architectures.
+                #[cfg(feature = "std")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+                #[inline]
+                pub fn is_detected (self) -> bool {
+                    cfg_if::cfg_if! {
+                        if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
+                            return match self {
+                                $(X86Feature::$variant => std::is_x86_feature_detected!($name)),+
+                            }
+                        } else {
+                            return false
+                        }
+                    }
+                }
+            }
+
+            $(
+                #[doc = concat!("Checks if the current platform is x86 (32-bit or 64-bit) and has support for `", $name, "` instructions.")]
+                #[inline]
+                pub const fn $fn () -> bool {
+                    return cfg!(all(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature = $name
+                    ));
+                }
+            )+
+        };
+    }
+
+    x86_features! {
+        /// Streaming SIMD Extensions: 128-bit `f32` vectors
+        Sse = "sse" as is_x86_sse,
+        /// 128-bit `f64` and integer vectors
+        Sse2 = "sse2" as is_x86_sse2,
+        /// Horizontal additions and duplicating loads
+        Sse3 = "sse3" as is_x86_sse3,
+        /// Byte shuffles and integer absolute values
+        Ssse3 = "ssse3" as is_x86_ssse3,
+        /// Rounding, blends, and the missing integer minimums, maximums and conversions
+        Sse41 = "sse4.1" as is_x86_sse41,
+        /// 64-bit integer comparisons
+        Sse42 = "sse4.2" as is_x86_sse42,
+        /// 256-bit floating point vectors
+        Avx = "avx" as is_x86_avx,
+        /// 256-bit integer vectors
+        Avx2 = "avx2" as is_x86_avx2,
+        /// Fused multiply-add
+        Fma = "fma" as is_x86_fma,
+        /// Conversions between `f16` and `f32`
+        F16c = "f16c" as is_x86_f16c,
+        /// Parallel bit deposits and extractions
+        Bmi2 = "bmi2" as is_x86_bmi2,
+        /// 512-bit vectors of 32 and 64-bit lanes, and mask registers
+        Avx512f = "avx512f" as is_x86_avx512f,
+        /// 512-bit vectors of 8 and 16-bit lanes
+        Avx512bw = "avx512bw" as is_x86_avx512bw,
+        /// 64-bit integer multiplications and conversions
+        Avx512dq = "avx512dq" as is_x86_avx512dq,
+        /// The AVX-512 instructions on 128 and 256-bit vectors
+        Avx512vl = "avx512vl" as is_x86_avx512vl,
+        /// Integer dot products
+        Avx512vnni = "avx512vnni" as is_x86_avx512vnni,
+    }
+
+    /// Checks if the current platform is x86 (32-bit or 64-bit) and has support for both SSE4.1 and SSE4.2 instructions.
     #[inline]
-    pub const fn is_x86_sse() -> bool {
-        return cfg!(all(
-            any(target_arch = "x86", target_arch = "x86_64"),
-            target_feature = "sse"
-        ));
+    pub const fn is_x86_sse4() -> bool {
+        return is_x86_sse41() && is_x86_sse42();
     }
 
-    /// Checks if the current platform is x86 (32-bit or 64-bit) and has support for SSE3 instructions.
+    /// Checks if the current platform is x86 (32-bit or 64-bit) and has support for AVX512 instructions.
+    /// This is the same as [`is_x86_avx512f`].
     #[inline]
-    pub const fn is_x86_sse3() -> bool {
-        return cfg!(all(
-            any(target_arch = "x86", target_arch = "x86_64"),
-            target_feature = "sse3"
-        ));
+    pub const fn is_x86_avx512() -> bool {
+        return is_x86_avx512f();
     }
 
-    /// Checks if the current platform is x86 (32-bit or 64-bit) and has support for SSE4 instructions.
+    /// Checks if the current platform is AArch64 and has support for NEON (Advanced SIMD) instructions.
     #[inline]
-    pub const fn is_x86_sse4() -> bool {
+    pub const fn is_arm_neon() -> bool {
         return cfg!(all(
-            any(target_arch = "x86", target_arch = "x86_64"),
-            target_feature = "sse4"
+            target_arch = "aarch64",
+            target_feature = "neon"
         ));
     }
 
-    /// Checks if the current platform is x86 (32-bit or 64-bit) and has support for AVX instructions.
+    /// Checks if the current platform is WebAssembly and has support for the `simd128` instructions.
     #[inline]
-    pub const fn is_x86_avx() -> bool {
+    pub const fn is_wasm_simd128() -> bool {
         return cfg!(all(
-            any(target_arch = "x86", target_arch = "x86_64"),
-            target_feature = "avx"
+            target_arch = "wasm32",
+            target_feature = "simd128"
         ));
     }
 
-    /// Checks if the current platform is x86 (32-bit or 64-bit) and has support for AVX512 instructions.
+    /// Checks if the portable `std::simd` backend was picked (through the `portable` feature) over the architecture-specific ones.
     #[inline]
-    pub const fn is_x86_avx512() -> bool {
-        return cfg!(all(
-            feature = "nightly",
-            any(target_arch = "x86", target_arch = "x86_64"),
-            target_feature = "avx512f"
-        ));
+    pub const fn is_portable() -> bool {
+        return cfg!(feature = "portable");
     }
 
     /// Checks if the current platform is using naïve implementations of the algorithms.
-    /// This is true when the `naive` feature is enabled, or as a fallback if no supported feature set is detected.
+    /// This is true when neither the portable backend nor a supported feature set is enabled at compile time.
+    /// See [`width`](crate::width) to fall back to them at runtime.
     #[inline]
     pub const fn is_naive() -> bool {
-        return !is_x86_sse();
+        return !is_portable() && !is_simd_128();
     }
 }
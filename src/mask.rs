@@ -0,0 +1,222 @@
+//! Packed bitmasks, with one bit per element of a slice.
+//!
+//! Element `i` is mapped to bit `i % 64` of word `i / 64`. Bits past the last element are always zero.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::ops::*;
+use docfg::docfg;
+
+/// Returns the number of words needed to hold a mask of `len` elements.
+#[inline]
+pub const fn words_for (len: usize) -> usize {
+    return len.div_ceil(u64::BITS as usize)
+}
+
+/// Owned bitmask, as produced by the comparisons of [`VerticalCmp`](crate::VerticalCmp).
+///
+/// # Example
+/// ```rust
+/// use slicesimd::*;
+///
+/// let values = [1, 5, 2, 8, 3, 9];
+/// let mask = values.cmp_gt_scalar(4);
+///
+/// assert_eq!(mask.count_ones(), 3);
+/// assert_eq!(mask.iter_ones().collect::<Vec<_>>(), [1, 3, 5]);
+/// assert_eq!(mask.filter(&values).copied().collect::<Vec<_>>(), [5, 8, 9]);
+/// ```
+#[docfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Mask {
+    pub(crate) words: Vec<u64>,
+    pub(crate) len: usize
+}
+
+#[cfg(feature = "alloc")]
+impl Mask {
+    /// Creates a mask of `len` elements, all of them unset.
+    #[inline]
+    pub fn new (len: usize) -> Self {
+        return Self { words: alloc::vec![0; words_for(len)], len }
+    }
+
+    /// Creates a mask from it's packed words.
+    ///
+    /// # Panics
+    /// This method panics if `words` doesn't hold exactly [`words_for(len)`](words_for) words.
+    #[inline]
+    pub fn from_words (mut words: Vec<u64>, len: usize) -> Self {
+        assert_eq!(words.len(), words_for(len), "Mask sizes don't match: {} words for {} elements", words.len(), len);
+        if let Some(last) = words.last_mut() {
+            *last &= tail_bits(len);
+        }
+        return Self { words, len }
+    }
+
+    /// Creates a mask with the bits set where `values` is `true`.
+    #[inline]
+    pub fn from_bools (values: &[bool]) -> Self {
+        let mut mask = Self::new(values.len());
+        for (word, chunk) in mask.words.iter_mut().zip(values.chunks(u64::BITS as usize)) {
+            for (i, value) in chunk.iter().enumerate() {
+                *word |= (*value as u64) << i;
+            }
+        }
+        return mask
+    }
+
+    /// Returns the number of elements covered by the mask.
+    #[inline]
+    pub fn len (&self) -> usize {
+        return self.len
+    }
+
+    /// Returns `true` if the mask covers no elements.
+    #[inline]
+    pub fn is_empty (&self) -> bool {
+        return self.len == 0
+    }
+
+    /// Returns the packed words of the mask.
+    #[inline]
+    pub fn as_words (&self) -> &[u64] {
+        return &self.words
+    }
+
+    /// Returns the packed words of the mask.
+    #[inline]
+    pub fn into_words (self) -> Vec<u64> {
+        return self.words
+    }
+
+    /// Returns whether the bit of the `i`-th element is set, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get (&self, i: usize) -> Option<bool> {
+        if i >= self.len { return None }
+        return Some(self.words[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    /// Sets the bit of the `i`-th element to `value`.
+    ///
+    /// # Panics
+    /// This method panics if `i` is out of bounds.
+    #[inline]
+    pub fn set (&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "Index out of bounds: {} v. {}", i, self.len);
+        let word = &mut self.words[i / 64];
+        *word = (*word & !(1 << (i % 64))) | ((value as u64) << (i % 64));
+    }
+
+    /// Returns the number of set bits.
+    #[inline]
+    pub fn count_ones (&self) -> usize {
+        return self.words.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if any bit is set.
+    #[inline]
+    pub fn any (&self) -> bool {
+        return self.words.iter().any(|x| *x != 0)
+    }
+
+    /// Returns `true` if every bit is set (including when the mask is empty).
+    #[inline]
+    pub fn all (&self) -> bool {
+        return self.count_ones() == self.len
+    }
+
+    /// Returns an iterator over the bits of the mask.
+    #[inline]
+    pub fn iter (&self) -> impl '_ + ExactSizeIterator<Item = bool> {
+        return (0..self.len).map(|i| self.words[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    /// Returns an iterator over the indices of the set bits, in increasing order.
+    #[inline]
+    pub fn iter_ones (&self) -> impl '_ + Iterator<Item = usize> {
+        return self.words.iter().enumerate().flat_map(|(i, word)| {
+            let mut word = *word;
+            core::iter::from_fn(move || {
+                if word == 0 { return None }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                return Some(64 * i + bit)
+            })
+        })
+    }
+
+    /// Returns an iterator over the elements of `values` whose bit is set.
+    ///
+    /// # Panics
+    /// This method panics if `values` and the mask have different lengths.
+    #[inline]
+    pub fn filter<'a, T> (&'a self, values: &'a [T]) -> impl 'a + Iterator<Item = &'a T> {
+        assert_eq!(self.len, values.len(), "Slice sizes don't match: {} v. {}", self.len, values.len());
+        return self.iter_ones().map(|i| &values[i])
+    }
+}
+
+macro_rules! impl_bitwise {
+    ($($trait:ident & $assign_trait:ident as $f:ident & $assign_f:ident => $op:tt),+) => {
+        $(
+            #[docfg(feature = "alloc")]
+            impl $assign_trait<&Mask> for Mask {
+                /// # Panics
+                /// This method panics if both masks have different lengths.
+                #[inline]
+                fn $assign_f (&mut self, rhs: &Mask) {
+                    assert_eq!(self.len, rhs.len, "Mask sizes don't match: {} v. {}", self.len, rhs.len);
+                    for (x, y) in self.words.iter_mut().zip(rhs.words.iter()) {
+                        *x = *x $op *y;
+                    }
+                }
+            }
+
+            #[docfg(feature = "alloc")]
+            impl $trait<&Mask> for Mask {
+                type Output = Mask;
+
+                /// # Panics
+                /// This method panics if both masks have different lengths.
+                #[inline]
+                fn $f (mut self, rhs: &Mask) -> Mask {
+                    self.$assign_f(rhs);
+                    return self
+                }
+            }
+        )+
+    };
+}
+
+impl_bitwise! {
+    BitAnd & BitAndAssign as bitand & bitand_assign => &,
+    BitOr & BitOrAssign as bitor & bitor_assign => |,
+    BitXor & BitXorAssign as bitxor & bitxor_assign => ^
+}
+
+#[docfg(feature = "alloc")]
+impl Not for Mask {
+    type Output = Mask;
+
+    #[inline]
+    fn not (mut self) -> Mask {
+        for x in self.words.iter_mut() {
+            *x = !*x;
+        }
+        if let Some(last) = self.words.last_mut() {
+            *last &= tail_bits(self.len);
+        }
+        return self
+    }
+}
+
+/// Bits of the last word used by a mask of `len` elements
+#[inline]
+pub(crate) const fn tail_bits (len: usize) -> u64 {
+    match len % 64 {
+        0 => u64::MAX,
+        rem => (1 << rem) - 1
+    }
+}
@@ -0,0 +1,249 @@
+//! Elementary functions over floating point slices.
+//!
+//! The SIMD implementations evaluate polynomial approximations after reducing the argument to a small interval,
+//! and handle special values (infinities, NaN, signed zeros and subnormals) like the scalar functions in `std`.
+//! Their maximum errors, in units in the last place (ULP) and measured against arbitrary precision references, are:
+//!
+//! | Function | `f32` | `f64` | Notes |
+//! |----------|-------|-------|-------|
+//! | `exp` | 1 | 1 | |
+//! | `ln` | 1 | 1 | |
+//! | `log2` | 1 | 1 | |
+//! | `sin`, `cos` | 1 | 1 | Arguments beyond `8192` (`f32`) or `2^20` (`f64`) in magnitude go through the scalar functions |
+//! | `tanh` | 1.5 | 1.5 | |
+//! | `pow` | 1.5 | 1.5 | |
+//!
+//! Implementations without SIMD support fall back to the scalar functions (from `std` or `libm`).
+
+use core::mem::MaybeUninit;
+use crate::sealed::{Slice, Float};
+use slicesimd_proc::simd_trait;
+
+/// Element-wise elementary functions.
+///
+/// See the [module documentation](self) for the accuracy of each function.
+#[simd_trait]
+pub trait VerticalMath: Slice<Element: Float> {
+    /// Replaces every element of the slice with the exponential function (`e^x`) of itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [0.0f32, 1.0, -1.0, f32::NEG_INFINITY];
+    /// values.exp_assign();
+    ///
+    /// for (x, y) in values.into_iter().zip([1.0, core::f32::consts::E, 1.0 / core::f32::consts::E, 0.0]) {
+    ///     assert!((x - y).abs() <= y * f32::EPSILON);
+    /// }
+    /// ```
+    #[inline]
+    fn exp_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::exp(*x)
+        }
+    }
+
+    /// Writes the exponential function (`e^x`) of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn exp_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::exp(*x));
+        }
+    }
+
+    /// Replaces every element of the slice with the natural logarithm of itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0f32, core::f32::consts::E, 0.0, -1.0];
+    /// values.ln_assign();
+    ///
+    /// assert_eq!(values[0], 0.0);
+    /// assert!((values[1] - 1.0).abs() <= f32::EPSILON);
+    /// assert_eq!(values[2], f32::NEG_INFINITY);
+    /// assert!(values[3].is_nan());
+    /// ```
+    #[inline]
+    fn ln_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::ln(*x)
+        }
+    }
+
+    /// Writes the natural logarithm of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn ln_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::ln(*x));
+        }
+    }
+
+    /// Replaces every element of the slice with the base 2 logarithm of itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0f64, 8.0, 0.5, 1024.0];
+    /// values.log2_assign();
+    /// assert_eq!(values, [0.0, 3.0, -1.0, 10.0]);
+    /// ```
+    #[inline]
+    fn log2_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::log2(*x)
+        }
+    }
+
+    /// Writes the base 2 logarithm of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn log2_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::log2(*x));
+        }
+    }
+
+    /// Replaces every element of the slice with the sine (in radians) of itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// use core::f32::consts::*;
+    ///
+    /// let mut values = [0.0f32, FRAC_PI_2, -FRAC_PI_6, PI];
+    /// values.sin_assign();
+    ///
+    /// for (x, y) in values.into_iter().zip([0.0, 1.0, -0.5, 0.0]) {
+    ///     assert!((x - y).abs() <= 2.0 * f32::EPSILON);
+    /// }
+    /// ```
+    #[inline]
+    fn sin_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::sin(*x)
+        }
+    }
+
+    /// Writes the sine (in radians) of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn sin_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::sin(*x));
+        }
+    }
+
+    /// Replaces every element of the slice with the cosine (in radians) of itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// use core::f32::consts::*;
+    ///
+    /// let mut values = [0.0f32, PI, FRAC_PI_3, -FRAC_PI_2];
+    /// values.cos_assign();
+    ///
+    /// for (x, y) in values.into_iter().zip([1.0, -1.0, 0.5, 0.0]) {
+    ///     assert!((x - y).abs() <= 2.0 * f32::EPSILON);
+    /// }
+    /// ```
+    #[inline]
+    fn cos_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::cos(*x)
+        }
+    }
+
+    /// Writes the cosine (in radians) of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn cos_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::cos(*x));
+        }
+    }
+
+    /// Replaces every element of the slice with the hyperbolic tangent of itself.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [0.0f32, 0.5, -20.0, f32::INFINITY];
+    /// values.tanh_assign();
+    ///
+    /// assert_eq!(values[0], 0.0);
+    /// assert!((values[1] - 0.46211716).abs() <= f32::EPSILON);
+    /// assert_eq!(values[2..], [-1.0, 1.0]);
+    /// ```
+    #[inline]
+    fn tanh_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::tanh(*x)
+        }
+    }
+
+    /// Writes the hyperbolic tangent of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn tanh_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::tanh(*x));
+        }
+    }
+
+    /// Raises every element of the slice to the power of it's counterpart in `exp`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [2.0f64, -2.0, 4.0, 0.0];
+    /// values.pow_assign(&[10.0, 3.0, 0.5, -1.0]);
+    /// assert_eq!(values, [1024.0, -8.0, 2.0, f64::INFINITY]);
+    /// ```
+    #[binary]
+    #[inline]
+    unsafe fn pow_assign_unchecked (&mut self, exp: &Self) {
+        for (x, y) in self.iter_mut().zip(exp.iter()) {
+            *x = Float::powf(*x, *y)
+        }
+    }
+
+    /// Raises every element of the slice to the power of `exp`.
+    #[inline]
+    fn pow_assign_scalar (&mut self, exp: Self::Element) {
+        for x in self.iter_mut() {
+            *x = Float::powf(*x, exp)
+        }
+    }
+}
@@ -1,104 +1,1433 @@
-use core::ops::*;
+use core::{ops::*, mem::MaybeUninit};
+use crate::sealed::{Slice, Signed, Float, Integer, Wrapping, Selector};
+use num_traits::{PrimInt, AsPrimitive, FromPrimitive};
 use slicesimd_proc::simd_trait;
+use docfg::docfg;
+#[cfg(feature = "alloc")]
+use crate::mask::Mask;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "libm"))))]
+pub mod math;
+
+#[macro_use]
+pub(crate) mod simd;
 
 cfg_if::cfg_if! {
-    if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))] {
+    if #[cfg(feature = "portable")] {
+        mod portable;
+    } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))] {
         mod x86;
+    } else if #[cfg(all(target_arch = "aarch64", target_feature = "neon"))] {
+        mod neon;
+    } else if #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))] {
+        mod wasm;
+    } else {
+        mod scalar;
     }
 }
 
 #[simd_trait]
 pub trait VerticalAdd {
+    #[binary]
     #[inline]
-    fn add_assign (&mut self, rhs: &Self) {
-        if !self.add_assign_checked(rhs) {
-            panic!("Slice sizes don't match: {} v. {}", self.len(), rhs.len())
+    unsafe fn add_assign_unchecked (&mut self, rhs: &Self) {
+        for (x, y) in self.iter_mut().zip(rhs.iter()) {
+            *x = Wrapping::add(*x, *y)
         }
     }
 
+    /// Adds `rhs` to the slice, repeating `rhs` as many times as needed to cover it.
+    ///
+    /// # Panics
+    /// This method panics if `rhs` is empty, or if it's length doesn't evenly divide the slice's.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// // Interleaved RGB pixels, with a bias for every channel
+    /// let mut pixels = [10, 20, 30, 40, 50, 60];
+    /// pixels.add_assign_cyclic(&[1, 2, 3]);
+    /// assert_eq!(pixels, [11, 22, 33, 41, 52, 63]);
+    /// ```
+    #[binary(pattern(rhs))]
     #[inline]
-    fn add_assign_checked (&mut self, rhs: &Self) -> bool {
-        if self.len() != rhs.len() { return false }
-        unsafe { self.add_assign_unchecked(rhs) };
-        return true
+    unsafe fn add_assign_cyclic_unchecked (&mut self, rhs: &Self) {
+        for (x, y) in zip_cyclic(self, rhs) {
+            *x = Wrapping::add(*x, *y)
+        }
     }
- 
+}
+
+#[simd_trait]
+pub trait VerticalSub {
+    #[binary]
     #[inline]
-    unsafe fn add_assign_unchecked(&mut self, rhs: &Self) {
+    unsafe fn sub_assign_unchecked (&mut self, rhs: &Self) {
         for (x, y) in self.iter_mut().zip(rhs.iter()) {
-            x.add_assign(y)
+            *x = Wrapping::sub(*x, *y)
+        }
+    }
+
+    /// Subtracts `rhs` from the slice, repeating `rhs` as many times as needed to cover it.
+    ///
+    /// # Panics
+    /// This method panics if `rhs` is empty, or if it's length doesn't evenly divide the slice's.
+    #[binary(pattern(rhs))]
+    #[inline]
+    unsafe fn sub_assign_cyclic_unchecked (&mut self, rhs: &Self) {
+        for (x, y) in zip_cyclic(self, rhs) {
+            *x = Wrapping::sub(*x, *y)
         }
     }
 }
 
 #[simd_trait]
-pub trait VerticalSub {
+pub trait VerticalMul {
+    #[binary]
     #[inline]
-    fn sub_assign (&mut self, rhs: &Self) {
-        if !self.sub_assign_checked(rhs) {
-            panic!("Slice sizes don't match: {} v. {}", self.len(), rhs.len())
+    unsafe fn mul_assign_unchecked (&mut self, rhs: &Self) {
+        for (x, y) in self.iter_mut().zip(rhs.iter()) {
+            *x = Wrapping::mul(*x, *y)
         }
     }
 
+    /// Multiplies the slice by `rhs`, repeating `rhs` as many times as needed to cover it.
+    ///
+    /// # Panics
+    /// This method panics if `rhs` is empty, or if it's length doesn't evenly divide the slice's.
+    #[binary(pattern(rhs))]
     #[inline]
-    fn sub_assign_checked (&mut self, rhs: &Self) -> bool {
-        if self.len() != rhs.len() { return false }
-        unsafe { self.sub_assign_unchecked(rhs) };
-        return true
+    unsafe fn mul_assign_cyclic_unchecked (&mut self, rhs: &Self) {
+        for (x, y) in zip_cyclic(self, rhs) {
+            *x = Wrapping::mul(*x, *y)
+        }
     }
- 
+}
+
+#[simd_trait]
+pub trait VerticalDiv {
+    #[binary]
     #[inline]
-    unsafe fn sub_assign_unchecked(&mut self, rhs: &Self) {
+    unsafe fn div_assign_unchecked (&mut self, rhs: &Self) {
         for (x, y) in self.iter_mut().zip(rhs.iter()) {
-            x.sub_assign(y)
+            x.div_assign(y)
+        }
+    }
+
+    /// Divides the slice by `rhs`, repeating `rhs` as many times as needed to cover it.
+    ///
+    /// # Panics
+    /// This method panics if `rhs` is empty, or if it's length doesn't evenly divide the slice's.
+    #[binary(pattern(rhs))]
+    #[inline]
+    unsafe fn div_assign_cyclic_unchecked (&mut self, rhs: &Self) {
+        for (x, y) in zip_cyclic(self, rhs) {
+            x.div_assign(y)
         }
     }
 }
 
+/// Accumulation of many slices at once.
+///
+/// Every block of the slice is loaded and stored once, while all the sources are streamed through it, instead of once per source.
+/// Intermediate sums may be kept at a higher precision than the elements themselves.
 #[simd_trait]
-pub trait VerticalMul {
+pub trait VerticalAccumulate {
+    /// Adds every slice in `sources` to this one, in order.
+    ///
+    /// # Panics
+    /// This method panics if any of the sources has a different length than the slice.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut gradient = [0.0, 0.0, 0.0];
+    /// gradient.add_assign_many(&[&[1.0, 2.0, 3.0], &[0.5, 0.5, 0.5], &[-1.0, 0.0, 1.0]]);
+    /// assert_eq!(gradient, [0.5, 2.5, 4.5]);
+    /// ```
+    #[binary(each(sources))]
     #[inline]
-    fn mul_assign (&mut self, rhs: &Self) {
-        if !self.mul_assign_checked(rhs) {
-            panic!("Slice sizes don't match: {} v. {}", self.len(), rhs.len())
+    unsafe fn add_assign_many_unchecked (&mut self, sources: &[&Self]) {
+        for source in sources {
+            for (x, y) in self.iter_mut().zip(source.iter()) {
+                *x = Wrapping::add(*x, *y)
+            }
         }
     }
+}
 
+/// Element-wise mean of many slices.
+#[simd_trait]
+pub trait VerticalMean: Slice<Element: Float + FromPrimitive> {
+    /// Replaces every element of the slice with the mean of it's counterparts in `sources`.
+    ///
+    /// # Panics
+    /// This method panics if `sources` is empty, or if any of them has a different length than the slice.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut mean = [0.0; 3];
+    /// mean.mean_of(&[&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0], &[2.0, 5.0, 8.0], &[0.0, -1.0, 0.0]]);
+    /// assert_eq!(mean, [1.5, 2.0, 3.0]);
+    /// ```
+    #[binary(nonempty(sources), each(sources))]
     #[inline]
-    fn mul_assign_checked (&mut self, rhs: &Self) -> bool {
-        if self.len() != rhs.len() { return false }
-        unsafe { self.mul_assign_unchecked(rhs) };
-        return true
+    unsafe fn mean_of_unchecked (&mut self, sources: &[&Self]) {
+        let count = Self::Element::from_usize(sources.len()).unwrap();
+
+        for (x, y) in self.iter_mut().zip(sources.get_unchecked(0).iter()) {
+            *x = *y
+        }
+        for source in sources.get_unchecked(1..) {
+            for (x, y) in self.iter_mut().zip(source.iter()) {
+                x.add_assign(y)
+            }
+        }
+        for x in self.iter_mut() {
+            x.div_assign(count)
+        }
     }
- 
+}
+
+/// Element-wise minimum.
+///
+/// For floating point slices, if only one of the compared values is NaN, the other one is kept (like [`f32::min`]).
+/// As with [`f32::min`], the result of comparing `-0.0` with `0.0` may be either of them, and may change with the
+/// backend and the [`width`](crate::width) cap.
+#[simd_trait]
+pub trait VerticalMin {
+    /// Replaces every element of the slice with the minimum between itself and it's counterpart in `rhs`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0, f32::NAN, 3.0, 4.0, 5.0];
+    /// values.min_assign(&[5.0, 4.0, 3.0, 2.0, f32::NAN]);
+    /// assert_eq!(values, [1.0, 4.0, 3.0, 2.0, 5.0]);
+    /// ```
+    #[binary]
     #[inline]
-    unsafe fn mul_assign_unchecked(&mut self, rhs: &Self) {
+    unsafe fn min_assign_unchecked (&mut self, rhs: &Self) {
         for (x, y) in self.iter_mut().zip(rhs.iter()) {
-            x.mul_assign(y)
+            *x = min(*x, *y)
+        }
+    }
+
+    /// Replaces every element of the slice with the minimum between itself and `rhs`.
+    #[inline]
+    fn min_assign_scalar (&mut self, rhs: Self::Element) {
+        for x in self.iter_mut() {
+            *x = min(*x, rhs)
         }
     }
 }
 
+/// Element-wise maximum.
+///
+/// For floating point slices, if only one of the compared values is NaN, the other one is kept (like [`f32::max`]).
+/// As with [`f32::max`], the result of comparing `-0.0` with `0.0` may be either of them, and may change with the
+/// backend and the [`width`](crate::width) cap.
 #[simd_trait]
-pub trait VerticalDiv {
+pub trait VerticalMax {
+    /// Replaces every element of the slice with the maximum between itself and it's counterpart in `rhs`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1u64, 2, 3, u64::MAX, 5];
+    /// values.max_assign(&[5, 4, 3, 2, 1]);
+    /// assert_eq!(values, [5, 4, 3, u64::MAX, 5]);
+    /// ```
+    #[binary]
+    #[inline]
+    unsafe fn max_assign_unchecked (&mut self, rhs: &Self) {
+        for (x, y) in self.iter_mut().zip(rhs.iter()) {
+            *x = max(*x, *y)
+        }
+    }
+
+    /// Replaces every element of the slice with the maximum between itself and `rhs`.
     #[inline]
-    fn div_assign (&mut self, rhs: &Self) {
-        if !self.div_assign_checked(rhs) {
-            panic!("Slice sizes don't match: {} v. {}", self.len(), rhs.len())
+    fn max_assign_scalar (&mut self, rhs: Self::Element) {
+        for x in self.iter_mut() {
+            *x = max(*x, rhs)
         }
     }
+}
 
+/// Element-wise clamping into a range.
+///
+/// For floating point slices, NaN elements are left as NaN (like [`f32::clamp`]).
+#[simd_trait]
+pub trait VerticalClamp {
+    /// Restricts every element of the slice to the range `lo..=hi`.
+    ///
+    /// # Panics
+    /// This method panics if `lo > hi`, or if either of them is NaN.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-3.0, 0.5, f32::NAN, 7.0];
+    /// values.clamp_assign(0.0, 1.0);
+    ///
+    /// assert_eq!(values[..2], [0.0, 0.5]);
+    /// assert!(values[2].is_nan());
+    /// assert_eq!(values[3], 1.0);
+    /// ```
+    #[binary(bounds(lo, hi))]
     #[inline]
-    fn div_assign_checked (&mut self, rhs: &Self) -> bool {
-        if self.len() != rhs.len() { return false }
-        unsafe { self.div_assign_unchecked(rhs) };
-        return true
+    unsafe fn clamp_assign_unchecked (&mut self, lo: Self::Element, hi: Self::Element) {
+        for x in self.iter_mut() {
+            if *x < lo {
+                *x = lo
+            } else if *x > hi {
+                *x = hi
+            }
+        }
     }
- 
+}
+
+/// Element-wise absolute value.
+///
+/// On slices of signed integers, the absolute value of `MIN` wraps around to `MIN`.
+#[simd_trait]
+pub trait VerticalAbs: Slice<Element: Signed> {
+    /// Replaces every element of the slice with it's absolute value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-1, 2, -3, i32::MIN];
+    /// values.abs_assign();
+    /// assert_eq!(values, [1, 2, 3, i32::MIN]);
+    /// ```
     #[inline]
-    unsafe fn div_assign_unchecked(&mut self, rhs: &Self) {
-        for (x, y) in self.iter_mut().zip(rhs.iter()) {
-            x.div_assign(y)
+    fn abs_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Signed::abs(*x)
+        }
+    }
+
+    /// Writes the absolute value of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn abs_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Signed::abs(*x));
+        }
+    }
+}
+
+/// Element-wise negation.
+///
+/// On slices of signed integers, the negation of `MIN` wraps around to `MIN`.
+#[simd_trait]
+pub trait VerticalNeg: Slice<Element: Signed> {
+    /// Replaces every element of the slice with it's negation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let values = [-1.0, 2.0, -3.0, 0.0];
+    /// let mut out = [MaybeUninit::uninit(); 4];
+    /// values.neg_into(&mut out);
+    ///
+    /// let out = out.map(|x| unsafe { x.assume_init() });
+    /// assert_eq!(out, [1.0, -2.0, 3.0, -0.0]);
+    /// ```
+    #[inline]
+    fn neg_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Signed::neg(*x)
+        }
+    }
+
+    /// Writes the negation of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn neg_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Signed::neg(*x));
+        }
+    }
+}
+
+/// Element-wise square root.
+#[docfg(any(feature = "std", feature = "libm"))]
+#[simd_trait]
+pub trait VerticalSqrt: Slice<Element: Float> {
+    /// Replaces every element of the slice with it's square root.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [0.0, 1.0, 4.0, 9.0, 2.25];
+    /// values.sqrt_assign();
+    /// assert_eq!(values, [0.0, 1.0, 2.0, 3.0, 1.5]);
+    /// ```
+    #[inline]
+    fn sqrt_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::sqrt(*x)
+        }
+    }
+
+    /// Writes the square root of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn sqrt_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::sqrt(*x));
+        }
+    }
+}
+
+/// Element-wise approximate reciprocal square root.
+#[docfg(any(feature = "std", feature = "libm"))]
+#[simd_trait]
+pub trait VerticalRsqrt: Slice<Element: Float> {
+    /// Replaces every element of the slice with an approximation of it's reciprocal square root.
+    ///
+    /// The relative error of the approximation is at most `1.5 * 2^-12`. If `refine` is `true`, a Newton-Raphson step is applied to it,
+    /// bringing the relative error under `2^-21`. Implementations without a dedicated instruction compute the exact value instead.
+    ///
+    /// Zeros, infinities and NaN give the same result as `1.0 / x.sqrt()`, whether refined or not, while subnormals are
+    /// approximated like any other element.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0f32, 4.0, 16.0, 0.25];
+    /// values.rsqrt_assign(true);
+    ///
+    /// for (x, y) in values.into_iter().zip([1.0, 0.5, 0.25, 2.0]) {
+    ///     assert!((x - y).abs() <= y * 1e-6);
+    /// }
+    ///
+    /// let specials = [0.0f32, -0.0, f32::INFINITY, f32::NEG_INFINITY, f32::NAN, -1.0, 1.0559455e-38, -1.0559455e-38];
+    /// for refine in [false, true] {
+    ///     let mut values = specials.repeat(8);
+    ///     values.rsqrt_assign(refine);
+    ///
+    ///     for (x, y) in specials.iter().cycle().zip(values) {
+    ///         let exact = 1.0 / x.sqrt();
+    ///         let close = exact.is_finite() && (y - exact).abs() <= exact * 4e-4;
+    ///         assert!(y == exact || close || (y.is_nan() && exact.is_nan()), "rsqrt({x}) = {y}");
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[allow(unused_variables)]
+    fn rsqrt_assign (&mut self, refine: bool) {
+        for x in self.iter_mut() {
+            *x = Float::recip(Float::sqrt(*x))
+        }
+    }
+
+    /// Writes an approximation of the reciprocal square root of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    #[allow(unused_variables)]
+    unsafe fn rsqrt_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>], refine: bool) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::recip(Float::sqrt(*x)));
+        }
+    }
+}
+
+/// Element-wise approximate reciprocal.
+#[simd_trait]
+pub trait VerticalRecip: Slice<Element: Float> {
+    /// Replaces every element of the slice with an approximation of it's reciprocal.
+    ///
+    /// The relative error of the approximation is at most `1.5 * 2^-12`. If `refine` is `true`, a Newton-Raphson step is applied to it,
+    /// bringing the relative error under `2^-21`. Implementations without a dedicated instruction compute the exact value instead.
+    ///
+    /// Zeros, infinities and NaN give the same result as `1.0 / x`, whether refined or not, while subnormals are
+    /// approximated like any other element.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0f32, 4.0, -8.0, 0.5];
+    /// values.recip_assign(true);
+    ///
+    /// for (x, y) in values.into_iter().zip([1.0, 0.25, -0.125, 2.0]) {
+    ///     assert!((x - y).abs() <= y.abs() * 1e-6);
+    /// }
+    ///
+    /// let specials = [0.0f32, -0.0, f32::INFINITY, f32::NEG_INFINITY, f32::NAN, -2.0, 1.0559455e-38, -1.0559455e-38];
+    /// for refine in [false, true] {
+    ///     let mut values = specials.repeat(8);
+    ///     values.recip_assign(refine);
+    ///
+    ///     for (x, y) in specials.iter().cycle().zip(values) {
+    ///         let exact = 1.0 / x;
+    ///         let close = exact.is_finite() && (y - exact).abs() <= exact.abs() * 4e-4;
+    ///         assert!(y == exact || close || (y.is_nan() && exact.is_nan()), "recip({x}) = {y}");
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[allow(unused_variables)]
+    fn recip_assign (&mut self, refine: bool) {
+        for x in self.iter_mut() {
+            *x = Float::recip(*x)
+        }
+    }
+
+    /// Writes an approximation of the reciprocal of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    #[allow(unused_variables)]
+    unsafe fn recip_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>], refine: bool) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::recip(*x));
+        }
+    }
+}
+
+/// Element-wise square.
+#[simd_trait]
+pub trait VerticalSquare: Slice<Element: Float> {
+    /// Replaces every element of the slice with it's square.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-1.5, 2.0, 3.0];
+    /// values.square_assign();
+    /// assert_eq!(values, [2.25, 4.0, 9.0]);
+    /// ```
+    #[inline]
+    fn square_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = *x * *x
+        }
+    }
+
+    /// Writes the square of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn square_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(*x * *x);
+        }
+    }
+}
+
+/// Element-wise rounding to integral values.
+///
+/// Like their scalar counterparts, these operations preserve the sign of zero, infinities and NaN.
+#[simd_trait]
+pub trait VerticalRound: Slice<Element: Float> {
+    /// Rounds every element of the slice down, to the largest integer less than or equal to it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-1.5, -0.5, 0.5, 1.5];
+    /// values.floor_assign();
+    /// assert_eq!(values, [-2.0, -1.0, 0.0, 1.0]);
+    /// ```
+    #[inline]
+    fn floor_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::floor(*x)
+        }
+    }
+
+    /// Writes every element of the slice, rounded down, into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn floor_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::floor(*x));
+        }
+    }
+
+    /// Rounds every element of the slice up, to the smallest integer greater than or equal to it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-1.5, -0.5, 0.5, 1.5];
+    /// values.ceil_assign();
+    /// assert_eq!(values, [-1.0, -0.0, 1.0, 2.0]);
+    /// ```
+    #[inline]
+    fn ceil_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::ceil(*x)
+        }
+    }
+
+    /// Writes every element of the slice, rounded up, into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn ceil_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::ceil(*x));
+        }
+    }
+
+    /// Rounds every element of the slice towards zero, discarding it's fractional part.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-1.5, -0.5, 0.5, 1.5];
+    /// values.trunc_assign();
+    /// assert_eq!(values, [-1.0, -0.0, 0.0, 1.0]);
+    /// ```
+    #[inline]
+    fn trunc_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::trunc(*x)
+        }
+    }
+
+    /// Writes the integer part of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn trunc_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::trunc(*x));
+        }
+    }
+
+    /// Rounds every element of the slice to the nearest integer, with half-way cases rounded away from zero (like [`f32::round`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-1.5, -0.5, 0.5, 2.5];
+    /// values.round_assign();
+    /// assert_eq!(values, [-2.0, -1.0, 1.0, 3.0]);
+    /// ```
+    #[inline]
+    fn round_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::round(*x)
+        }
+    }
+
+    /// Writes every element of the slice, rounded to the nearest integer (with half-way cases rounded away from zero), into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn round_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::round(*x));
         }
     }
-}
\ No newline at end of file
+
+    /// Rounds every element of the slice to the nearest integer, with half-way cases rounded to the nearest even integer (like [`f32::round_ties_even`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [-1.5, -0.5, 0.5, 2.5];
+    /// values.round_ties_even_assign();
+    /// assert_eq!(values, [-2.0, -0.0, 0.0, 2.0]);
+    /// ```
+    #[inline]
+    fn round_ties_even_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = Float::round_ties_even(*x)
+        }
+    }
+
+    /// Writes every element of the slice, rounded to the nearest integer (with half-way cases rounded to the nearest even integer), into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn round_ties_even_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(Float::round_ties_even(*x));
+        }
+    }
+}
+
+/// Pairs every element of `lhs` with it's counterpart in `rhs`, repeated as many times as needed.
+#[inline]
+fn zip_cyclic<'a, S: ?Sized + Slice> (lhs: &'a mut S, rhs: &'a S) -> impl Iterator<Item = (&'a mut S::Element, &'a S::Element)> {
+    let mut pattern = rhs.iter();
+    lhs.iter_mut().map(move |x| match pattern.next() {
+        Some(y) => (x, y),
+        None => {
+            pattern = rhs.iter();
+            (x, pattern.next().expect("Empty pattern"))
+        }
+    })
+}
+
+/// Returns `true` if `x` isn't comparable to itself (i.e. it's NaN).
+#[inline]
+fn is_nan<T: PartialOrd> (x: &T) -> bool {
+    return x.partial_cmp(x).is_none()
+}
+
+#[inline]
+fn min<T: PartialOrd> (x: T, y: T) -> T {
+    if y < x || is_nan(&x) { y } else { x }
+}
+
+#[inline]
+fn max<T: PartialOrd> (x: T, y: T) -> T {
+    if y > x || is_nan(&x) { y } else { x }
+}
+
+/// Fused multiply-add operations.
+///
+/// On floating point slices, these operations are computed with a single rounding step when the target supports FMA instructions
+/// (`target_feature = "fma"`, or NEON), and with a multiplication followed by an addition (rounding twice) otherwise.
+/// The same holds when the [`width`](crate::width) cap rules out SIMD, as long as the `std` or `libm` features are enabled,
+/// without which the scalar fallback always rounds twice. Integer slices wrap around on overflow.
+#[simd_trait]
+pub trait VerticalMulAdd {
+    /// Multiplies every element of the slice by it's counterpart in `a`, and then adds it's counterpart in `b` (`self = self * a + b`).
+    ///
+    /// # Panics
+    /// This method panics if the slices have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0, 2.0, 3.0, 4.0, 5.0];
+    /// values.mul_add_assign(&[2.0; 5], &[1.0, 1.0, 1.0, 1.0, 0.0]);
+    /// assert_eq!(values, [3.0, 5.0, 7.0, 9.0, 10.0]);
+    /// ```
+    #[binary(a, b)]
+    #[inline]
+    unsafe fn mul_add_assign_unchecked (&mut self, a: &Self, b: &Self) {
+        for ((x, a), b) in self.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *x = Wrapping::mul_add(*x, *a, *b)
+        }
+    }
+
+    /// Adds `x` scaled by `alpha` to the slice (`self = alpha * x + self`).
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0, 2.0, 3.0];
+    /// values.axpy(0.5, &[2.0, 4.0, 6.0]);
+    /// assert_eq!(values, [2.0, 4.0, 6.0]);
+    /// ```
+    #[binary(x)]
+    #[inline]
+    unsafe fn axpy_unchecked (&mut self, alpha: Self::Element, x: &Self) {
+        for (y, x) in self.iter_mut().zip(x.iter()) {
+            *y = Wrapping::mul_add(alpha, *x, *y)
+        }
+    }
+
+    /// Applies an affine transformation to every element of the slice (`self = self * scale + offset`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [0.0, 1.0, 2.0, 3.0];
+    /// values.affine_assign(2.0, -1.0);
+    /// assert_eq!(values, [-1.0, 1.0, 3.0, 5.0]);
+    /// ```
+    #[inline]
+    fn affine_assign (&mut self, scale: Self::Element, offset: Self::Element) {
+        for x in self.iter_mut() {
+            *x = Wrapping::mul_add(*x, scale, offset)
+        }
+    }
+}
+
+macro_rules! bitwise_trait {
+    ($(
+        $(#[$attr:meta])*
+        pub trait $trait:ident ($desc:literal) {
+            $unchecked:ident, $scalar:ident => |$x:ident, $y:ident| $f:expr
+        }
+    )+) => {
+        $(
+            $(#[$attr])*
+            #[simd_trait]
+            pub trait $trait: Slice<Element: Integer> {
+                #[doc = concat!("Replaces every element of the slice with the ", $desc, " of itself and it's counterpart in `rhs`.")]
+                ///
+                /// # Panics
+                /// This method panics if both slices have different lengths.
+                #[binary]
+                #[inline]
+                unsafe fn $unchecked (&mut self, rhs: &Self) {
+                    for (x, y) in self.iter_mut().zip(rhs.iter()) {
+                        let ($x, $y) = (*x, *y);
+                        *x = $f
+                    }
+                }
+
+                #[doc = concat!("Replaces every element of the slice with the ", $desc, " of itself and `rhs`.")]
+                #[inline]
+                fn $scalar (&mut self, rhs: Self::Element) {
+                    for x in self.iter_mut() {
+                        let ($x, $y) = (*x, rhs);
+                        *x = $f
+                    }
+                }
+            }
+        )+
+    };
+}
+
+bitwise_trait! {
+    /// Element-wise bitwise and.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [0b1100u8, 0b1010, 0xff];
+    /// values.and_assign(&[0b1010, 0b0110, 0x0f]);
+    /// assert_eq!(values, [0b1000, 0b0010, 0x0f]);
+    ///
+    /// values.and_assign_scalar(0b11);
+    /// assert_eq!(values, [0b00, 0b10, 0b11]);
+    /// ```
+    pub trait VerticalAnd ("bitwise and") {
+        and_assign_unchecked, and_assign_scalar => |x, y| x & y
+    }
+
+    /// Element-wise bitwise or.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut bitmap = [0u64, 1 << 63, 0b101];
+    /// bitmap.or_assign(&[1, 1, 0b010]);
+    /// assert_eq!(bitmap, [1, (1 << 63) | 1, 0b111]);
+    /// ```
+    pub trait VerticalOr ("bitwise or") {
+        or_assign_unchecked, or_assign_scalar => |x, y| x | y
+    }
+
+    /// Element-wise bitwise exclusive or.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1i32, -1, 0];
+    /// values.xor_assign_scalar(-1);
+    /// assert_eq!(values, [!1, 0, -1]);
+    /// ```
+    pub trait VerticalXor ("bitwise exclusive or") {
+        xor_assign_unchecked, xor_assign_scalar => |x, y| x ^ y
+    }
+
+    /// Element-wise bitwise and with the complement of the right-hand side (`self & !rhs`), to clear the bits of a mask.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut bitmap = [0b1111u16, 0b1010, 0];
+    /// bitmap.andnot_assign(&[0b0101, 0b1000, 0b1]);
+    /// assert_eq!(bitmap, [0b1010, 0b0010, 0]);
+    /// ```
+    pub trait VerticalAndNot ("bitwise and with the complement") {
+        andnot_assign_unchecked, andnot_assign_scalar => |x, y| x & !y
+    }
+}
+
+/// Element-wise bitwise complement.
+#[simd_trait]
+pub trait VerticalNot: Slice<Element: Integer> {
+    /// Replaces every element of the slice with it's bitwise complement.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [0u8, 0x0f, 0xff];
+    /// values.not_assign();
+    /// assert_eq!(values, [0xff, 0xf0, 0]);
+    /// ```
+    #[inline]
+    fn not_assign (&mut self) {
+        for x in self.iter_mut() {
+            *x = !*x
+        }
+    }
+
+    /// Writes the bitwise complement of every element of the slice into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    #[unary]
+    #[inline]
+    unsafe fn not_into_unchecked (&self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(!*x);
+        }
+    }
+}
+
+macro_rules! shift_trait {
+    (
+        $(#[$attr:meta])*
+        pub trait $trait:ident {
+            $(
+                ($desc:literal) $unchecked:ident, $scalar:ident => $f:path
+            );+ $(;)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[simd_trait]
+        pub trait $trait: Slice<Element: Integer> {
+            $(
+                #[doc = concat!("Replaces every element of the slice with itself ", $desc, " it's counterpart in `counts`.")]
+                ///
+                /// # Panics
+                /// This method panics if both slices have different lengths.
+                #[binary]
+                #[inline]
+                unsafe fn $unchecked (&mut self, counts: &Self) {
+                    for (x, n) in self.iter_mut().zip(counts.iter()) {
+                        *x = $f(*x, Integer::count(*n))
+                    }
+                }
+
+                #[doc = concat!("Replaces every element of the slice with itself ", $desc, " `n`.")]
+                #[inline]
+                fn $scalar (&mut self, n: u32) {
+                    for x in self.iter_mut() {
+                        *x = $f(*x, n)
+                    }
+                }
+            )+
+        }
+    };
+}
+
+shift_trait! {
+    /// Element-wise bit shifts.
+    ///
+    /// Right shifts are arithmetic on signed slices and logical on unsigned ones, like the `>>` operator.
+    /// Counts are taken modulo the width of the elements (like [`u32::wrapping_shl`]), and constant counts
+    /// compile down to immediate shifts once inlined.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1u32, 2, 3, u32::MAX];
+    /// values.shl_assign(&[0, 1, 31, 4]);
+    /// assert_eq!(values, [1, 4, 1 << 31, u32::MAX << 4]);
+    ///
+    /// let mut values = [-64i16, 64, -1];
+    /// values.shr_assign_scalar(3);
+    /// assert_eq!(values, [-8, 8, -1]);
+    /// ```
+    pub trait VerticalShift {
+        ("shifted left by") shl_assign_unchecked, shl_assign_scalar => Integer::wrapping_shl;
+        ("shifted right by") shr_assign_unchecked, shr_assign_scalar => Integer::wrapping_shr
+    }
+}
+
+shift_trait! {
+    /// Element-wise bit rotations.
+    ///
+    /// Counts are taken modulo the width of the elements.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [0x8000_0001u32, 0x1234_5678];
+    /// values.rotate_left_assign_scalar(4);
+    /// assert_eq!(values, [0x0000_0018, 0x2345_6781]);
+    ///
+    /// values.rotate_right_assign(&[4, 36]);
+    /// assert_eq!(values, [0x8000_0001, 0x1234_5678]);
+    /// ```
+    pub trait VerticalRotate {
+        ("rotated left by") rotate_left_assign_unchecked, rotate_left_assign_scalar => PrimInt::rotate_left;
+        ("rotated right by") rotate_right_assign_unchecked, rotate_right_assign_scalar => PrimInt::rotate_right
+    }
+}
+
+/// Element-wise conversion between slices of different element types.
+///
+/// Conversions follow the semantics of the `as` operator: integers are zero- or sign-extended (depending on their source type)
+/// and truncated, floats are rounded to nearest, and float-to-integer conversions round towards zero and saturate
+/// (with NaN converting to zero).
+#[simd_trait]
+pub trait VerticalConvert<T: 'static + Copy>: Slice<Element: AsPrimitive<T>> {
+    /// Writes every element of the slice, converted with `as`, into `out`.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use slicesimd::*;
+    ///
+    /// let pixels = [0u8, 128, 255];
+    /// let mut weights = [MaybeUninit::<f32>::uninit(); 3];
+    /// pixels.convert_into(&mut weights);
+    ///
+    /// let mut weights = weights.map(|x| unsafe { x.assume_init() });
+    /// weights.add_assign(&[0.0, 128.0, 255.0]);
+    /// assert_eq!(weights, [0.0, 256.0, 510.0]);
+    ///
+    /// // Float to integer conversions saturate
+    /// let mut pixels = [MaybeUninit::<u8>::uninit(); 3];
+    /// weights.convert_into(&mut pixels);
+    /// assert_eq!(pixels.map(|x| unsafe { x.assume_init() }), [0, 255, 255]);
+    /// ```
+    #[unary]
+    #[inline]
+    unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<T>]) {
+        for (x, y) in self.iter().zip(out.iter_mut()) {
+            y.write(x.as_());
+        }
+    }
+}
+
+macro_rules! cmp_trait {
+    (
+        $(#[$attr:meta])*
+        pub trait $trait:ident {
+            $(
+                $op:tt ($desc:literal) => $cmp:ident, $cmp_scalar:ident, $into:ident, $unchecked:ident, $scalar_unchecked:ident
+            );+ $(;)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[simd_trait]
+        pub trait $trait {
+            $(
+                #[doc = concat!("Returns a mask with the bits set where the elements of the slice are ", $desc, " their counterparts in `rhs`.")]
+                ///
+                /// # Panics
+                /// This method panics if both slices have different lengths.
+                #[docfg(feature = "alloc")]
+                #[inline]
+                fn $cmp (&self, rhs: &Self) -> Mask {
+                    let mut mask = Mask::new(self.len());
+                    self.$into(rhs, &mut mask.words);
+                    return mask
+                }
+
+                #[doc = concat!("Returns a mask with the bits set where the elements of the slice are ", $desc, " `rhs`.")]
+                #[docfg(feature = "alloc")]
+                #[inline]
+                fn $cmp_scalar (&self, rhs: Self::Element) -> Mask {
+                    let mut mask = Mask::new(self.len());
+                    unsafe { self.$scalar_unchecked(rhs, &mut mask.words) };
+                    return mask
+                }
+
+                #[doc = concat!("Sets the bits of `out` where the elements of the slice are ", $desc, " their counterparts in `rhs`, and clears the others.")]
+                ///
+                /// # Panics
+                /// This method panics if both slices have different lengths, or if `out` is shorter than [`words_for(self.len())`](crate::mask::words_for).
+                #[binary(rhs, words(out))]
+                #[inline]
+                unsafe fn $unchecked (&self, rhs: &Self, out: &mut [u64]) {
+                    let mut rhs = rhs.iter();
+                    pack_into(self.iter(), out, |x| x $op rhs.next().unwrap_unchecked())
+                }
+
+                #[doc = concat!("Sets the bits of `out` where the elements of the slice are ", $desc, " `rhs`, and clears the others.")]
+                ///
+                /// # Panics
+                /// This method panics if `out` is shorter than [`words_for(self.len())`](crate::mask::words_for).
+                #[binary(words(out))]
+                #[inline]
+                unsafe fn $scalar_unchecked (&self, rhs: Self::Element, out: &mut [u64]) {
+                    pack_into(self.iter(), out, |x| *x $op rhs)
+                }
+            )+
+        }
+    };
+}
+
+cmp_trait! {
+    /// Element-wise comparisons, producing packed bitmasks (see the [`mask`](crate::mask) module).
+    ///
+    /// Like their scalar counterparts, comparisons involving NaN are `false`, except for the `ne` ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let values = [1.0, 2.0, f32::NAN, 4.0, 5.0];
+    /// let mut mask = [0u64];
+    ///
+    /// values.cmp_lt_into(&[2.0, 2.0, 2.0, 2.0, 6.0], &mut mask);
+    /// assert_eq!(mask, [0b10001]);
+    ///
+    /// values.cmp_ne_scalar_into(4.0, &mut mask);
+    /// assert_eq!(mask, [0b10111]);
+    /// ```
+    pub trait VerticalCmp {
+        == ("equal to") => cmp_eq, cmp_eq_scalar, cmp_eq_into, cmp_eq_into_unchecked, cmp_eq_scalar_into_unchecked;
+        != ("not equal to") => cmp_ne, cmp_ne_scalar, cmp_ne_into, cmp_ne_into_unchecked, cmp_ne_scalar_into_unchecked;
+        < ("less than") => cmp_lt, cmp_lt_scalar, cmp_lt_into, cmp_lt_into_unchecked, cmp_lt_scalar_into_unchecked;
+        <= ("less than or equal to") => cmp_le, cmp_le_scalar, cmp_le_into, cmp_le_into_unchecked, cmp_le_scalar_into_unchecked;
+        > ("greater than") => cmp_gt, cmp_gt_scalar, cmp_gt_into, cmp_gt_into_unchecked, cmp_gt_scalar_into_unchecked;
+        >= ("greater than or equal to") => cmp_ge, cmp_ge_scalar, cmp_ge_into, cmp_ge_into_unchecked, cmp_ge_scalar_into_unchecked;
+    }
+}
+
+/// Packs the results of `f` over `iter` into `out`, clearing the bits past the last element.
+#[inline]
+unsafe fn pack_into<I: Iterator> (iter: I, out: &mut [u64], mut f: impl FnMut(I::Item) -> bool) {
+    let mut word = 0;
+    let mut len = 0;
+
+    for x in iter {
+        word |= (f(x) as u64) << (len % 64);
+        len += 1;
+        if len % 64 == 0 {
+            *out.get_unchecked_mut(len / 64 - 1) = word;
+            word = 0;
+        }
+    }
+
+    if len % 64 != 0 {
+        *out.get_unchecked_mut(len / 64) = word;
+    }
+}
+
+/// Element-wise selection between two slices, driven by a mask.
+///
+/// Masks may be packed words (`[u64]` or [`Mask`], as produced by [`VerticalCmp`]) or `[bool]`.
+/// Packed words must hold at least [`words_for(self.len())`](crate::mask::words_for) words, while other masks must cover exactly
+/// as many elements as the slice.
+#[simd_trait]
+pub trait VerticalSelect {
+    /// Replaces the elements of the slice with their counterparts in `rhs` wherever `mask` is set.
+    ///
+    /// # Panics
+    /// This method panics if both slices have different lengths, or if `mask` doesn't cover the slice.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1, 2, 3, 4];
+    /// values.blend_assign(&[true, false, false, true][..], &[10, 20, 30, 40]);
+    /// assert_eq!(values, [10, 2, 3, 40]);
+    ///
+    /// values.blend_assign(&[0b0110][..], &[0, 0, 0, 0]);
+    /// assert_eq!(values, [10, 0, 0, 40]);
+    /// ```
+    #[binary(rhs, covers(mask))]
+    #[inline]
+    unsafe fn blend_assign_unchecked<M: ?Sized + Selector> (&mut self, mask: &M, rhs: &Self) {
+        for (i, (x, y)) in self.iter_mut().zip(rhs.iter()).enumerate() {
+            if mask.bits(i, 1) != 0 {
+                *x = *y
+            }
+        }
+    }
+
+    /// Replaces the elements of the slice with `rhs` wherever `mask` is set.
+    ///
+    /// # Panics
+    /// This method panics if `mask` doesn't cover the slice.
+    ///
+    /// # Example
+    /// ```rust
+    /// use slicesimd::*;
+    ///
+    /// let mut values = [1.0, -2.0, f32::NAN, 4.0];
+    /// let mask = values.cmp_ge_scalar(0.0);
+    /// values.blend_assign_scalar(&!mask, 0.0);
+    /// assert_eq!(values, [1.0, 0.0, 0.0, 4.0]);
+    /// ```
+    #[binary(covers(mask))]
+    #[inline]
+    unsafe fn blend_assign_scalar_unchecked<M: ?Sized + Selector> (&mut self, mask: &M, rhs: Self::Element) {
+        for (i, x) in self.iter_mut().enumerate() {
+            if mask.bits(i, 1) != 0 {
+                *x = rhs
+            }
+        }
+    }
+
+    /// Writes the elements of the slice into `out` wherever `mask` is set, and their counterparts in `if_false` everywhere else.
+    ///
+    /// # Panics
+    /// This method panics if the slices have different lengths, or if `mask` doesn't cover them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use slicesimd::*;
+    ///
+    /// let x = [1, 5, 3, 7];
+    /// let y = [4, 4, 4, 4];
+    ///
+    /// let mut out = [MaybeUninit::uninit(); 4];
+    /// x.select_into(&x.cmp_gt(&y), &y, &mut out);
+    /// assert_eq!(out.map(|x| unsafe { x.assume_init() }), [4, 5, 4, 7]);
+    /// ```
+    #[unary(if_false, out, covers(mask))]
+    #[inline]
+    unsafe fn select_into_unchecked<M: ?Sized + Selector> (&self, mask: &M, if_false: &Self, out: &mut [MaybeUninit<Self::Element>]) {
+        for (i, ((x, y), z)) in self.iter().zip(if_false.iter()).zip(out.iter_mut()).enumerate() {
+            z.write(if mask.bits(i, 1) != 0 { *x } else { *y });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::mem::MaybeUninit;
+    use num_traits::AsPrimitive;
+    use crate::*;
+    use crate::sealed::Wrapping;
+    use crate::width::for_each_width;
+
+    /// Lengths covering the empty slice, the tails of every register and a few full blocks
+    const LENS: core::ops::RangeInclusive<usize> = 0..=70;
+
+    /// Pseudo-random elements, spread over the whole range of `T`
+    fn values<T> (len: usize, seed: u64, from_bits: impl Fn(u64) -> T) -> Vec<T> {
+        return (0..len as u64).map(|i| from_bits((i + seed).wrapping_mul(0x9E37_79B9_7F4A_7C15))).collect()
+    }
+
+    #[test]
+    fn integers_wrap () {
+        for_each_width(|bits| {
+            for len in LENS {
+                let (lhs, rhs) = (values(len, 0, |x| x as i8), values(len, 1, |x| x as i8));
+
+                let mut result = lhs.clone();
+                result.add_assign(&rhs);
+                assert!(result.iter().zip(lhs.iter().zip(&rhs)).all(|(&z, (&x, &y))| z == x.wrapping_add(y)), "add under {bits:?}");
+
+                let mut result = lhs.clone();
+                result.sub_assign(&rhs);
+                assert!(result.iter().zip(lhs.iter().zip(&rhs)).all(|(&z, (&x, &y))| z == x.wrapping_sub(y)), "sub under {bits:?}");
+
+                let mut result = lhs.clone();
+                result.mul_assign(&rhs);
+                assert!(result.iter().zip(lhs.iter().zip(&rhs)).all(|(&z, (&x, &y))| z == x.wrapping_mul(y)), "mul under {bits:?}");
+
+                let mut result = lhs.clone();
+                result.add_assign_many(&[&rhs, &rhs]);
+                assert!(result.iter().zip(lhs.iter().zip(&rhs)).all(|(&z, (&x, &y))| z == x.wrapping_add(y).wrapping_add(y)), "add_assign_many under {bits:?}");
+            }
+        })
+    }
+
+    #[test]
+    fn mul_add () {
+        for_each_width(|bits| {
+            for len in LENS {
+                let (x, a, b) = (
+                    values(len, 0, |x| (x >> 40) as f32 / 3.0),
+                    values(len, 1, |x| (x >> 40) as f32 / 7.0),
+                    values(len, 2, |x| -((x >> 40) as f32))
+                );
+
+                // Whether the backends fuse or not, the scalar fallback must agree with them
+                let mut result = x.clone();
+                result.mul_add_assign(&a, &b);
+                let expected = x.iter().zip(a.iter().zip(&b)).map(|(&x, (&a, &b))| Wrapping::mul_add(x, a, b));
+                assert!(result.iter().copied().eq(expected), "mul_add_assign under {bits:?}");
+
+                let mut result = b.clone();
+                result.axpy(0.1, &x);
+                let expected = x.iter().zip(&b).map(|(&x, &b)| Wrapping::mul_add(0.1, x, b));
+                assert!(result.iter().copied().eq(expected), "axpy under {bits:?}");
+
+                let (x, a, b) = (values(len, 0, |x| x as u16), values(len, 1, |x| x as u16), values(len, 2, |x| x as u16));
+                let mut result = x.clone();
+                result.mul_add_assign(&a, &b);
+                assert!(result.iter().zip(x.iter().zip(a.iter().zip(&b))).all(|(&z, (&x, (&a, &b)))| z == x.wrapping_mul(a).wrapping_add(b)), "integer mul_add_assign under {bits:?}");
+            }
+        })
+    }
+
+    #[test]
+    fn nan () {
+        let lhs = [f32::NAN, 1.0, f32::NAN, -2.0, 0.5].repeat(9);
+        let rhs = [2.0, f32::NAN, f32::NAN, 3.0, -0.5].repeat(9);
+
+        for_each_width(|bits| {
+            let mut min = lhs.clone();
+            min.min_assign(&rhs);
+            let mut max = lhs.clone();
+            max.max_assign(&rhs);
+
+            for (i, (&x, &y)) in lhs.iter().zip(&rhs).enumerate() {
+                assert_eq!(min[i].to_bits(), x.min(y).to_bits(), "min({x}, {y}) under {bits:?}");
+                assert_eq!(max[i].to_bits(), x.max(y).to_bits(), "max({x}, {y}) under {bits:?}");
+            }
+
+            let mut clamped = lhs.clone();
+            clamped.clamp_assign(0.0, 1.0);
+            for (&x, &y) in lhs.iter().zip(&clamped) {
+                assert!(y.to_bits() == x.clamp(0.0, 1.0).to_bits() || (x.is_nan() && y.is_nan()), "clamp({x}) = {y} under {bits:?}");
+            }
+            assert_eq!(clamped.try_clamp_assign(f32::NAN, 1.0), Err(Error::InvalidBounds));
+            assert_eq!(clamped.try_clamp_assign(1.0, 0.0), Err(Error::InvalidBounds));
+        })
+    }
+
+    #[test]
+    fn conversions () {
+        fn check<A: ?Sized + VerticalConvert<B>, B: 'static + Copy + PartialEq> (src: &A, bits: Option<usize>) where A::Element: AsPrimitive<B> {
+            let mut out = vec![MaybeUninit::<B>::uninit(); src.len()];
+            src.convert_into(&mut out);
+            let expected = src.iter().map(|x| x.as_());
+            let (a, b) = (core::any::type_name::<A>(), core::any::type_name::<B>());
+            assert!(out.iter().map(|x| unsafe { x.assume_init() }).eq(expected), "{a} into {b} under {bits:?}");
+        }
+
+        for_each_width(|bits| {
+            for len in LENS {
+                let ints = values(len, 0, |x| x as u32);
+                check::<_, u8>(&ints[..], bits);
+                check::<_, i16>(&ints[..], bits);
+                check::<_, f32>(&ints[..], bits);
+                check::<_, f64>(&ints[..], bits);
+                check::<_, u64>(&ints[..], bits);
+
+                let floats = values(len, 1, |x| (x as i32) as f32 / 1000.0);
+                check::<_, i32>(&floats[..], bits);
+                check::<_, u8>(&floats[..], bits);
+                check::<_, f64>(&floats[..], bits);
+
+                let longs = values(len, 2, |x| x as i64);
+                check::<_, u16>(&longs[..], bits);
+            }
+        })
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half () {
+        use half::{f16, bf16};
+
+        for_each_width(|bits| {
+            for len in LENS {
+                let floats = values(len, 0, |x| (x as i32) as f32 / 1e6);
+                let (lhs, rhs): (Vec<f16>, Vec<bf16>) = (floats.iter().map(|&x| f16::from_f32(x)).collect(), floats.iter().map(|&x| bf16::from_f32(x)).collect());
+
+                let mut narrow = vec![MaybeUninit::<f16>::uninit(); len];
+                floats.convert_into(&mut narrow);
+                assert!(narrow.iter().map(|x| unsafe { x.assume_init() }).eq(lhs.iter().copied()), "f32 into f16 under {bits:?}");
+
+                let mut wide = vec![MaybeUninit::<f32>::uninit(); len];
+                rhs.convert_into(&mut wide);
+                assert!(wide.iter().map(|x| unsafe { x.assume_init() }).eq(rhs.iter().map(|x| x.to_f32())), "bf16 into f32 under {bits:?}");
+
+                let mut sum = lhs.clone();
+                sum.add_assign(&lhs);
+                assert!(sum.iter().zip(&lhs).all(|(&z, &x)| z == x + x), "f16 add under {bits:?}");
+
+                let mut product = rhs.clone();
+                product.mul_assign(&rhs);
+                assert!(product.iter().zip(&rhs).all(|(&z, &x)| z == x * x), "bf16 mul under {bits:?}");
+            }
+        })
+    }
+
+    #[test]
+    fn cyclic () {
+        for_each_width(|bits| {
+            for len in LENS {
+                let values = values(len, 0, |x| x as i32);
+                for pattern in 1..=9 {
+                    let rhs: Vec<i32> = (1..=pattern).collect();
+                    let mut result = values.clone();
+
+                    if len % pattern as usize != 0 {
+                        let err = Error::UnevenPattern { len, pattern: pattern as usize };
+                        assert_eq!(result.try_add_assign_cyclic(&rhs), Err(err));
+                        assert!(!result.mul_assign_cyclic_checked(&rhs));
+                        assert_eq!(result, values, "an uneven pattern of {pattern} changed {len} elements under {bits:?}");
+                        continue
+                    }
+
+                    result.add_assign_cyclic(&rhs);
+                    let expected = values.iter().zip(rhs.iter().cycle()).map(|(&x, &y)| x.wrapping_add(y));
+                    assert!(result.iter().copied().eq(expected), "add_assign_cyclic({pattern}) on {len} elements under {bits:?}");
+                }
+
+                let mut result = values.clone();
+                assert_eq!(result.try_sub_assign_cyclic(&[]), Err(Error::UnevenPattern { len, pattern: 0 }));
+            }
+        })
+    }
+}
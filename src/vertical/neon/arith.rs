@@ -0,0 +1,67 @@
+use core::arch::aarch64::*;
+use docfg::docfg;
+use crate::sealed::Lane;
+use super::*;
+
+impl_accumulate! {
+    #[cfg(target_feature = "neon")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+impl_mean! {
+    #[cfg(target_feature = "neon")]
+    f32, f64
+}
+
+impl_lane! {
+    #[cfg(target_feature = "neon")]
+    f32, f64
+}
+
+macro_rules! impl_arith {
+    ($vec:ty: $t:ty => $add:ident, $sub:ident) => {
+        impl Arith<$t> for $vec {
+            #[inline(always)]
+            unsafe fn add (self, rhs: Self) -> Self {
+                $add(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn sub (self, rhs: Self) -> Self {
+                $sub(self, rhs)
+            }
+        }
+    };
+
+    ($vec:ty: $t:ty => $add:ident, $sub:ident, $mul:ident, $div:ident) => {
+        impl_arith! { $vec: $t => $add, $sub }
+
+        impl FloatArith<$t> for $vec {
+            #[inline(always)]
+            unsafe fn mul (self, rhs: Self) -> Self {
+                $mul(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn div (self, rhs: Self) -> Self {
+                $div(self, rhs)
+            }
+        }
+    };
+}
+
+/* FLOATS */
+impl_arith! { float32x4_t: f32 => vaddq_f32, vsubq_f32, vmulq_f32, vdivq_f32 }
+impl_arith! { float64x2_t: f64 => vaddq_f64, vsubq_f64, vmulq_f64, vdivq_f64 }
+
+/* INTEGERS */
+impl_arith! { int8x16_t: i8 => vaddq_s8, vsubq_s8 }
+impl_arith! { uint8x16_t: u8 => vaddq_u8, vsubq_u8 }
+impl_arith! { int16x8_t: i16 => vaddq_s16, vsubq_s16 }
+impl_arith! { uint16x8_t: u16 => vaddq_u16, vsubq_u16 }
+impl_arith! { int32x4_t: i32 => vaddq_s32, vsubq_s32 }
+impl_arith! { uint32x4_t: u32 => vaddq_u32, vsubq_u32 }
+impl_arith! { int64x2_t: i64 => vaddq_s64, vsubq_s64 }
+impl_arith! { uint64x2_t: u64 => vaddq_u64, vsubq_u64 }
@@ -0,0 +1,56 @@
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_bitwise! {
+    #[cfg(target_feature = "neon")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_register {
+    ($($t:ty as $vec:ty => $and:ident, $or:ident, $xor:ident, $bic:ident, $not:expr),+) => {
+        $(
+            impl Bitwise<$t> for $vec {
+                #[inline(always)]
+                unsafe fn and (self, rhs: Self) -> Self {
+                    $and(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn or (self, rhs: Self) -> Self {
+                    $or(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn xor (self, rhs: Self) -> Self {
+                    $xor(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn andnot (self, rhs: Self) -> Self {
+                    // Unlike on x86, the intrinsics complement their second operand
+                    $bic(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn not (self) -> Self {
+                    $not(self)
+                }
+            }
+        )+
+    };
+}
+
+// There's no 64-bit `vmvnq`, so those lanes are flipped through a XOR with all ones
+impl_register! {
+    i8 as int8x16_t => vandq_s8, vorrq_s8, veorq_s8, vbicq_s8, vmvnq_s8,
+    u8 as uint8x16_t => vandq_u8, vorrq_u8, veorq_u8, vbicq_u8, vmvnq_u8,
+    i16 as int16x8_t => vandq_s16, vorrq_s16, veorq_s16, vbicq_s16, vmvnq_s16,
+    u16 as uint16x8_t => vandq_u16, vorrq_u16, veorq_u16, vbicq_u16, vmvnq_u16,
+    i32 as int32x4_t => vandq_s32, vorrq_s32, veorq_s32, vbicq_s32, vmvnq_s32,
+    u32 as uint32x4_t => vandq_u32, vorrq_u32, veorq_u32, vbicq_u32, vmvnq_u32,
+    i64 as int64x2_t => vandq_s64, vorrq_s64, veorq_s64, vbicq_s64, |x| veorq_s64(x, vdupq_n_s64(-1)),
+    u64 as uint64x2_t => vandq_u64, vorrq_u64, veorq_u64, vbicq_u64, |x| veorq_u64(x, vdupq_n_u64(u64::MAX))
+}
@@ -0,0 +1,74 @@
+use core::arch::aarch64::*;
+use docfg::docfg;
+use super::*;
+
+impl_cmp! {
+    #[cfg(target_feature = "neon")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_cmp {
+    ($($t:ty as $vec:ty => $eq:ident, $lt:ident, $le:ident & $movemask:ident),+) => {
+        $(
+            impl Compare<$t> for $vec {
+                #[inline(always)]
+                unsafe fn eq (self, rhs: Self) -> u64 {
+                    $movemask($eq(self, rhs))
+                }
+
+                #[inline(always)]
+                unsafe fn lt (self, rhs: Self) -> u64 {
+                    $movemask($lt(self, rhs))
+                }
+
+                #[inline(always)]
+                unsafe fn le (self, rhs: Self) -> u64 {
+                    $movemask($le(self, rhs))
+                }
+            }
+        )+
+    };
+}
+
+impl_cmp! {
+    f32 as float32x4_t => vceqq_f32, vcltq_f32, vcleq_f32 & movemask_32,
+    f64 as float64x2_t => vceqq_f64, vcltq_f64, vcleq_f64 & movemask_64,
+    i8 as int8x16_t => vceqq_s8, vcltq_s8, vcleq_s8 & movemask_8,
+    u8 as uint8x16_t => vceqq_u8, vcltq_u8, vcleq_u8 & movemask_8,
+    i16 as int16x8_t => vceqq_s16, vcltq_s16, vcleq_s16 & movemask_16,
+    u16 as uint16x8_t => vceqq_u16, vcltq_u16, vcleq_u16 & movemask_16,
+    i32 as int32x4_t => vceqq_s32, vcltq_s32, vcleq_s32 & movemask_32,
+    u32 as uint32x4_t => vceqq_u32, vcltq_u32, vcleq_u32 & movemask_32,
+    i64 as int64x2_t => vceqq_s64, vcltq_s64, vcleq_s64 & movemask_64,
+    u64 as uint64x2_t => vceqq_u64, vcltq_u64, vcleq_u64 & movemask_64
+}
+
+/* MOVEMASKS */
+// There's no `movemask` instruction, so every lane keeps only it's own bit, and then the lanes are added together.
+
+#[inline(always)]
+unsafe fn movemask_8 (mask: uint8x16_t) -> u64 {
+    const WEIGHTS: [u8; 16] = [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128];
+    let bits = vandq_u8(mask, vld1q_u8(WEIGHTS.as_ptr()));
+    return vaddv_u8(vget_low_u8(bits)) as u64 | (vaddv_u8(vget_high_u8(bits)) as u64) << 8
+}
+
+#[inline(always)]
+unsafe fn movemask_16 (mask: uint16x8_t) -> u64 {
+    const WEIGHTS: [u16; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+    return vaddvq_u16(vandq_u16(mask, vld1q_u16(WEIGHTS.as_ptr()))) as u64
+}
+
+#[inline(always)]
+unsafe fn movemask_32 (mask: uint32x4_t) -> u64 {
+    const WEIGHTS: [u32; 4] = [1, 2, 4, 8];
+    return vaddvq_u32(vandq_u32(mask, vld1q_u32(WEIGHTS.as_ptr()))) as u64
+}
+
+#[inline(always)]
+unsafe fn movemask_64 (mask: uint64x2_t) -> u64 {
+    const WEIGHTS: [u64; 2] = [1, 2];
+    return vaddvq_u64(vandq_u64(mask, vld1q_u64(WEIGHTS.as_ptr())))
+}
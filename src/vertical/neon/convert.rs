@@ -0,0 +1,374 @@
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+// Conversions go through blocks of 32-bit lanes, so that every pair of element types shares the same block size
+/// Number of elements converted at once
+const BLOCK: usize = 4;
+type Epi32 = int32x4_t;
+type Ps = float32x4_t;
+/// A block of 64-bit lanes, split into two registers
+type Pd = [float64x2_t; 2];
+type Epi64 = [int64x2_t; 2];
+
+/// Integers of up to 32 bits, converted through a block of 32-bit lanes.
+trait Lanes: Copy {
+    const MIN: f64;
+    const MAX: f64;
+
+    /// Loads a block of elements, extended to 32 bits with `as` semantics (unsigned 32-bit elements keep their bits).
+    unsafe fn load (ptr: *const Self) -> Epi32;
+    /// Stores a block of 32-bit lanes, truncated to the element type.
+    unsafe fn store (v: Epi32, ptr: *mut Self);
+
+    /// Converts lanes loaded from this element type to floats.
+    #[inline(always)]
+    unsafe fn to_ps (v: Epi32) -> Ps {
+        vcvtq_f32_s32(v)
+    }
+
+    #[inline(always)]
+    unsafe fn to_pd (v: Epi32) -> Pd {
+        Self::to_epi64(v).map(|x| vcvtq_f64_s64(x))
+    }
+
+    /// Extends lanes loaded from this element type to 64 bits.
+    #[inline(always)]
+    unsafe fn to_epi64 (v: Epi32) -> Epi64 {
+        [vmovl_s32(vget_low_s32(v)), vmovl_high_s32(v)]
+    }
+
+    /// Converts floats to this element type, rounding towards zero and saturating (with NaN converting to zero).
+    #[inline(always)]
+    unsafe fn from_ps (v: Ps) -> Epi32 {
+        // The conversion saturates to `i32` and turns NaN into zero by itself, and `vmaxq` lets NaN through
+        let v = vminq_f32(vmaxq_f32(v, vdupq_n_f32(Self::MIN as f32)), vdupq_n_f32(Self::MAX as f32));
+        vcvtq_s32_f32(v)
+    }
+
+    #[inline(always)]
+    unsafe fn from_pd (v: Pd) -> Epi32 {
+        let v = v.map(|x| vminq_f64(vmaxq_f64(x, vdupq_n_f64(Self::MIN)), vdupq_n_f64(Self::MAX)));
+        narrow_epi64(v.map(|x| vcvtq_s64_f64(x)))
+    }
+}
+
+macro_rules! impl_convert {
+    ($($a:ident),+ => $bs:tt as |$src:ident, $dst:ident| $f:expr) => {
+        $(
+            impl_convert! { @row $a => $bs as |$src, $dst| $f }
+        )+
+    };
+
+    (@row $a:ident => [$($b:ident),+] as |$src:ident, $dst:ident| $f:expr) => {
+        $(
+            #[cfg(not(target_feature = "neon"))]
+            impl VerticalConvert<$b> for [$a] {}
+
+            #[docfg(target_feature = "neon")]
+            impl SimdVerticalConvert<$b> for [$a] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$b>]) {
+                    type A = $a;
+                    type B = $b;
+                    convert_raw(self.as_ptr(), out.as_mut_ptr().cast(), self.len(), |$src: *const A, $dst: *mut B| $f)
+                }
+            }
+        )+
+    };
+
+    // Conversions that only copy bits around
+    (copy: $($a:ident),+ => $bs:tt) => {
+        $(
+            impl_convert! { @copy $a => $bs }
+        )+
+    };
+
+    (@copy $a:ident => [$($b:ident),+]) => {
+        $(
+            impl SimdVerticalConvert<$b> for [$a] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$b>]) {
+                    core::ptr::copy_nonoverlapping(self.as_ptr().cast::<$b>(), out.as_mut_ptr().cast(), self.len())
+                }
+            }
+        )+
+    };
+
+    (scalar: $($a:ident),+ => $bs:tt) => {
+        $(
+            impl_convert! { @scalar $a => $bs }
+        )+
+    };
+
+    (@scalar $a:ident => [$($b:ident),+]) => {
+        $(
+            impl VerticalConvert<$b> for [$a] {}
+        )+
+    };
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(A::load(src), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [f32]
+    as |src, dst| store_ps(A::to_ps(A::load(src)), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [f64]
+    as |src, dst| store_pd(A::to_pd(A::load(src)), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [i64, u64]
+    as |src, dst| store_epi64(A::to_epi64(A::load(src)), dst.cast())
+}
+
+impl_convert! {
+    f32 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(B::from_ps(load_ps(src)), dst)
+}
+
+impl_convert! {
+    f64 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(B::from_pd(load_pd(src)), dst)
+}
+
+impl_convert! {
+    i64, u64 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(narrow_epi64(load_epi64(src.cast())), dst)
+}
+
+impl_convert! {
+    f32 => [f64]
+    as |src, dst| store_pd(cvtps_pd(load_ps(src)), dst)
+}
+
+impl_convert! {
+    f64 => [f32]
+    as |src, dst| store_ps(cvtpd_ps(load_pd(src)), dst)
+}
+
+// Unlike on x86, 64-bit integers have their own conversions, which saturate like `as` does
+impl_convert! {
+    i64 => [f64]
+    as |src, dst| store_pd(load_epi64(src.cast()).map(|x| vcvtq_f64_s64(x)), dst)
+}
+
+impl_convert! {
+    u64 => [f64]
+    as |src, dst| store_pd(load_epi64(src.cast()).map(|x| vcvtq_f64_u64(vreinterpretq_u64_s64(x))), dst)
+}
+
+impl_convert! {
+    f64 => [i64]
+    as |src, dst| store_epi64(load_pd(src).map(|x| vcvtq_s64_f64(x)), dst.cast())
+}
+
+impl_convert! {
+    f64 => [u64]
+    as |src, dst| store_epi64(load_pd(src).map(|x| vreinterpretq_s64_u64(vcvtq_u64_f64(x))), dst.cast())
+}
+
+// Widening to `f64` is exact, so it doesn't change the result
+impl_convert! {
+    f32 => [i64]
+    as |src, dst| store_epi64(cvtps_pd(load_ps(src)).map(|x| vcvtq_s64_f64(x)), dst.cast())
+}
+
+impl_convert! {
+    f32 => [u64]
+    as |src, dst| store_epi64(cvtps_pd(load_ps(src)).map(|x| vreinterpretq_s64_u64(vcvtq_u64_f64(x))), dst.cast())
+}
+
+impl_convert! {
+    copy: i64, u64 => [i64, u64]
+}
+
+impl_convert! {
+    copy: f32 => [f32]
+}
+
+impl_convert! {
+    copy: f64 => [f64]
+}
+
+// Going through `f64` would round twice
+impl_convert! {
+    scalar: i64, u64 => [f32]
+}
+
+/// Converts `len` elements from `src` into `dst`, one block at a time.
+///
+/// The trailing elements are padded with zeros into a full block.
+#[inline(always)]
+unsafe fn convert_raw<A: Copy, B: Copy> (src: *const A, dst: *mut B, len: usize, f: impl Fn(*const A, *mut B)) {
+    let mut i = 0;
+    while i + BLOCK <= len {
+        f(src.add(i), dst.add(i));
+        i += BLOCK;
+    }
+
+    if i < len {
+        let rem = len - i;
+        let mut lhs = MaybeUninit::<[A; BLOCK]>::zeroed();
+        let mut rhs = MaybeUninit::<[B; BLOCK]>::uninit();
+        core::ptr::copy_nonoverlapping(src.add(i), lhs.as_mut_ptr().cast::<A>(), rem);
+        f(lhs.as_ptr().cast(), rhs.as_mut_ptr().cast());
+        core::ptr::copy_nonoverlapping(rhs.as_ptr().cast::<B>(), dst.add(i), rem);
+    }
+}
+
+/* INTEGERS */
+macro_rules! impl_lanes {
+    (
+        $t:ident {
+            load: |$load_ptr:ident| $load:expr,
+            store: |$store_v:ident, $store_ptr:ident| $store:expr
+        }
+    ) => {
+        impl Lanes for $t {
+            const MIN: f64 = $t::MIN as f64;
+            const MAX: f64 = $t::MAX as f64;
+
+            #[inline(always)]
+            unsafe fn load ($load_ptr: *const Self) -> Epi32 {
+                $load
+            }
+
+            #[inline(always)]
+            unsafe fn store ($store_v: Epi32, $store_ptr: *mut Self) {
+                $store
+            }
+        }
+    };
+}
+
+impl_lanes! {
+    i8 {
+        load: |ptr| vmovl_s16(vget_low_s16(vmovl_s8(vcreate_s8(ptr.cast::<u32>().read_unaligned() as u64)))),
+        store: |v, ptr| store_epi8(v, ptr.cast())
+    }
+}
+
+impl_lanes! {
+    u8 {
+        load: |ptr| vreinterpretq_s32_u32(vmovl_u16(vget_low_u16(vmovl_u8(vcreate_u8(ptr.cast::<u32>().read_unaligned() as u64))))),
+        store: |v, ptr| store_epi8(v, ptr.cast())
+    }
+}
+
+impl_lanes! {
+    i16 {
+        load: |ptr| vmovl_s16(vld1_s16(ptr)),
+        store: |v, ptr| vst1_s16(ptr, vmovn_s32(v))
+    }
+}
+
+impl_lanes! {
+    u16 {
+        load: |ptr| vreinterpretq_s32_u32(vmovl_u16(vld1_u16(ptr))),
+        store: |v, ptr| vst1_s16(ptr.cast(), vmovn_s32(v))
+    }
+}
+
+impl_lanes! {
+    i32 {
+        load: |ptr| vld1q_s32(ptr),
+        store: |v, ptr| vst1q_s32(ptr, v)
+    }
+}
+
+/// Truncates a block of 32-bit lanes to bytes.
+#[inline(always)]
+unsafe fn store_epi8 (v: Epi32, ptr: *mut i8) {
+    let v = vmovn_s32(v);
+    let v = vmovn_s16(vcombine_s16(v, v));
+    ptr.cast::<u32>().write_unaligned(vget_lane_u32::<0>(vreinterpret_u32_s8(v)))
+}
+
+impl Lanes for u32 {
+    const MIN: f64 = u32::MIN as f64;
+    const MAX: f64 = u32::MAX as f64;
+
+    #[inline(always)]
+    unsafe fn load (ptr: *const Self) -> Epi32 {
+        i32::load(ptr.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn store (v: Epi32, ptr: *mut Self) {
+        i32::store(v, ptr.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn to_ps (v: Epi32) -> Ps {
+        vcvtq_f32_u32(vreinterpretq_u32_s32(v))
+    }
+
+    #[inline(always)]
+    unsafe fn to_epi64 (v: Epi32) -> Epi64 {
+        let v = vreinterpretq_u32_s32(v);
+        [vmovl_u32(vget_low_u32(v)), vmovl_high_u32(v)].map(|x| vreinterpretq_s64_u64(x))
+    }
+
+    #[inline(always)]
+    unsafe fn from_ps (v: Ps) -> Epi32 {
+        // The unsigned conversion saturates on it's own
+        vreinterpretq_s32_u32(vcvtq_u32_f32(v))
+    }
+}
+
+/* FLOATS */
+#[inline(always)]
+unsafe fn load_ps (ptr: *const f32) -> Ps {
+    vld1q_f32(ptr)
+}
+
+#[inline(always)]
+unsafe fn store_ps (v: Ps, ptr: *mut f32) {
+    vst1q_f32(ptr, v)
+}
+
+#[inline(always)]
+unsafe fn load_pd (ptr: *const f64) -> Pd {
+    [vld1q_f64(ptr), vld1q_f64(ptr.add(2))]
+}
+
+#[inline(always)]
+unsafe fn store_pd (v: Pd, ptr: *mut f64) {
+    vst1q_f64(ptr, v[0]);
+    vst1q_f64(ptr.add(2), v[1])
+}
+
+#[inline(always)]
+unsafe fn load_epi64 (ptr: *const i64) -> Epi64 {
+    [vld1q_s64(ptr), vld1q_s64(ptr.add(2))]
+}
+
+#[inline(always)]
+unsafe fn store_epi64 (v: Epi64, ptr: *mut i64) {
+    vst1q_s64(ptr, v[0]);
+    vst1q_s64(ptr.add(2), v[1])
+}
+
+/// Truncates a block of 64-bit lanes to 32 bits.
+#[inline(always)]
+unsafe fn narrow_epi64 (v: Epi64) -> Epi32 {
+    vcombine_s32(vmovn_s64(v[0]), vmovn_s64(v[1]))
+}
+
+#[inline(always)]
+unsafe fn cvtps_pd (v: Ps) -> Pd {
+    [vcvt_f64_f32(vget_low_f32(v)), vcvt_high_f64_f32(v)]
+}
+
+#[inline(always)]
+unsafe fn cvtpd_ps (v: Pd) -> Ps {
+    vcvt_high_f32_f64(vcvt_f32_f64(v[0]), v[1])
+}
@@ -0,0 +1,28 @@
+use core::arch::aarch64::*;
+use docfg::docfg;
+use super::*;
+
+impl_mul_add! {
+    #[cfg(target_feature = "neon")]
+    f32, f64
+}
+
+impl_default! {
+    VerticalMulAdd =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
+
+impl MulAdd<f32> for float32x4_t {
+    #[inline(always)]
+    unsafe fn mul_add (self, a: Self, b: Self) -> Self {
+        vfmaq_f32(b, self, a)
+    }
+}
+
+impl MulAdd<f64> for float64x2_t {
+    #[inline(always)]
+    unsafe fn mul_add (self, a: Self, b: Self) -> Self {
+        vfmaq_f64(b, self, a)
+    }
+}
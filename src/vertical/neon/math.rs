@@ -0,0 +1,128 @@
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::vertical::math::*;
+use crate::vertical::simd::math::*;
+use super::*;
+
+impl_math! {
+    #[cfg(target_feature = "neon")]
+    f32, f64
+}
+
+#[cfg(feature = "half")]
+impl_widen_math! {
+    #[cfg(target_feature = "neon")]
+    f16, bf16
+}
+
+/* REGISTERS */
+macro_rules! impl_lanes {
+    (
+        $t:ty as $vec:ty: $mask:ty {
+            $add:ident, $sub:ident, $mul:ident, $div:ident,
+            $and:ident, $or:ident, $xor:ident,
+            shl: $shl:expr, shr: $shr:expr,
+            $lt:ident, $le:ident, $eq:ident,
+            mask: $mask_and:ident, $mask_or:ident, $mask_andnot:ident, $mask_not:expr,
+            any: $any:expr,
+            select: $select:ident
+        }
+    ) => {
+        impl Lanes<$t> for $vec {
+            type Mask = $mask;
+            const FUSED: bool = true;
+
+            #[inline(always)]
+            unsafe fn add (self, rhs: Self) -> Self { $add(self, rhs) }
+            #[inline(always)]
+            unsafe fn sub (self, rhs: Self) -> Self { $sub(self, rhs) }
+            #[inline(always)]
+            unsafe fn mul (self, rhs: Self) -> Self { $mul(self, rhs) }
+            #[inline(always)]
+            unsafe fn div (self, rhs: Self) -> Self { $div(self, rhs) }
+            #[inline(always)]
+            unsafe fn and (self, rhs: Self) -> Self { $and(self, rhs) }
+            #[inline(always)]
+            unsafe fn or (self, rhs: Self) -> Self { $or(self, rhs) }
+            #[inline(always)]
+            unsafe fn xor (self, rhs: Self) -> Self { $xor(self, rhs) }
+            #[inline(always)]
+            unsafe fn shl (self, count: i32) -> Self { $shl(self, count) }
+            #[inline(always)]
+            unsafe fn shr (self, count: i32) -> Self { $shr(self, count) }
+
+            #[inline(always)]
+            unsafe fn lt (self, rhs: Self) -> $mask { $lt(self, rhs) }
+            #[inline(always)]
+            unsafe fn le (self, rhs: Self) -> $mask { $le(self, rhs) }
+            #[inline(always)]
+            unsafe fn eq (self, rhs: Self) -> $mask { $eq(self, rhs) }
+
+            #[inline(always)]
+            unsafe fn unord (self, rhs: Self) -> $mask {
+                // Only NaN lanes compare unequal to themselves
+                $mask_not($mask_and($eq(self, self), $eq(rhs, rhs)))
+            }
+
+            #[inline(always)]
+            unsafe fn mask_and (lhs: $mask, rhs: $mask) -> $mask { $mask_and(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn mask_or (lhs: $mask, rhs: $mask) -> $mask { $mask_or(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn mask_andnot (lhs: $mask, rhs: $mask) -> $mask { $mask_andnot(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn any (mask: $mask) -> bool { $any(mask) }
+            #[inline(always)]
+            unsafe fn select (mask: $mask, a: Self, b: Self) -> Self { $select(mask, a, b) }
+        }
+    };
+}
+
+impl_lanes! {
+    f32 as float32x4_t: uint32x4_t {
+        vaddq_f32, vsubq_f32, vmulq_f32, vdivq_f32,
+        and_ps, or_ps, xor_ps,
+        shl: |x, n| vreinterpretq_f32_u32(vshlq_u32(vreinterpretq_u32_f32(x), vdupq_n_s32(n))),
+        shr: |x, n: i32| vreinterpretq_f32_u32(vshlq_u32(vreinterpretq_u32_f32(x), vdupq_n_s32(-n))),
+        vcltq_f32, vcleq_f32, vceqq_f32,
+        mask: vandq_u32, vorrq_u32, vbicq_u32, vmvnq_u32,
+        any: |mask| vmaxvq_u32(mask) != 0,
+        select: vbslq_f32
+    }
+}
+
+impl_lanes! {
+    f64 as float64x2_t: uint64x2_t {
+        vaddq_f64, vsubq_f64, vmulq_f64, vdivq_f64,
+        and_pd, or_pd, xor_pd,
+        shl: |x, n| vreinterpretq_f64_u64(vshlq_u64(vreinterpretq_u64_f64(x), vdupq_n_s64(n as i64))),
+        shr: |x, n: i32| vreinterpretq_f64_u64(vshlq_u64(vreinterpretq_u64_f64(x), vdupq_n_s64(-n as i64))),
+        vcltq_f64, vcleq_f64, vceqq_f64,
+        mask: vandq_u64, vorrq_u64, vbicq_u64, |x| veorq_u64(x, vdupq_n_u64(u64::MAX)),
+        // There's no 64-bit horizontal maximum, but the halves of set lanes are set too
+        any: |mask| vmaxvq_u32(vreinterpretq_u32_u64(mask)) != 0,
+        select: vbslq_f64
+    }
+}
+
+// Bitwise operations only exist for integer registers, so the lanes are reinterpreted around them
+macro_rules! impl_bitwise {
+    ($($name:ident: $vec:ty => $f:ident through $to:ident & $from:ident),+) => {
+        $(
+            #[inline(always)]
+            unsafe fn $name (x: $vec, y: $vec) -> $vec {
+                $from($f($to(x), $to(y)))
+            }
+        )+
+    };
+}
+
+impl_bitwise! {
+    and_ps: float32x4_t => vandq_u32 through vreinterpretq_u32_f32 & vreinterpretq_f32_u32,
+    or_ps: float32x4_t => vorrq_u32 through vreinterpretq_u32_f32 & vreinterpretq_f32_u32,
+    xor_ps: float32x4_t => veorq_u32 through vreinterpretq_u32_f32 & vreinterpretq_f32_u32,
+    and_pd: float64x2_t => vandq_u64 through vreinterpretq_u64_f64 & vreinterpretq_f64_u64,
+    or_pd: float64x2_t => vorrq_u64 through vreinterpretq_u64_f64 & vreinterpretq_f64_u64,
+    xor_pd: float64x2_t => veorq_u64 through vreinterpretq_u64_f64 & vreinterpretq_f64_u64
+}
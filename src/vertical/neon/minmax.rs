@@ -0,0 +1,76 @@
+use core::arch::aarch64::*;
+use docfg::docfg;
+use super::*;
+
+impl_minmax! {
+    #[cfg(target_feature = "neon")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* FLOATS */
+macro_rules! impl_float {
+    ($t:ty as $vec:ty { $minnm:ident, $maxnm:ident, $min:ident, $max:ident }) => {
+        impl MinMax<$t> for $vec {
+            // The `nm` variants return the other operand if only one of them is NaN, like `f32::min`
+            #[inline(always)]
+            unsafe fn min (self, rhs: Self) -> Self {
+                $minnm(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn max (self, rhs: Self) -> Self {
+                $maxnm(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn clamp (self, lo: Self, hi: Self) -> Self {
+                // The plain variants propagate NaN, which lets NaN lanes through
+                $max(lo, $min(hi, self))
+            }
+        }
+    };
+}
+
+impl_float! { f32 as float32x4_t { vminnmq_f32, vmaxnmq_f32, vminq_f32, vmaxq_f32 } }
+impl_float! { f64 as float64x2_t { vminnmq_f64, vmaxnmq_f64, vminq_f64, vmaxq_f64 } }
+
+/* INTEGERS */
+macro_rules! impl_int {
+    ($t:ty as $vec:ty { $min:expr, $max:expr }) => {
+        impl MinMax<$t> for $vec {
+            #[inline(always)]
+            unsafe fn min (self, rhs: Self) -> Self {
+                $min(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn max (self, rhs: Self) -> Self {
+                $max(self, rhs)
+            }
+        }
+    };
+}
+
+impl_int! { i8 as int8x16_t { vminq_s8, vmaxq_s8 } }
+impl_int! { u8 as uint8x16_t { vminq_u8, vmaxq_u8 } }
+impl_int! { i16 as int16x8_t { vminq_s16, vmaxq_s16 } }
+impl_int! { u16 as uint16x8_t { vminq_u16, vmaxq_u16 } }
+impl_int! { i32 as int32x4_t { vminq_s32, vmaxq_s32 } }
+impl_int! { u32 as uint32x4_t { vminq_u32, vmaxq_u32 } }
+
+// There are no 64-bit `min` and `max` instructions
+impl_int! {
+    i64 as int64x2_t {
+        |x, y| vbslq_s64(vcgtq_s64(x, y), y, x),
+        |x, y| vbslq_s64(vcgtq_s64(x, y), x, y)
+    }
+}
+
+impl_int! {
+    u64 as uint64x2_t {
+        |x, y| vbslq_u64(vcgtq_u64(x, y), y, x),
+        |x, y| vbslq_u64(vcgtq_u64(x, y), x, y)
+    }
+}
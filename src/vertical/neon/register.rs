@@ -0,0 +1,45 @@
+use core::arch::aarch64::*;
+use super::*;
+
+macro_rules! impl_register {
+    ($($vec:ty: $t:ty => $load:ident & $store:ident & $splat:ident),+) => {
+        $(
+            impl Register<$t> for $vec {
+                #[inline(always)]
+                unsafe fn load (ptr: *const $t) -> Self {
+                    $load(ptr)
+                }
+
+                #[inline(always)]
+                unsafe fn store (self, ptr: *mut $t) {
+                    $store(ptr, self)
+                }
+
+                #[inline(always)]
+                unsafe fn splat (value: $t) -> Self {
+                    $splat(value)
+                }
+            }
+
+            impl Vectorize for $t {
+                type Vector = $vec;
+                type Vector256 = $vec;
+                type Vector128 = $vec;
+                const ISA: &'static str = "neon";
+            }
+        )+
+    };
+}
+
+impl_register! {
+    float32x4_t: f32 => vld1q_f32 & vst1q_f32 & vdupq_n_f32,
+    float64x2_t: f64 => vld1q_f64 & vst1q_f64 & vdupq_n_f64,
+    int8x16_t: i8 => vld1q_s8 & vst1q_s8 & vdupq_n_s8,
+    uint8x16_t: u8 => vld1q_u8 & vst1q_u8 & vdupq_n_u8,
+    int16x8_t: i16 => vld1q_s16 & vst1q_s16 & vdupq_n_s16,
+    uint16x8_t: u16 => vld1q_u16 & vst1q_u16 & vdupq_n_u16,
+    int32x4_t: i32 => vld1q_s32 & vst1q_s32 & vdupq_n_s32,
+    uint32x4_t: u32 => vld1q_u32 & vst1q_u32 & vdupq_n_u32,
+    int64x2_t: i64 => vld1q_s64 & vst1q_s64 & vdupq_n_s64,
+    uint64x2_t: u64 => vld1q_u64 & vst1q_u64 & vdupq_n_u64
+}
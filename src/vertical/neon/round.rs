@@ -0,0 +1,43 @@
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_round! {
+    #[cfg(target_feature = "neon")]
+    f32, f64
+}
+
+macro_rules! impl_float {
+    ($t:ty as $vec:ty { $floor:ident, $ceil:ident, $trunc:ident, $round:ident, $even:ident }) => {
+        impl Round<$t> for $vec {
+            #[inline(always)]
+            unsafe fn floor (self) -> Self {
+                $floor(self)
+            }
+
+            #[inline(always)]
+            unsafe fn ceil (self) -> Self {
+                $ceil(self)
+            }
+
+            #[inline(always)]
+            unsafe fn trunc (self) -> Self {
+                $trunc(self)
+            }
+
+            #[inline(always)]
+            unsafe fn round (self) -> Self {
+                $round(self)
+            }
+
+            #[inline(always)]
+            unsafe fn round_ties_even (self) -> Self {
+                $even(self)
+            }
+        }
+    };
+}
+
+impl_float! { f32 as float32x4_t { vrndmq_f32, vrndpq_f32, vrndq_f32, vrndaq_f32, vrndnq_f32 } }
+impl_float! { f64 as float64x2_t { vrndmq_f64, vrndpq_f64, vrndq_f64, vrndaq_f64, vrndnq_f64 } }
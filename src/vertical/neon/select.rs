@@ -0,0 +1,66 @@
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::sealed::Selector;
+use super::*;
+
+impl_select! {
+    #[cfg(target_feature = "neon")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* LANE MASKS */
+/// Expands the low bits of `bits` into the lanes of a register, with every bit of lane `i` set if bit `i` is.
+macro_rules! impl_expand {
+    ($($name:ident -> $vec:ty: $splat:ident($bits:ty) & [$($w:literal),+] => $load:ident & $tst:ident),+) => {
+        $(
+            #[inline(always)]
+            unsafe fn $name (bits: u64) -> $vec {
+                const WEIGHTS: &[$bits] = &[$($w),+];
+                return $tst($splat(bits as $bits), $load(WEIGHTS.as_ptr()))
+            }
+        )+
+    };
+}
+
+impl_expand! {
+    expand_16 -> uint16x8_t: vdupq_n_u16(u16) & [1, 2, 4, 8, 16, 32, 64, 128] => vld1q_u16 & vtstq_u16,
+    expand_32 -> uint32x4_t: vdupq_n_u32(u32) & [1, 2, 4, 8] => vld1q_u32 & vtstq_u32,
+    expand_64 -> uint64x2_t: vdupq_n_u64(u64) & [1, 2] => vld1q_u64 & vtstq_u64
+}
+
+// 8-bit lanes test the low byte of `bits` on the low half, and the high byte on the high half
+#[inline(always)]
+unsafe fn expand_8 (bits: u64) -> uint8x16_t {
+    const WEIGHTS: [u8; 16] = [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128];
+    let v = vcombine_u8(vdup_n_u8(bits as u8), vdup_n_u8((bits >> 8) as u8));
+    return vtstq_u8(v, vld1q_u8(WEIGHTS.as_ptr()))
+}
+
+macro_rules! impl_blend {
+    ($($t:ty as $vec:ty => $bsl:ident & $expand:ident),+) => {
+        $(
+            impl Blend<$t> for $vec {
+                #[inline(always)]
+                unsafe fn blend (self, rhs: Self, bits: u64) -> Self {
+                    $bsl($expand(bits), rhs, self)
+                }
+            }
+        )+
+    };
+}
+
+impl_blend! {
+    f32 as float32x4_t => vbslq_f32 & expand_32,
+    f64 as float64x2_t => vbslq_f64 & expand_64,
+    i8 as int8x16_t => vbslq_s8 & expand_8,
+    u8 as uint8x16_t => vbslq_u8 & expand_8,
+    i16 as int16x8_t => vbslq_s16 & expand_16,
+    u16 as uint16x8_t => vbslq_u16 & expand_16,
+    i32 as int32x4_t => vbslq_s32 & expand_32,
+    u32 as uint32x4_t => vbslq_u32 & expand_32,
+    i64 as int64x2_t => vbslq_s64 & expand_64,
+    u64 as uint64x2_t => vbslq_u64 & expand_64
+}
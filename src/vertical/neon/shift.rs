@@ -0,0 +1,116 @@
+use core::arch::aarch64::*;
+use docfg::docfg;
+use super::*;
+
+impl_shift! {
+    #[cfg(target_feature = "neon")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/// Implements [`Shift`] for a pair of signed and unsigned lanes of the same width.
+///
+/// `vshlq` shifts every lane by a signed count, to the left if it's positive and to the right if it's negative.
+/// Right shifts are arithmetic on signed lanes and logical on unsigned ones, so logical shifts of signed lanes
+/// go through their unsigned reinterpretation.
+macro_rules! impl_register {
+    (
+        $si:ident & $us:ident as $svec:ident & $uvec:ident {
+            $sshl:ident, $ushl:ident, $splat:ident($count:ty), $neg:ident,
+            $to_unsigned:ident, $to_signed:ident
+        }
+    ) => {
+        impl Shift<$si> for $svec {
+            #[inline(always)]
+            unsafe fn shl (self, n: u32) -> Self {
+                $sshl(self, $splat(n as $count))
+            }
+
+            #[inline(always)]
+            unsafe fn shr (self, n: u32) -> Self {
+                $sshl(self, $splat(-(n as $count)))
+            }
+
+            #[inline(always)]
+            unsafe fn shr_logical (self, n: u32) -> Self {
+                $to_signed($ushl($to_unsigned(self), $splat(-(n as $count))))
+            }
+
+            #[inline(always)]
+            unsafe fn shlv (self, counts: Self) -> Self {
+                $sshl(self, counts)
+            }
+
+            #[inline(always)]
+            unsafe fn shrv (self, counts: Self) -> Self {
+                $sshl(self, $neg(counts))
+            }
+
+            #[inline(always)]
+            unsafe fn shrv_logical (self, counts: Self) -> Self {
+                $to_signed($ushl($to_unsigned(self), $neg(counts)))
+            }
+        }
+
+        impl Shift<$us> for $uvec {
+            #[inline(always)]
+            unsafe fn shl (self, n: u32) -> Self {
+                $ushl(self, $splat(n as $count))
+            }
+
+            #[inline(always)]
+            unsafe fn shr (self, n: u32) -> Self {
+                Shift::<$us>::shr_logical(self, n)
+            }
+
+            #[inline(always)]
+            unsafe fn shr_logical (self, n: u32) -> Self {
+                $ushl(self, $splat(-(n as $count)))
+            }
+
+            #[inline(always)]
+            unsafe fn shlv (self, counts: Self) -> Self {
+                // Counts are less than the width of the lanes, so they're never negative
+                $ushl(self, $to_signed(counts))
+            }
+
+            #[inline(always)]
+            unsafe fn shrv (self, counts: Self) -> Self {
+                Shift::<$us>::shrv_logical(self, counts)
+            }
+
+            #[inline(always)]
+            unsafe fn shrv_logical (self, counts: Self) -> Self {
+                $ushl(self, $neg($to_signed(counts)))
+            }
+        }
+    };
+}
+
+impl_register! {
+    i8 & u8 as int8x16_t & uint8x16_t {
+        vshlq_s8, vshlq_u8, vdupq_n_s8(i8), vnegq_s8,
+        vreinterpretq_u8_s8, vreinterpretq_s8_u8
+    }
+}
+
+impl_register! {
+    i16 & u16 as int16x8_t & uint16x8_t {
+        vshlq_s16, vshlq_u16, vdupq_n_s16(i16), vnegq_s16,
+        vreinterpretq_u16_s16, vreinterpretq_s16_u16
+    }
+}
+
+impl_register! {
+    i32 & u32 as int32x4_t & uint32x4_t {
+        vshlq_s32, vshlq_u32, vdupq_n_s32(i32), vnegq_s32,
+        vreinterpretq_u32_s32, vreinterpretq_s32_u32
+    }
+}
+
+impl_register! {
+    i64 & u64 as int64x2_t & uint64x2_t {
+        vshlq_s64, vshlq_u64, vdupq_n_s64(i64), vnegq_s64,
+        vreinterpretq_u64_s64, vreinterpretq_s64_u64
+    }
+}
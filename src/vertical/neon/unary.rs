@@ -0,0 +1,126 @@
+use core::arch::aarch64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_unary_op! {
+    #[cfg(target_feature = "neon")]
+    VerticalAbs as abs() => Sign::abs for f32, f64, i8, i16, i32, i64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "neon")]
+    VerticalNeg as neg() => Sign::neg for f32, f64, i8, i16, i32, i64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "neon")]
+    VerticalSqrt as sqrt() => Unary::sqrt for f32, f64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "neon")]
+    VerticalRsqrt as rsqrt(refine: bool) => Unary::rsqrt for f32, f64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "neon")]
+    VerticalRecip as recip(refine: bool) => Unary::recip for f32, f64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "neon")]
+    VerticalSquare as square() => Unary::square for f32, f64
+}
+
+macro_rules! impl_sign {
+    ($($t:ty as $vec:ty => $abs:ident & $neg:ident),+) => {
+        $(
+            impl Sign<$t> for $vec {
+                #[inline(always)]
+                unsafe fn abs (self) -> Self {
+                    $abs(self)
+                }
+
+                #[inline(always)]
+                unsafe fn neg (self) -> Self {
+                    $neg(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_sign! {
+    f32 as float32x4_t => vabsq_f32 & vnegq_f32,
+    f64 as float64x2_t => vabsq_f64 & vnegq_f64,
+    i8 as int8x16_t => vabsq_s8 & vnegq_s8,
+    i16 as int16x8_t => vabsq_s16 & vnegq_s16,
+    i32 as int32x4_t => vabsq_s32 & vnegq_s32,
+    i64 as int64x2_t => vabsq_s64 & vnegq_s64
+}
+
+/* FLOATS */
+macro_rules! impl_float {
+    (
+        $t:ty as $vec:ty {
+            $mul:ident, $sqrt:ident,
+            $rsqrte:ident & $rsqrts:ident,
+            $recpe:ident & $recps:ident
+        }
+    ) => {
+        impl Unary<$t> for $vec {
+            #[inline(always)]
+            unsafe fn sqrt (self) -> Self {
+                $sqrt(self)
+            }
+
+            #[inline(always)]
+            unsafe fn square (self) -> Self {
+                $mul(self, self)
+            }
+
+            // The estimates are only accurate to 8 bits, so they always get one Newton-Raphson step, and one more if refined.
+            // The step instructions treat `0 * inf` as zero, so zeros and infinities don't turn into NaN either.
+            #[inline(always)]
+            unsafe fn rsqrt (self, refine: bool) -> Self {
+                // y * (3 - x * y * y) / 2
+                let mut y = $rsqrte(self);
+                y = $mul(y, $rsqrts($mul(y, y), self));
+                if refine {
+                    y = $mul(y, $rsqrts($mul(y, y), self));
+                }
+                return y
+            }
+
+            #[inline(always)]
+            unsafe fn recip (self, refine: bool) -> Self {
+                // y * (2 - x * y)
+                let mut y = $recpe(self);
+                y = $mul(y, $recps(self, y));
+                if refine {
+                    y = $mul(y, $recps(self, y));
+                }
+                return y
+            }
+        }
+    };
+}
+
+impl_float! {
+    f32 as float32x4_t {
+        vmulq_f32, vsqrtq_f32,
+        vrsqrteq_f32 & vrsqrtsq_f32,
+        vrecpeq_f32 & vrecpsq_f32
+    }
+}
+
+impl_float! {
+    f64 as float64x2_t {
+        vmulq_f64, vsqrtq_f64,
+        vrsqrteq_f64 & vrsqrtsq_f64,
+        vrecpeq_f64 & vrecpsq_f64
+    }
+}
@@ -0,0 +1,72 @@
+use core::arch::aarch64::*;
+use core::arch::asm;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use half::{f16, bf16};
+use crate::sealed::Selector;
+use super::*;
+
+impl_widen! {
+    #[cfg(target_feature = "neon")]
+    f16, bf16
+}
+
+impl VerticalConvert<bf16> for [f16] {}
+impl VerticalConvert<f16> for [bf16] {}
+
+// Half-precision floats don't have their own comparisons yet
+impl_default! {
+    VerticalCmp =>
+    f16, bf16
+}
+
+impl_sign! {
+    #[cfg(target_feature = "neon")]
+    f16, bf16
+}
+
+impl_widen_select! {
+    f16, bf16
+}
+
+/* F16 */
+// The conversion intrinsics need the unstable `f16` type, but the instructions are part of the base instruction set
+impl Widen<f16> for float32x4_t {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const f16) -> Self {
+        let v = vld1_u16(ptr.cast());
+        let res: float32x4_t;
+        asm!("fcvtl {0:v}.4s, {1:v}.4h", out(vreg) res, in(vreg) v, options(pure, nomem, nostack));
+        return res
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut f16) {
+        let res: uint16x4_t;
+        asm!("fcvtn {0:v}.4h, {1:v}.4s", out(vreg) res, in(vreg) self, options(pure, nomem, nostack));
+        vst1_u16(ptr.cast(), res)
+    }
+}
+
+/* BF16 */
+// A `bf16` is the top half of an `f32`
+impl Widen<bf16> for float32x4_t {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const bf16) -> Self {
+        vreinterpretq_f32_u32(vshll_n_u16::<16>(vld1_u16(ptr.cast())))
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut bf16) {
+        let x = vreinterpretq_u32_f32(self);
+
+        // Adding just under half an ulp (plus one if the truncated result is odd) rounds ties to even
+        let lsb = vandq_u32(vshrq_n_u32::<16>(x), vdupq_n_u32(1));
+        let rounded = vaddq_u32(vaddq_u32(x, vdupq_n_u32(0x7fff)), lsb);
+
+        // NaNs are made quiet instead, so that rounding can't carry them into infinities
+        let nan = vmvnq_u32(vceqq_f32(self, self));
+        let x = vbslq_u32(nan, vorrq_u32(x, vdupq_n_u32(0x00400000)), rounded);
+        vst1_u16(ptr.cast(), vshrn_n_u32::<16>(x))
+    }
+}
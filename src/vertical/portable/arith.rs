@@ -0,0 +1,65 @@
+use docfg::docfg;
+use crate::sealed::Lane;
+use super::*;
+
+impl_accumulate! {
+    #[cfg(feature = "portable")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+impl_mean! {
+    #[cfg(feature = "portable")]
+    f32, f64
+}
+
+impl_lane! {
+    #[cfg(feature = "portable")]
+    f32, f64
+}
+
+// Integer vectors wrap around on overflow
+macro_rules! impl_arith {
+    ($($t:ty),+) => {
+        $(
+            impl Arith<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn add (self, rhs: Self) -> Self {
+                    self + rhs
+                }
+
+                #[inline(always)]
+                unsafe fn sub (self, rhs: Self) -> Self {
+                    self - rhs
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_float {
+    ($($t:ty),+) => {
+        $(
+            impl FloatArith<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn mul (self, rhs: Self) -> Self {
+                    self * rhs
+                }
+
+                #[inline(always)]
+                unsafe fn div (self, rhs: Self) -> Self {
+                    self / rhs
+                }
+            }
+        )+
+    };
+}
+
+impl_arith! {
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+impl_float! { f32, f64 }
@@ -0,0 +1,47 @@
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_bitwise! {
+    #[cfg(feature = "portable")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_register {
+    ($($t:ty),+) => {
+        $(
+            impl Bitwise<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn and (self, rhs: Self) -> Self {
+                    self & rhs
+                }
+
+                #[inline(always)]
+                unsafe fn or (self, rhs: Self) -> Self {
+                    self | rhs
+                }
+
+                #[inline(always)]
+                unsafe fn xor (self, rhs: Self) -> Self {
+                    self ^ rhs
+                }
+
+                #[inline(always)]
+                unsafe fn andnot (self, rhs: Self) -> Self {
+                    self & !rhs
+                }
+
+                #[inline(always)]
+                unsafe fn not (self) -> Self {
+                    !self
+                }
+            }
+        )+
+    };
+}
+
+impl_register! {
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
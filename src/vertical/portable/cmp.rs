@@ -0,0 +1,39 @@
+use std::simd::prelude::*;
+use docfg::docfg;
+use super::*;
+
+impl_cmp! {
+    #[cfg(feature = "portable")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_cmp {
+    ($($t:ty),+) => {
+        $(
+            impl Compare<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn eq (self, rhs: Self) -> u64 {
+                    self.simd_eq(rhs).to_bitmask()
+                }
+
+                #[inline(always)]
+                unsafe fn lt (self, rhs: Self) -> u64 {
+                    self.simd_lt(rhs).to_bitmask()
+                }
+
+                #[inline(always)]
+                unsafe fn le (self, rhs: Self) -> u64 {
+                    self.simd_le(rhs).to_bitmask()
+                }
+            }
+        )+
+    };
+}
+
+impl_cmp! {
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
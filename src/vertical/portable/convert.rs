@@ -0,0 +1,57 @@
+use std::simd::{prelude::*, SimdElement};
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+// Conversions go through blocks of a fixed number of elements, so that every pair of element types shares the same block size
+/// Number of elements converted at once, enough for a full vector of 32-bit lanes
+const BLOCK: usize = WIDTH / 4;
+
+/// `Simd::cast` follows the semantics of `as`, so every pair of element types converts the same way.
+macro_rules! impl_convert {
+    ($($a:ident),+ => $bs:tt) => {
+        $(
+            impl_convert! { @row $a => $bs }
+        )+
+    };
+
+    (@row $a:ident => [$($b:ident),+]) => {
+        $(
+            #[docfg(feature = "portable")]
+            impl SimdVerticalConvert<$b> for [$a] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$b>]) {
+                    convert_raw(self.as_ptr(), out.as_mut_ptr().cast(), self.len(), |v: Simd<$a, BLOCK>| v.cast::<$b>())
+                }
+            }
+        )+
+    };
+}
+
+impl_convert! {
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+    => [f32, f64, i8, u8, i16, u16, i32, u32, i64, u64]
+}
+
+/// Converts `len` elements from `src` into `dst`, one block at a time.
+///
+/// The trailing elements are padded with zeros into a full block.
+#[inline(always)]
+unsafe fn convert_raw<A: SimdElement, B: SimdElement> (src: *const A, dst: *mut B, len: usize, f: impl Fn(Simd<A, BLOCK>) -> Simd<B, BLOCK>) {
+    let mut i = 0;
+    while i + BLOCK <= len {
+        let v = src.add(i).cast::<Simd<A, BLOCK>>().read_unaligned();
+        dst.add(i).cast::<Simd<B, BLOCK>>().write_unaligned(f(v));
+        i += BLOCK;
+    }
+
+    if i < len {
+        let rem = len - i;
+        let mut lhs = MaybeUninit::<Simd<A, BLOCK>>::zeroed();
+        core::ptr::copy_nonoverlapping(src.add(i), lhs.as_mut_ptr().cast::<A>(), rem);
+        let rhs = f(lhs.assume_init());
+        core::ptr::copy_nonoverlapping(core::ptr::addr_of!(rhs).cast::<B>(), dst.add(i), rem);
+    }
+}
@@ -0,0 +1,34 @@
+use docfg::docfg;
+use super::*;
+
+impl_mul_add! {
+    #[cfg(feature = "portable")]
+    f32, f64
+}
+
+impl_default! {
+    VerticalMulAdd =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
+
+/// Whether the target has fused multiply-add instructions. Without them, `StdFloat::mul_add` falls back to a library call for every lane.
+pub(super) const FUSED: bool = cfg!(any(target_feature = "fma", target_arch = "aarch64"));
+
+macro_rules! impl_float {
+    ($($t:ty),+) => {
+        $(
+            impl MulAdd<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn mul_add (self, a: Self, b: Self) -> Self {
+                    if FUSED {
+                        return std::simd::StdFloat::mul_add(self, a, b)
+                    }
+                    return self * a + b
+                }
+            }
+        )+
+    };
+}
+
+impl_float! { f32, f64 }
@@ -0,0 +1,78 @@
+use std::simd::prelude::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::vertical::math::*;
+use crate::vertical::simd::math::*;
+use super::*;
+
+impl_math! {
+    #[cfg(feature = "portable")]
+    f32, f64
+}
+
+// There are no half-precision conversions to widen `f16` through
+#[cfg(feature = "half")]
+impl_widen_math! {
+    #[cfg(any())]
+    f16
+}
+
+#[cfg(feature = "half")]
+impl_widen_math! {
+    #[cfg(feature = "portable")]
+    bf16
+}
+
+/* REGISTERS */
+// Bitwise operations and shifts go through the lanes' bits
+macro_rules! impl_lanes {
+    ($($t:ty),+) => {
+        $(
+            impl Lanes<$t> for vector!($t) {
+                type Mask = <Self as SimdPartialEq>::Mask;
+                const FUSED: bool = super::fma::FUSED;
+
+                #[inline(always)]
+                unsafe fn add (self, rhs: Self) -> Self { self + rhs }
+                #[inline(always)]
+                unsafe fn sub (self, rhs: Self) -> Self { self - rhs }
+                #[inline(always)]
+                unsafe fn mul (self, rhs: Self) -> Self { self * rhs }
+                #[inline(always)]
+                unsafe fn div (self, rhs: Self) -> Self { self / rhs }
+                #[inline(always)]
+                unsafe fn and (self, rhs: Self) -> Self { Self::from_bits(self.to_bits() & rhs.to_bits()) }
+                #[inline(always)]
+                unsafe fn or (self, rhs: Self) -> Self { Self::from_bits(self.to_bits() | rhs.to_bits()) }
+                #[inline(always)]
+                unsafe fn xor (self, rhs: Self) -> Self { Self::from_bits(self.to_bits() ^ rhs.to_bits()) }
+                #[inline(always)]
+                unsafe fn shl (self, count: i32) -> Self { Self::from_bits(self.to_bits() << Simd::splat(count as _)) }
+                #[inline(always)]
+                unsafe fn shr (self, count: i32) -> Self { Self::from_bits(self.to_bits() >> Simd::splat(count as _)) }
+
+                #[inline(always)]
+                unsafe fn lt (self, rhs: Self) -> Self::Mask { self.simd_lt(rhs) }
+                #[inline(always)]
+                unsafe fn le (self, rhs: Self) -> Self::Mask { self.simd_le(rhs) }
+                #[inline(always)]
+                unsafe fn eq (self, rhs: Self) -> Self::Mask { self.simd_eq(rhs) }
+                #[inline(always)]
+                unsafe fn unord (self, rhs: Self) -> Self::Mask { self.is_nan() | rhs.is_nan() }
+
+                #[inline(always)]
+                unsafe fn mask_and (lhs: Self::Mask, rhs: Self::Mask) -> Self::Mask { lhs & rhs }
+                #[inline(always)]
+                unsafe fn mask_or (lhs: Self::Mask, rhs: Self::Mask) -> Self::Mask { lhs | rhs }
+                #[inline(always)]
+                unsafe fn mask_andnot (lhs: Self::Mask, rhs: Self::Mask) -> Self::Mask { lhs & !rhs }
+                #[inline(always)]
+                unsafe fn any (mask: Self::Mask) -> bool { mask.any() }
+                #[inline(always)]
+                unsafe fn select (mask: Self::Mask, a: Self, b: Self) -> Self { mask.select(a, b) }
+            }
+        )+
+    };
+}
+
+impl_lanes! { f32, f64 }
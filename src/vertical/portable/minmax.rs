@@ -0,0 +1,63 @@
+use std::simd::prelude::*;
+use docfg::docfg;
+use super::*;
+
+impl_minmax! {
+    #[cfg(feature = "portable")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* FLOATS */
+macro_rules! impl_float {
+    ($($t:ty),+) => {
+        $(
+            impl MinMax<$t> for vector!($t) {
+                // Like `f32::min`, these return the other lane if only one of them is NaN
+                #[inline(always)]
+                unsafe fn min (self, rhs: Self) -> Self {
+                    self.simd_min(rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn max (self, rhs: Self) -> Self {
+                    self.simd_max(rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn clamp (self, lo: Self, hi: Self) -> Self {
+                    // NaN lanes compare false both ways, so they're let through
+                    let x = self.simd_lt(lo).select(lo, self);
+                    return self.simd_gt(hi).select(hi, x)
+                }
+            }
+        )+
+    };
+}
+
+impl_float! { f32, f64 }
+
+/* INTEGERS */
+macro_rules! impl_int {
+    ($($t:ty),+) => {
+        $(
+            impl MinMax<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn min (self, rhs: Self) -> Self {
+                    self.simd_min(rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn max (self, rhs: Self) -> Self {
+                    self.simd_max(rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_int! {
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
@@ -0,0 +1,78 @@
+//! Portable backend, on the [`Simd`] vectors of `std::simd`.
+//!
+//! Every element type gets a vector of [`WIDTH`] bytes, which LLVM splits into (or fuses from) whatever registers
+//! the target actually has. It's slower than the hand-written backends, but it runs everywhere.
+
+use std::simd::Simd;
+use docfg::docfg;
+use concat_idents::concat_idents;
+use super::*;
+use super::simd::*;
+
+/// Width of the vectors, in bytes
+const WIDTH: usize = 32;
+
+/// Vector of `T`s, [`WIDTH`] bytes wide
+macro_rules! vector {
+    ($t:ty) => {
+        Simd<$t, { WIDTH / core::mem::size_of::<$t>() }>
+    };
+}
+
+mod register;
+mod arith;
+mod minmax;
+mod fma;
+mod unary;
+mod round;
+mod cmp;
+mod select;
+mod bitwise;
+mod shift;
+mod convert;
+#[cfg(feature = "half")]
+mod widen;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod math;
+
+/* ADDITIONS */
+impl_arith_op! {
+    #[cfg(feature = "portable")]
+    VerticalAdd as add => Arith::add for
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* SUBTRACTIONS */
+impl_arith_op! {
+    #[cfg(feature = "portable")]
+    VerticalSub as sub => Arith::sub for
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* MULTIPLICATIONS */
+impl_arith_op! {
+    #[cfg(feature = "portable")]
+    VerticalMul as mul => FloatArith::mul for f32, f64
+}
+
+impl_default! {
+    VerticalMul =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
+
+/* DIVISIONS */
+impl_arith_op! {
+    #[cfg(feature = "portable")]
+    VerticalDiv as div => FloatArith::div for f32, f64
+}
+
+impl_default! {
+    VerticalDiv =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
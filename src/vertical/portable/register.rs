@@ -0,0 +1,38 @@
+use std::simd::Simd;
+use super::*;
+
+macro_rules! impl_register {
+    ($($t:ty),+) => {
+        $(
+            impl Register<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn load (ptr: *const $t) -> Self {
+                    ptr.cast::<Self>().read_unaligned()
+                }
+
+                #[inline(always)]
+                unsafe fn store (self, ptr: *mut $t) {
+                    ptr.cast::<Self>().write_unaligned(self)
+                }
+
+                #[inline(always)]
+                unsafe fn splat (value: $t) -> Self {
+                    Simd::splat(value)
+                }
+            }
+
+            impl Vectorize for $t {
+                type Vector = vector!($t);
+                type Vector256 = vector!($t);
+                type Vector128 = vector!($t);
+                const ISA: &'static str = "std::simd";
+            }
+        )+
+    };
+}
+
+impl_register! {
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
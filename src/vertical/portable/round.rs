@@ -0,0 +1,44 @@
+use std::simd::StdFloat;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_round! {
+    #[cfg(feature = "portable")]
+    f32, f64
+}
+
+macro_rules! impl_float {
+    ($($t:ty),+) => {
+        $(
+            impl Round<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn floor (self) -> Self {
+                    StdFloat::floor(self)
+                }
+
+                #[inline(always)]
+                unsafe fn ceil (self) -> Self {
+                    StdFloat::ceil(self)
+                }
+
+                #[inline(always)]
+                unsafe fn trunc (self) -> Self {
+                    StdFloat::trunc(self)
+                }
+
+                #[inline(always)]
+                unsafe fn round (self) -> Self {
+                    StdFloat::round(self)
+                }
+
+                #[inline(always)]
+                unsafe fn round_ties_even (self) -> Self {
+                    StdFloat::round_ties_even(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_float! { f32, f64 }
@@ -0,0 +1,31 @@
+use std::simd::prelude::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::sealed::Selector;
+use super::*;
+
+impl_select! {
+    #[cfg(feature = "portable")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_blend {
+    ($($t:ty),+) => {
+        $(
+            impl Blend<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn blend (self, rhs: Self, bits: u64) -> Self {
+                    <Self as SimdPartialEq>::Mask::from_bitmask(bits).select(rhs, self)
+                }
+            }
+        )+
+    };
+}
+
+impl_blend! {
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
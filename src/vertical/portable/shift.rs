@@ -0,0 +1,90 @@
+use std::simd::prelude::*;
+use docfg::docfg;
+use super::*;
+
+impl_shift! {
+    #[cfg(feature = "portable")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/// Implements [`Shift`] for a pair of signed and unsigned lanes of the same width.
+///
+/// Right shifts are arithmetic on signed lanes and logical on unsigned ones, so logical shifts of signed lanes
+/// go through their unsigned reinterpretation.
+macro_rules! impl_register {
+    ($($si:ident & $us:ident),+) => {
+        $(
+            impl Shift<$si> for vector!($si) {
+                #[inline(always)]
+                unsafe fn shl (self, n: u32) -> Self {
+                    self << Simd::splat(n as $si)
+                }
+
+                #[inline(always)]
+                unsafe fn shr (self, n: u32) -> Self {
+                    self >> Simd::splat(n as $si)
+                }
+
+                #[inline(always)]
+                unsafe fn shr_logical (self, n: u32) -> Self {
+                    (self.cast::<$us>() >> Simd::splat(n as $us)).cast()
+                }
+
+                #[inline(always)]
+                unsafe fn shlv (self, counts: Self) -> Self {
+                    self << counts
+                }
+
+                #[inline(always)]
+                unsafe fn shrv (self, counts: Self) -> Self {
+                    self >> counts
+                }
+
+                #[inline(always)]
+                unsafe fn shrv_logical (self, counts: Self) -> Self {
+                    (self.cast::<$us>() >> counts.cast::<$us>()).cast()
+                }
+            }
+
+            impl Shift<$us> for vector!($us) {
+                #[inline(always)]
+                unsafe fn shl (self, n: u32) -> Self {
+                    self << Simd::splat(n as $us)
+                }
+
+                #[inline(always)]
+                unsafe fn shr (self, n: u32) -> Self {
+                    self >> Simd::splat(n as $us)
+                }
+
+                #[inline(always)]
+                unsafe fn shr_logical (self, n: u32) -> Self {
+                    self >> Simd::splat(n as $us)
+                }
+
+                #[inline(always)]
+                unsafe fn shlv (self, counts: Self) -> Self {
+                    self << counts
+                }
+
+                #[inline(always)]
+                unsafe fn shrv (self, counts: Self) -> Self {
+                    self >> counts
+                }
+
+                #[inline(always)]
+                unsafe fn shrv_logical (self, counts: Self) -> Self {
+                    self >> counts
+                }
+            }
+        )+
+    };
+}
+
+impl_register! {
+    i8 & u8,
+    i16 & u16,
+    i32 & u32,
+    i64 & u64
+}
@@ -0,0 +1,92 @@
+use std::simd::{prelude::*, StdFloat};
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_unary_op! {
+    #[cfg(feature = "portable")]
+    VerticalAbs as abs() => Sign::abs for f32, f64, i8, i16, i32, i64
+}
+
+impl_unary_op! {
+    #[cfg(feature = "portable")]
+    VerticalNeg as neg() => Sign::neg for f32, f64, i8, i16, i32, i64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(feature = "portable")]
+    VerticalSqrt as sqrt() => Unary::sqrt for f32, f64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(feature = "portable")]
+    VerticalRsqrt as rsqrt(refine: bool) => Unary::rsqrt for f32, f64
+}
+
+impl_unary_op! {
+    #[cfg(feature = "portable")]
+    VerticalRecip as recip(refine: bool) => Unary::recip for f32, f64
+}
+
+impl_unary_op! {
+    #[cfg(feature = "portable")]
+    VerticalSquare as square() => Unary::square for f32, f64
+}
+
+// Integer lanes wrap around, so the absolute value and negation of `MIN` are `MIN`
+macro_rules! impl_sign {
+    ($($t:ty as $simd:ident),+) => {
+        $(
+            impl Sign<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn abs (self) -> Self {
+                    $simd::abs(self)
+                }
+
+                #[inline(always)]
+                unsafe fn neg (self) -> Self {
+                    -self
+                }
+            }
+        )+
+    };
+}
+
+impl_sign! {
+    f32 as SimdFloat, f64 as SimdFloat,
+    i8 as SimdInt, i16 as SimdInt, i32 as SimdInt, i64 as SimdInt
+}
+
+/* FLOATS */
+// There are no estimates to refine, so the reciprocals are always exact
+macro_rules! impl_float {
+    ($($t:ty),+) => {
+        $(
+            impl Unary<$t> for vector!($t) {
+                #[inline(always)]
+                unsafe fn sqrt (self) -> Self {
+                    StdFloat::sqrt(self)
+                }
+
+                #[inline(always)]
+                unsafe fn square (self) -> Self {
+                    self * self
+                }
+
+                #[inline(always)]
+                unsafe fn rsqrt (self, _refine: bool) -> Self {
+                    Simd::splat(1.0) / StdFloat::sqrt(self)
+                }
+
+                #[inline(always)]
+                unsafe fn recip (self, _refine: bool) -> Self {
+                    SimdFloat::recip(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_float! { f32, f64 }
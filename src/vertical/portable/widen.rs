@@ -0,0 +1,61 @@
+use std::simd::prelude::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use half::{f16, bf16};
+use crate::sealed::Selector;
+use super::*;
+
+// `std::simd` has no half-precision conversions
+impl_widen! {
+    #[cfg(any())]
+    f16
+}
+
+impl_widen! {
+    #[cfg(feature = "portable")]
+    bf16
+}
+
+impl VerticalConvert<bf16> for [f16] {}
+impl VerticalConvert<f16> for [bf16] {}
+
+// Half-precision floats don't have their own comparisons yet
+impl_default! {
+    VerticalCmp =>
+    f16, bf16
+}
+
+impl_sign! {
+    #[cfg(feature = "portable")]
+    f16, bf16
+}
+
+impl_widen_select! {
+    f16, bf16
+}
+
+/* BF16 */
+/// Vector of `u16` with as many lanes as a vector of `f32`
+type Bits = Simd<u16, { WIDTH / 4 }>;
+
+// A `bf16` is the top half of an `f32`
+impl Widen<bf16> for vector!(f32) {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const bf16) -> Self {
+        let v = ptr.cast::<Bits>().read_unaligned();
+        Simd::from_bits(v.cast::<u32>() << 16)
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut bf16) {
+        let x = self.to_bits();
+
+        // Adding just under half an ulp (plus one if the truncated result is odd) rounds ties to even
+        let lsb = (x >> 16) & Simd::splat(1);
+        let rounded = x + Simd::splat(0x7fff) + lsb;
+
+        // NaNs are made quiet instead, so that rounding can't carry them into infinities
+        let x = self.is_nan().select(x | Simd::splat(0x00400000), rounded);
+        ptr.cast::<Bits>().write_unaligned((x >> 16).cast::<u16>())
+    }
+}
@@ -0,0 +1,87 @@
+//! Fallback for targets without a SIMD backend, where every slice keeps the scalar default methods.
+
+#[cfg(feature = "half")]
+use half::{f16, bf16};
+#[cfg(any(feature = "std", feature = "libm"))]
+use super::math::VerticalMath;
+use super::*;
+
+macro_rules! impl_defaults {
+    ($($trait:ident),+ => $t:tt) => {
+        $(
+            impl_default! { $trait => $t }
+        )+
+    };
+    ($($trait:ident),+ => $t:tt, $($rest:tt),+) => {
+        impl_defaults! { $($trait),+ => $t }
+        impl_defaults! { $($trait),+ => $($rest),+ }
+    };
+}
+
+/// Conversions from every type in the list to every other one, itself included.
+macro_rules! impl_convert {
+    (@from $t:ty => [$($u:ty),+]) => {
+        $(
+            impl VerticalConvert<$u> for [$t] {}
+        )+
+    };
+    (@each $all:tt $($t:ty),+) => {
+        $(
+            impl_convert! { @from $t => $all }
+        )+
+    };
+    ($($t:ty),+) => {
+        impl_convert! { @each [$($t),+] $($t),+ }
+    };
+}
+
+impl_defaults! {
+    VerticalAdd, VerticalSub, VerticalMul, VerticalDiv, VerticalAccumulate,
+    VerticalMin, VerticalMax, VerticalClamp, VerticalMulAdd, VerticalCmp, VerticalSelect =>
+    i8, u8, i16, u16, i32, u32, i64, u64, f32, f64
+}
+
+impl_defaults! {
+    VerticalAbs, VerticalNeg =>
+    i8, i16, i32, i64, f32, f64
+}
+
+impl_defaults! {
+    VerticalMean, VerticalRecip, VerticalSquare, VerticalRound =>
+    f32, f64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_defaults! {
+    VerticalSqrt, VerticalRsqrt, VerticalMath =>
+    f32, f64
+}
+
+impl_defaults! {
+    VerticalAnd, VerticalOr, VerticalXor, VerticalAndNot, VerticalNot, VerticalShift, VerticalRotate =>
+    i8, u8, i16, u16, i32, u32, i64, u64
+}
+
+#[cfg(not(feature = "half"))]
+impl_convert! {
+    i8, u8, i16, u16, i32, u32, i64, u64, f32, f64
+}
+
+#[cfg(feature = "half")]
+impl_convert! {
+    i8, u8, i16, u16, i32, u32, i64, u64, f32, f64, f16, bf16
+}
+
+#[cfg(feature = "half")]
+impl_defaults! {
+    VerticalAdd, VerticalSub, VerticalMul, VerticalDiv, VerticalAccumulate, VerticalMean,
+    VerticalMin, VerticalMax, VerticalClamp, VerticalMulAdd, VerticalAbs, VerticalNeg,
+    VerticalRecip, VerticalSquare, VerticalRound, VerticalCmp, VerticalSelect =>
+    f16, bf16
+}
+
+#[cfg(all(feature = "half", any(feature = "std", feature = "libm")))]
+impl_defaults! {
+    VerticalSqrt, VerticalRsqrt, VerticalMath =>
+    f16, bf16
+}
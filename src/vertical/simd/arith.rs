@@ -0,0 +1,159 @@
+use super::*;
+
+/// Lane-wise addition and subtraction. Integer lanes wrap around on overflow.
+pub(crate) trait Arith<T: Copy>: Register<T> {
+    unsafe fn add (self, rhs: Self) -> Self;
+    unsafe fn sub (self, rhs: Self) -> Self;
+}
+
+/// Lane-wise multiplication and division of floating point lanes.
+pub(crate) trait FloatArith<T: Copy>: Arith<T> {
+    unsafe fn mul (self, rhs: Self) -> Self;
+    unsafe fn div (self, rhs: Self) -> Self;
+}
+
+/// Implements one of the arithmetic traits through the lane-wise `$register::$f`, along with it's cyclic variant.
+#[allow(unused_macros)]
+macro_rules! impl_arith_op {
+    (#[cfg($meta:meta)] $trait:ident as $op:ident => $register:ident::$f:ident for $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl $trait for [$t] {}
+
+            concat_idents!(r#trait = Simd, $trait {
+                #[docfg($meta)]
+                impl r#trait for [$t] {
+                    concat_idents!(f = $op, _assign_unchecked {
+                        #[inline]
+                        unsafe fn f (&mut self, rhs: &Self) {
+                            vectorized!($t => zip_assign(self, rhs, |x, y| $register::<$t>::$f(x, y)))
+                        }
+                    });
+
+                    concat_idents!(f = $op, _assign_cyclic_unchecked {
+                        #[inline]
+                        unsafe fn f (&mut self, rhs: &Self) {
+                            vectorized!($t => zip_cyclic_assign(self, rhs, |x, y| $register::<$t>::$f(x, y)))
+                        }
+                    });
+                }
+            });
+        )+
+    };
+}
+
+macro_rules! impl_accumulate {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalAccumulate for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalAccumulate for [$t] {
+                #[inline]
+                unsafe fn add_assign_many_unchecked (&mut self, sources: &[&Self]) {
+                    vectorized!($t => fold_many_assign(self, sources, |x, y| Arith::<$t>::add(x, y)))
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_mean {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalMean for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalMean for [$t] {
+                #[inline]
+                unsafe fn mean_of_unchecked (&mut self, sources: &[&Self]) {
+                    let (first, rest) = sources.split_first().unwrap_unchecked();
+                    vectorized!($t => {
+                        let count = <Vector<$t> as Register<$t>>::splat(sources.len() as $t);
+                        fold_many_raw(
+                            first.as_ptr(), rest, self.as_mut_ptr(), self.len(),
+                            |x, y| Arith::<$t>::add(x, y),
+                            |x| FloatArith::<$t>::div(x, count)
+                        )
+                    })
+                }
+            }
+        )+
+    };
+}
+
+/// Every register with floating point arithmetic can evaluate expressions.
+impl<T: Copy + Default + core::ops::Neg<Output = T>, V: FloatArith<T>> crate::sealed::Lanes<T> for V {
+    const LANES: usize = <V as Register<T>>::LANES;
+
+    #[inline(always)]
+    unsafe fn load (ptr: *const T) -> Self {
+        Register::<T>::load(ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn store (v: Self, ptr: *mut T) {
+        Register::<T>::store(v, ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn load_partial (ptr: *const T, len: usize) -> Self {
+        Register::<T>::load_partial(ptr, len)
+    }
+
+    #[inline(always)]
+    unsafe fn store_partial (v: Self, ptr: *mut T, len: usize) {
+        Register::<T>::store_partial(v, ptr, len)
+    }
+
+    #[inline(always)]
+    unsafe fn splat (value: T) -> Self {
+        Register::<T>::splat(value)
+    }
+
+    #[inline(always)]
+    unsafe fn add (lhs: Self, rhs: Self) -> Self {
+        Arith::<T>::add(lhs, rhs)
+    }
+
+    #[inline(always)]
+    unsafe fn sub (lhs: Self, rhs: Self) -> Self {
+        Arith::<T>::sub(lhs, rhs)
+    }
+
+    #[inline(always)]
+    unsafe fn mul (lhs: Self, rhs: Self) -> Self {
+        FloatArith::<T>::mul(lhs, rhs)
+    }
+
+    #[inline(always)]
+    unsafe fn div (lhs: Self, rhs: Self) -> Self {
+        FloatArith::<T>::div(lhs, rhs)
+    }
+
+    #[inline(always)]
+    unsafe fn neg (v: Self) -> Self {
+        // Subtracting from negative zero flips the sign of zeros too
+        Arith::<T>::sub(Register::<T>::splat(-T::default()), v)
+    }
+}
+
+/// Implements [`Lane`](crate::sealed::Lane), evaluating expressions on the registers allowed by the cap on their width.
+macro_rules! impl_lane {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg($meta)]
+            impl Lane for $t {
+                #[inline]
+                unsafe fn eval<E: crate::expr::Expr<Element = Self>> (expr: &E, out: &mut [core::mem::MaybeUninit<Self>]) {
+                    if crate::width::is_scalar() {
+                        return crate::expr::eval_lanes::<E, $t>(expr, out)
+                    }
+                    vectorized!($t => crate::expr::eval_lanes::<E, Vector<$t>>(expr, out))
+                }
+            }
+        )+
+    };
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+/// Lane-wise bitwise operations, which don't depend on the width of the lanes.
+pub(crate) trait Bitwise<T: Copy>: Register<T> {
+    unsafe fn and (self, rhs: Self) -> Self;
+    unsafe fn or (self, rhs: Self) -> Self;
+    unsafe fn xor (self, rhs: Self) -> Self;
+    /// `self & !rhs`
+    unsafe fn andnot (self, rhs: Self) -> Self;
+    unsafe fn not (self) -> Self;
+}
+
+macro_rules! impl_bitwise {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            impl_bitwise! { @binary #[cfg($meta)] $t: VerticalAnd as and_assign_unchecked & and_assign_scalar => and }
+            impl_bitwise! { @binary #[cfg($meta)] $t: VerticalOr as or_assign_unchecked & or_assign_scalar => or }
+            impl_bitwise! { @binary #[cfg($meta)] $t: VerticalXor as xor_assign_unchecked & xor_assign_scalar => xor }
+            impl_bitwise! { @binary #[cfg($meta)] $t: VerticalAndNot as andnot_assign_unchecked & andnot_assign_scalar => andnot }
+
+            #[cfg(not($meta))]
+            impl VerticalNot for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalNot for [$t] {
+                #[inline]
+                fn not_assign (&mut self) {
+                    unsafe { vectorized!($t => map_assign(self, |x| Bitwise::<$t>::not(x))) }
+                }
+
+                #[inline]
+                unsafe fn not_into_unchecked (&self, out: &mut [MaybeUninit<$t>]) {
+                    vectorized!($t => map_into(self, out, |x| Bitwise::<$t>::not(x)))
+                }
+            }
+        )+
+    };
+
+    (@binary #[cfg($meta:meta)] $t:ident: $trait:ident as $unchecked:ident & $scalar:ident => $f:ident) => {
+        #[cfg(not($meta))]
+        impl $trait for [$t] {}
+
+        concat_idents!(r#trait = Simd, $trait {
+            #[docfg($meta)]
+            impl r#trait for [$t] {
+                #[inline]
+                unsafe fn $unchecked (&mut self, rhs: &Self) {
+                    vectorized!($t => zip_assign(self, rhs, |x, y| Bitwise::<$t>::$f(x, y)))
+                }
+
+                #[inline]
+                fn $scalar (&mut self, rhs: $t) {
+                    unsafe {
+                        vectorized!($t => {
+                            let rhs = Vector::<$t>::splat(rhs);
+                            map_assign(self, |x| Bitwise::<$t>::$f(x, rhs))
+                        })
+                    }
+                }
+            }
+        });
+    };
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+/// Lane-wise comparisons, returning a bitmask with the result for lane `i` on bit `i`.
+///
+/// Floating point comparisons are ordered (`false` if either lane is NaN), except for [`ne`](Compare::ne).
+pub(crate) trait Compare<T: Copy>: Register<T> {
+    /// Bits used by the lanes of the register
+    const BITS: u64 = u64::MAX >> (64 - Self::LANES);
+
+    unsafe fn eq (self, rhs: Self) -> u64;
+    unsafe fn lt (self, rhs: Self) -> u64;
+    unsafe fn le (self, rhs: Self) -> u64;
+
+    #[inline(always)]
+    unsafe fn ne (self, rhs: Self) -> u64 {
+        !self.eq(rhs) & Self::BITS
+    }
+
+    #[inline(always)]
+    unsafe fn gt (self, rhs: Self) -> u64 {
+        rhs.lt(self)
+    }
+
+    #[inline(always)]
+    unsafe fn ge (self, rhs: Self) -> u64 {
+        rhs.le(self)
+    }
+}
+
+macro_rules! impl_cmp {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalCmp for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalCmp for [$t] {
+                impl_cmp! { @op $t: cmp_eq_into_unchecked & cmp_eq_scalar_into_unchecked => eq }
+                impl_cmp! { @op $t: cmp_ne_into_unchecked & cmp_ne_scalar_into_unchecked => ne }
+                impl_cmp! { @op $t: cmp_lt_into_unchecked & cmp_lt_scalar_into_unchecked => lt }
+                impl_cmp! { @op $t: cmp_le_into_unchecked & cmp_le_scalar_into_unchecked => le }
+                impl_cmp! { @op $t: cmp_gt_into_unchecked & cmp_gt_scalar_into_unchecked => gt }
+                impl_cmp! { @op $t: cmp_ge_into_unchecked & cmp_ge_scalar_into_unchecked => ge }
+            }
+        )+
+    };
+
+    (@op $t:ident: $into:ident & $scalar_into:ident => $f:ident) => {
+        #[inline]
+        unsafe fn $into (&self, rhs: &Self, out: &mut [u64]) {
+            let (lhs, rhs) = (self.as_ptr(), rhs.as_ptr());
+            vectorized!($t => pack_raw::<$t>(self.len(), out, |i, n| {
+                Compare::<$t>::$f(load_lanes(lhs.add(i), n), load_lanes(rhs.add(i), n))
+            }))
+        }
+
+        #[inline]
+        unsafe fn $scalar_into (&self, rhs: $t, out: &mut [u64]) {
+            let lhs = self.as_ptr();
+            vectorized!($t => {
+                let rhs = Vector::<$t>::splat(rhs);
+                pack_raw::<$t>(self.len(), out, |i, n| Compare::<$t>::$f(load_lanes(lhs.add(i), n), rhs))
+            })
+        }
+    };
+}
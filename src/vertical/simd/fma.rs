@@ -0,0 +1,42 @@
+use super::*;
+
+/// Lane-wise `self * a + b`, rounded once if the target supports FMA instructions.
+pub(crate) trait MulAdd<T: Copy>: Register<T> {
+    unsafe fn mul_add (self, a: Self, b: Self) -> Self;
+}
+
+macro_rules! impl_mul_add {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalMulAdd for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalMulAdd for [$t] {
+                #[inline]
+                unsafe fn mul_add_assign_unchecked (&mut self, a: &Self, b: &Self) {
+                    vectorized!($t => zip3_assign(self, a, b, |x, a, b| MulAdd::<$t>::mul_add(x, a, b)))
+                }
+
+                #[inline]
+                unsafe fn axpy_unchecked (&mut self, alpha: $t, x: &Self) {
+                    vectorized!($t => {
+                        let alpha = Vector::<$t>::splat(alpha);
+                        zip_assign(self, x, |y, x| MulAdd::<$t>::mul_add(x, alpha, y))
+                    })
+                }
+
+                #[inline]
+                fn affine_assign (&mut self, scale: $t, offset: $t) {
+                    unsafe {
+                        vectorized!($t => {
+                            let scale = Vector::<$t>::splat(scale);
+                            let offset = Vector::<$t>::splat(offset);
+                            map_assign(self, |x| MulAdd::<$t>::mul_add(x, scale, offset))
+                        })
+                    }
+                }
+            }
+        )+
+    };
+}
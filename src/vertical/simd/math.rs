@@ -0,0 +1,667 @@
+//! SIMD elementary functions, written once over [`Lanes`] and instantiated for every float register.
+//!
+//! The argument reductions and kernels follow fdlibm, with some of the polynomials taken from Cephes.
+
+use crate::sealed::{Float, Signed};
+use super::*;
+
+/// Floating point lanes, with the operations needed to evaluate the elementary functions.
+pub(crate) trait Lanes<T: Copy>: Register<T> + MulAdd<T> + MinMax<T> + Round<T> {
+    /// Result of a lane-wise comparison
+    type Mask: Copy;
+    /// Whether [`mul_add`](MulAdd::mul_add) rounds only once
+    const FUSED: bool;
+
+    unsafe fn add (self, rhs: Self) -> Self;
+    unsafe fn sub (self, rhs: Self) -> Self;
+    unsafe fn mul (self, rhs: Self) -> Self;
+    unsafe fn div (self, rhs: Self) -> Self;
+    unsafe fn and (self, rhs: Self) -> Self;
+    unsafe fn or (self, rhs: Self) -> Self;
+    unsafe fn xor (self, rhs: Self) -> Self;
+    /// Shifts the bits of every lane `count` places to the left
+    unsafe fn shl (self, count: i32) -> Self;
+    /// Shifts the bits of every lane `count` places to the right, shifting in zeros
+    unsafe fn shr (self, count: i32) -> Self;
+
+    unsafe fn lt (self, rhs: Self) -> Self::Mask;
+    unsafe fn le (self, rhs: Self) -> Self::Mask;
+    unsafe fn eq (self, rhs: Self) -> Self::Mask;
+    /// Lanes where either operand is NaN
+    unsafe fn unord (self, rhs: Self) -> Self::Mask;
+
+    unsafe fn mask_and (lhs: Self::Mask, rhs: Self::Mask) -> Self::Mask;
+    unsafe fn mask_or (lhs: Self::Mask, rhs: Self::Mask) -> Self::Mask;
+    /// `lhs & !rhs`
+    unsafe fn mask_andnot (lhs: Self::Mask, rhs: Self::Mask) -> Self::Mask;
+    /// Returns `true` if any lane of the mask is set
+    unsafe fn any (mask: Self::Mask) -> bool;
+    /// Selects `a` on the lanes where `mask` is set, and `b` otherwise
+    unsafe fn select (mask: Self::Mask, a: Self, b: Self) -> Self;
+}
+
+/// Constants and coefficients used by the elementary functions.
+pub(crate) trait Elementary: Float + PartialOrd + 'static {
+    const BITS: i32;
+    const MANTISSA: i32;
+
+    const ZERO: Self;
+    const NEG_ZERO: Self;
+    const HALF: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const INFINITY: Self;
+    const NAN: Self;
+    const SQRT_2: Self;
+    const MIN_POSITIVE: Self;
+    const ABS_MASK: Self;
+    const MANTISSA_MASK: Self;
+
+    /// `2^MANTISSA`, the smallest value without fractional bits
+    const MAGIC: Self;
+    /// `MAGIC` plus the exponent bias
+    const MAGIC_BIAS: Self;
+    /// `1.5 * 2^MANTISSA`, which keeps the two's complement of small integers added to it on it's lowest bits
+    const QUAD_MAGIC: Self;
+    /// Scale bringing subnormals into the normal range, and it's base 2 logarithm
+    const SUBNORMAL_SCALE: Self;
+    const SUBNORMAL_EXP: Self;
+    /// `2^ceil(MANTISSA / 2) + 1`, used to split values into halves with exact products
+    const SPLITTER: Self;
+
+    const LN2: Self;
+    const LN2_HI: Self;
+    const LN2_LO: Self;
+    const LOG2_E: Self;
+    const LOG2_E_LO: Self;
+
+    /// Arguments of `exp` beyond this value overflow (or underflow) regardless
+    const EXP_MAX: Self;
+    /// Arguments of `exp2` beyond this value overflow (or underflow) regardless
+    const EXP2_MAX: Self;
+    /// `(e^r - 1 - r) / r^2` on `|r| <= ln(2) / 2`
+    const EXP_POLY: &'static [Self];
+    /// `R(z)` of fdlibm's `log`
+    const LOG_POLY: &'static [Self];
+    /// `2 / 3` as an unevaluated sum
+    const TWO_THIRDS: [Self; 2];
+    /// `Q(z)` such that `log(1 + f) = 2s + 2s^3 / 3 + s * z^2 * Q(z)`, truncated well below the double precision of
+    /// the other terms
+    const LOG_TAIL: &'static [Self];
+
+    const FRAC_2_PI: Self;
+    /// `pi / 2` as an unevaluated sum, with enough trailing zeros on the first two terms for their products with
+    /// integers up to `TRIG_LIMIT * 2 / pi` to be exact
+    const PIO2: [Self; 4];
+    /// Largest argument reduced by `trig_reduce`
+    const TRIG_LIMIT: Self;
+    const SIN_POLY: &'static [Self];
+    const COS_POLY: &'static [Self];
+
+    /// Largest argument of `tanh`'s rational approximation
+    const TANH_SMALL: Self;
+    const TANH_P: &'static [Self];
+    const TANH_Q: &'static [Self];
+}
+
+impl Elementary for f32 {
+    const BITS: i32 = 32;
+    const MANTISSA: i32 = 23;
+
+    const ZERO: Self = 0.0;
+    const NEG_ZERO: Self = -0.0;
+    const HALF: Self = 0.5;
+    const ONE: Self = 1.0;
+    const TWO: Self = 2.0;
+    const INFINITY: Self = f32::INFINITY;
+    const NAN: Self = f32::NAN;
+    const SQRT_2: Self = core::f32::consts::SQRT_2;
+    const MIN_POSITIVE: Self = f32::MIN_POSITIVE;
+    const ABS_MASK: Self = f32::from_bits(0x7fff_ffff);
+    const MANTISSA_MASK: Self = f32::from_bits(0x007f_ffff);
+
+    const MAGIC: Self = 8388608.0;
+    const MAGIC_BIAS: Self = 8388735.0;
+    const QUAD_MAGIC: Self = 12582912.0;
+    const SUBNORMAL_SCALE: Self = 16777216.0;
+    const SUBNORMAL_EXP: Self = 24.0;
+    const SPLITTER: Self = 4097.0;
+
+    const LN2: Self = core::f32::consts::LN_2;
+    const LN2_HI: Self = 6.9314575e-1;
+    const LN2_LO: Self = 1.4286068e-6;
+    const LOG2_E: Self = core::f32::consts::LOG2_E;
+    const LOG2_E_LO: Self = 1.925963e-8;
+
+    const EXP_MAX: Self = 110.0;
+    const EXP2_MAX: Self = 160.0;
+    const EXP_POLY: &'static [Self] = &[
+        0.5, 1.6666666e-1, 4.1665796e-2,
+        8.333452e-3, 1.3981999e-3, 1.9875691e-4
+    ];
+    const LOG_POLY: &'static [Self] = &[
+        6.666666e-1, 4.0000972e-1, 2.8498787e-1, 2.4279079e-1
+    ];
+    const TWO_THIRDS: [Self; 2] = [6.666667e-1, -1.9868216e-8];
+    const LOG_TAIL: &'static [Self] = &[0.4, 2.857143e-1, 2.2222222e-1, 1.8181819e-1];
+
+    const FRAC_2_PI: Self = core::f32::consts::FRAC_2_PI;
+    const PIO2: [Self; 4] = [1.5703125, 4.837513e-4, 7.54979e-8, -1.7151245e-15];
+    const TRIG_LIMIT: Self = 8192.0;
+    const SIN_POLY: &'static [Self] = &[-1.6666655e-1, 8.332161e-3, -1.9515296e-4];
+    const COS_POLY: &'static [Self] = &[4.1666646e-2, -1.3887316e-3, 2.4433157e-5];
+
+    const TANH_SMALL: Self = 0.625;
+    const TANH_P: &'static [Self] = &[
+        -3.333328e-1, 1.3331442e-1, -5.3739715e-2,
+        2.0639088e-2, -5.7049887e-3
+    ];
+    const TANH_Q: &'static [Self] = &[1.0];
+}
+
+impl Elementary for f64 {
+    const BITS: i32 = 64;
+    const MANTISSA: i32 = 52;
+
+    const ZERO: Self = 0.0;
+    const NEG_ZERO: Self = -0.0;
+    const HALF: Self = 0.5;
+    const ONE: Self = 1.0;
+    const TWO: Self = 2.0;
+    const INFINITY: Self = f64::INFINITY;
+    const NAN: Self = f64::NAN;
+    const SQRT_2: Self = core::f64::consts::SQRT_2;
+    const MIN_POSITIVE: Self = f64::MIN_POSITIVE;
+    const ABS_MASK: Self = f64::from_bits(0x7fff_ffff_ffff_ffff);
+    const MANTISSA_MASK: Self = f64::from_bits(0x000f_ffff_ffff_ffff);
+
+    const MAGIC: Self = 4503599627370496.0;
+    const MAGIC_BIAS: Self = 4503599627371519.0;
+    const QUAD_MAGIC: Self = 6755399441055744.0;
+    const SUBNORMAL_SCALE: Self = 18014398509481984.0;
+    const SUBNORMAL_EXP: Self = 54.0;
+    const SPLITTER: Self = 134217729.0;
+
+    const LN2: Self = core::f64::consts::LN_2;
+    const LN2_HI: Self = 0.6931471803691238;
+    const LN2_LO: Self = 1.9082149292705877e-10;
+    const LOG2_E: Self = core::f64::consts::LOG2_E;
+    const LOG2_E_LO: Self = 2.0355273740931033e-17;
+
+    const EXP_MAX: Self = 760.0;
+    const EXP2_MAX: Self = 1100.0;
+    const EXP_POLY: &'static [Self] = &[
+        0.5, 0.16666666666666666, 0.041666666666666664, 0.008333333333333333,
+        0.001388888888888889, 0.0001984126984126984, 2.48015873015873e-5, 2.7557319223985893e-6,
+        2.755731922398589e-7, 2.505210838544172e-8, 2.08767569878681e-9, 1.6059043836821613e-10
+    ];
+    const LOG_POLY: &'static [Self] = &[
+        0.6666666666666735, 0.3999999999940942, 0.2857142874366239, 0.22222198432149784,
+        0.1818357216161805, 0.15313837699209373, 0.14798198605116586
+    ];
+    const TWO_THIRDS: [Self; 2] = [0.6666666666666666, 3.700743415417188e-17];
+    const LOG_TAIL: &'static [Self] = &[
+        0.4, 0.2857142857142857, 0.2222222222222222, 0.18181818181818182, 0.15384615384615385,
+        0.13333333333333333, 0.11764705882352941, 0.10526315789473684, 0.09523809523809523, 0.08695652173913043
+    ];
+
+    const FRAC_2_PI: Self = core::f64::consts::FRAC_2_PI;
+    const PIO2: [Self; 4] = [
+        1.5707963267341256, 6.077100506303966e-11,
+        2.0222662487959506e-21, 1.0085854035872483e-37
+    ];
+    const TRIG_LIMIT: Self = 1048576.0;
+    const SIN_POLY: &'static [Self] = &[
+        -0.16666666666666632, 0.00833333333332249, -0.0001984126982985795,
+        2.7557313707070068e-6, -2.5050760253406863e-8, 1.58969099521155e-10
+    ];
+    const COS_POLY: &'static [Self] = &[
+        0.0416666666666666, -0.001388888888887411, 2.480158728947673e-5,
+        -2.7557314351390663e-7, 2.087572321298175e-9, -1.1359647557788195e-11
+    ];
+
+    const TANH_SMALL: Self = 0.625;
+    const TANH_P: &'static [Self] = &[
+        -1614.6876844170845, -99.28772310019185, -0.9643991794250523
+    ];
+    const TANH_Q: &'static [Self] = &[
+        4844.063053251255, 2235.4883906010045, 112.81167849163293, 1.0
+    ];
+}
+
+macro_rules! impl_math {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalMath for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalMath for [$t] {
+                impl_math! { @op $t: exp_assign & exp_into_unchecked => exp }
+                impl_math! { @op $t: ln_assign & ln_into_unchecked => ln }
+                impl_math! { @op $t: log2_assign & log2_into_unchecked => log2 }
+                impl_math! { @op $t: sin_assign & sin_into_unchecked => sin }
+                impl_math! { @op $t: cos_assign & cos_into_unchecked => cos }
+                impl_math! { @op $t: tanh_assign & tanh_into_unchecked => tanh }
+
+                #[inline]
+                unsafe fn pow_assign_unchecked (&mut self, exp: &Self) {
+                    vectorized!($t => zip_assign(self, exp, |x, y| pow::<$t, _>(x, y)))
+                }
+
+                #[inline]
+                fn pow_assign_scalar (&mut self, exp: $t) {
+                    unsafe {
+                        vectorized!($t => {
+                            let exp = Vector::<$t>::splat(exp);
+                            map_assign(self, |x| pow::<$t, _>(x, exp))
+                        })
+                    }
+                }
+            }
+        )+
+    };
+
+    (@op $t:ident: $assign:ident & $into:ident => $f:ident) => {
+        #[inline]
+        fn $assign (&mut self) {
+            unsafe { vectorized!($t => map_assign(self, |x| $f::<$t, _>(x))) }
+        }
+
+        #[inline]
+        unsafe fn $into (&self, out: &mut [MaybeUninit<$t>]) {
+            vectorized!($t => map_into(self, out, |x| $f::<$t, _>(x)))
+        }
+    };
+}
+
+// Half-precision floats are evaluated on `f32` lanes
+#[cfg(feature = "half")]
+macro_rules! impl_widen_math {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalMath for [half::$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalMath for [half::$t] {
+                impl_widen_math! { @op exp_assign & exp_into_unchecked => exp }
+                impl_widen_math! { @op ln_assign & ln_into_unchecked => ln }
+                impl_widen_math! { @op log2_assign & log2_into_unchecked => log2 }
+                impl_widen_math! { @op sin_assign & sin_into_unchecked => sin }
+                impl_widen_math! { @op cos_assign & cos_into_unchecked => cos }
+                impl_widen_math! { @op tanh_assign & tanh_into_unchecked => tanh }
+
+                #[inline]
+                unsafe fn pow_assign_unchecked (&mut self, exp: &Self) {
+                    vectorized!(f32 => widen_zip_assign(self, exp, |x, y| pow::<f32, _>(x, y)))
+                }
+
+                #[inline]
+                fn pow_assign_scalar (&mut self, exp: half::$t) {
+                    unsafe {
+                        vectorized!(f32 => {
+                            let exp = Vector::<f32>::splat(exp.to_f32());
+                            widen_map_assign(self, |x| pow::<f32, _>(x, exp))
+                        })
+                    }
+                }
+            }
+        )+
+    };
+
+    (@op $assign:ident & $into:ident => $f:ident) => {
+        #[inline]
+        fn $assign (&mut self) {
+            unsafe { vectorized!(f32 => widen_map_assign(self, |x| $f::<f32, _>(x))) }
+        }
+
+        #[inline]
+        unsafe fn $into (&self, out: &mut [MaybeUninit<Self::Element>]) {
+            vectorized!(f32 => widen_map_into(self, out, |x| $f::<f32, _>(x)))
+        }
+    };
+}
+
+/* FUNCTIONS */
+#[inline(always)]
+pub(crate) unsafe fn exp<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    let x = MinMax::<T>::clamp(x, neg(V::splat(T::EXP_MAX)), V::splat(T::EXP_MAX));
+
+    // x = n * ln(2) + r, with `n * LN2_HI` computed exactly
+    let n = Round::<T>::round_ties_even(x.mul(V::splat(T::LOG2_E)));
+    let r = n.mul_add(neg(V::splat(T::LN2_HI)), x);
+    let r = n.mul_add(neg(V::splat(T::LN2_LO)), r);
+
+    return scale(exp_kernel(r), n)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn ln<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    let (e, f) = log_reduce(x);
+    let s = f.div(f.add(V::splat(T::TWO)));
+    let z = s.mul(s);
+    let r = z.mul(poly(z, T::LOG_POLY));
+    let hfsq = V::splat(T::HALF).mul(f).mul(f);
+
+    // e * ln2_hi - ((hfsq - (s * (hfsq + r) + e * ln2_lo)) - f)
+    let lo = s.mul_add(hfsq.add(r), e.mul(V::splat(T::LN2_LO)));
+    let y = e.mul(V::splat(T::LN2_HI)).sub(hfsq.sub(lo).sub(f));
+    return log_special(x, y)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn log2<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    let (hi, _) = log2_dd(x);
+    return log_special(x, hi)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn sin<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    // The reduction loses the sign of zero
+    let y = trig(x, T::ZERO, Float::sin);
+    return V::select(x.eq(V::splat(T::ZERO)), x, y)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn cos<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    trig(x, T::ONE, Float::cos)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn tanh<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    let sign = x.and(V::splat(T::NEG_ZERO));
+    let ax = abs(x);
+
+    // x + x^3 * P(x^2) / Q(x^2)
+    let z = x.mul(x);
+    let mut p = poly(z, T::TANH_P);
+    if T::TANH_Q.len() > 1 {
+        p = p.div(poly(z, T::TANH_Q));
+    }
+    let small = x.mul(z).mul_add(p, x);
+
+    // 1 - 2 / (e^2|x| + 1)
+    let e = exp(ax.add(ax));
+    let large = V::splat(T::ONE).sub(V::splat(T::TWO).div(e.add(V::splat(T::ONE))));
+
+    // The sign is restored on both branches for the sake of negative zero
+    return V::select(ax.lt(V::splat(T::TANH_SMALL)), small, large).or(sign)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn pow<T: Elementary, V: Lanes<T>> (x: V, y: V) -> V {
+    let zero = V::splat(T::ZERO);
+    let one = V::splat(T::ONE);
+    let inf = V::splat(T::INFINITY);
+
+    // x^y = 2^(y * log2(x)), with the product carried on two floats
+    let ax = abs(x);
+    let (l, ll) = log2_dd(ax);
+    let l = V::select(ax.eq(zero), neg(inf), l);
+    let l = V::select(ax.eq(inf), inf, l);
+    let ll = V::select(V::mask_or(ax.eq(zero), ax.eq(inf)), zero, ll);
+
+    let (p, q) = two_prod(y, l);
+    let q = y.mul_add(ll, q);
+
+    // Out of range exponents overflow (or underflow) regardless of the low part, which may have turned into NaN.
+    // So may have the low part of `y * 0` for huge `y`, when splitting it overflows.
+    let limit = V::splat(T::EXP2_MAX);
+    let q = V::select(V::mask_andnot(abs(p).le(limit), q.unord(q)), q, zero);
+    let p = MinMax::<T>::clamp(p, neg(limit), limit);
+
+    let n = Round::<T>::round_ties_even(p);
+    let r = p.sub(n).add(q).mul(V::splat(T::LN2));
+    let z = scale(exp_kernel(r), n);
+
+    // Negative bases
+    let y_int = Round::<T>::trunc(y).eq(y);
+    let half = y.mul(V::splat(T::HALF));
+    let y_odd = V::mask_andnot(y_int, Round::<T>::trunc(half).eq(half));
+    let z = V::select(V::mask_and(y_odd, is_sign_negative(x)), neg(z), z);
+    let finite_neg = V::mask_and(x.lt(zero), neg(inf).lt(x));
+    let z = V::select(V::mask_andnot(finite_neg, y_int), V::splat(T::NAN), z);
+
+    // NaN, except for `x^0` and `1^y`, which are always one (as is `(-1)^inf`)
+    let z = V::select(x.unord(y), x.add(y), z);
+    let is_one = V::mask_or(y.eq(zero), x.eq(one));
+    let is_one = V::mask_or(is_one, V::mask_and(x.eq(neg(one)), abs(y).eq(inf)));
+    return V::select(is_one, one, z)
+}
+
+/* HELPERS */
+/// `e^r` for `|r| <= ln(2) / 2`
+#[inline(always)]
+unsafe fn exp_kernel<T: Elementary, V: Lanes<T>> (r: V) -> V {
+    let p = poly(r, T::EXP_POLY);
+    return r.mul(r).mul_add(p, r).add(V::splat(T::ONE))
+}
+
+/// `x * 2^n`, for an integral `n` within `±EXP2_MAX`
+#[inline(always)]
+unsafe fn scale<T: Elementary, V: Lanes<T>> (x: V, n: V) -> V {
+    // Two steps keep both factors normal, and round subnormal results only once
+    let h = Round::<T>::floor(n.mul(V::splat(T::HALF)));
+    return x.mul(pow2i(h)).mul(pow2i(n.sub(h)))
+}
+
+/// `2^n`, for an integral `n` within the normal exponent range
+#[inline(always)]
+unsafe fn pow2i<T: Elementary, V: Lanes<T>> (n: V) -> V {
+    // The biased exponent ends up on the lowest bits of `MAGIC`, from where we shift it into place
+    return n.add(V::splat(T::MAGIC_BIAS)).shl(T::MANTISSA)
+}
+
+/// Splits `x` into `e` and `f` such that `x = 2^e * (1 + f)`, with `sqrt(2) / 2 <= 1 + f < sqrt(2)`.
+///
+/// Only meaningful for positive, finite `x`.
+#[inline(always)]
+unsafe fn log_reduce<T: Elementary, V: Lanes<T>> (x: V) -> (V, V) {
+    let one = V::splat(T::ONE);
+
+    let tiny = x.lt(V::splat(T::MIN_POSITIVE));
+    let x = V::select(tiny, x.mul(V::splat(T::SUBNORMAL_SCALE)), x);
+    let e = V::select(tiny, neg(V::splat(T::SUBNORMAL_EXP)), V::splat(T::ZERO));
+
+    // The biased exponent ends up on the lowest bits of `MAGIC`
+    let biased = x.shr(T::MANTISSA).or(V::splat(T::MAGIC));
+    let e = biased.sub(V::splat(T::MAGIC_BIAS)).add(e);
+    let m = x.and(V::splat(T::MANTISSA_MASK)).or(one);
+
+    let big = V::splat(T::SQRT_2).lt(m);
+    let m = V::select(big, m.mul(V::splat(T::HALF)), m);
+    let e = V::select(big, e.add(one), e);
+    return (e, m.sub(one))
+}
+
+/// `log2(x)` as an unevaluated sum of two floats, for positive, finite `x`
+#[inline(always)]
+unsafe fn log2_dd<T: Elementary, V: Lanes<T>> (x: V) -> (V, V) {
+    let (e, f) = log_reduce(x);
+
+    // s = f / (2 + f), on two floats
+    let (d, d_lo) = fast_two_sum(V::splat(T::TWO), f);
+    let s = f.div(d);
+    let (sd, sd_lo) = two_prod(s, d);
+    let s_lo = f.sub(sd).sub(sd_lo).sub(s.mul(d_lo)).div(d);
+
+    // z = s^2 and c = s^3, on two floats
+    let (z, z_lo) = two_prod(s, s);
+    let z_lo = s.add(s).mul_add(s_lo, z_lo);
+    let (c, c_lo) = two_prod(s, z);
+    let c_lo = s.mul_add(z_lo, s_lo.mul_add(z, c_lo));
+
+    // log(1 + f) = 2s + 2s^3 / 3 + s * z^2 * Q(z), where only the last term may be rounded
+    let [tt, tt_lo] = T::TWO_THIRDS;
+    let (t, t_lo) = two_prod(c, V::splat(tt));
+    let t_lo = c.mul_add(V::splat(tt_lo), c_lo.mul_add(V::splat(tt), t_lo));
+    let tail = c.mul(z).mul(poly(z, T::LOG_TAIL));
+    let (a, b) = fast_two_sum(s.add(s), t);
+    let b = b.add(s_lo.add(s_lo).add(t_lo).add(tail));
+    let (a, b) = fast_two_sum(a, b);
+
+    // e + log(1 + f) * log2(e)
+    let (p, q) = two_prod(a, V::splat(T::LOG2_E));
+    let q = a.mul_add(V::splat(T::LOG2_E_LO), b.mul_add(V::splat(T::LOG2_E), q));
+    let (w, t) = two_sum(e, p);
+    return fast_two_sum(w, t.add(q))
+}
+
+/// Special cases of the logarithms
+#[inline(always)]
+unsafe fn log_special<T: Elementary, V: Lanes<T>> (x: V, y: V) -> V {
+    let zero = V::splat(T::ZERO);
+    let inf = V::splat(T::INFINITY);
+
+    let y = V::select(x.eq(inf), inf, y);
+    let y = V::select(x.eq(zero), neg(inf), y);
+    let y = V::select(x.lt(zero), V::splat(T::NAN), y);
+    return V::select(x.unord(x), x, y)
+}
+
+/// Sine of `x + quadrant * pi / 2`
+#[inline(always)]
+unsafe fn trig<T: Elementary, V: Lanes<T>> (x: V, quadrant: T, scalar: fn(T) -> T) -> V {
+    let (n, hi, lo) = trig_reduce(x);
+    let s = sin_kernel(hi, lo);
+    let c = cos_kernel(hi, lo);
+
+    // The lowest bits of `q` hold `(n + quadrant) mod 4`. Odd quadrants swap sine and cosine, and the upper two are negated.
+    let q = n.add(V::splat(T::QUAD_MAGIC)).add(V::splat(quadrant));
+    let y = V::select(is_sign_negative(q.shl(T::BITS - 1)), c, s);
+    let y = y.xor(q.shl(T::BITS - 2).and(V::splat(T::NEG_ZERO)));
+
+    // Arguments out of the reduction's range (including infinities) go through the scalar function
+    if V::any(V::splat(T::TRIG_LIMIT).lt(abs(x))) {
+        return trig_fallback(x, y, scalar)
+    }
+    return y
+}
+
+#[cold]
+#[inline(never)]
+unsafe fn trig_fallback<T: Elementary, V: Lanes<T>> (x: V, y: V, scalar: fn(T) -> T) -> V {
+    // Big enough for any register
+    let mut xs = [T::ZERO; 16];
+    let mut ys = [T::ZERO; 16];
+    x.store(xs.as_mut_ptr());
+    y.store(ys.as_mut_ptr());
+
+    for (x, y) in xs.iter().zip(ys.iter_mut()).take(V::LANES) {
+        if Signed::abs(*x) > T::TRIG_LIMIT {
+            *y = scalar(*x)
+        }
+    }
+    return V::load(ys.as_ptr())
+}
+
+/// Reduces `x` into `n` and `hi + lo` such that `x = n * pi / 2 + hi + lo`, with `|hi + lo| <= pi / 4`.
+///
+/// Exact up to `TRIG_LIMIT`, save for the rounding of `hi + lo`.
+#[inline(always)]
+unsafe fn trig_reduce<T: Elementary, V: Lanes<T>> (x: V) -> (V, V, V) {
+    let [p1, p2, p3, p4] = T::PIO2;
+    let n = Round::<T>::round_ties_even(x.mul(V::splat(T::FRAC_2_PI)));
+
+    // Both products are exact, and so is the first subtraction
+    let a = n.mul_add(neg(V::splat(p1)), x);
+    let (r, err) = two_sum(a, neg(n.mul(V::splat(p2))));
+
+    // `r` may cancel out with `n * p3`, so it's subtracted exactly too
+    let (c, c_lo) = two_prod(n, V::splat(p3));
+    let (r, err2) = two_sum(r, neg(c));
+    let tail = n.mul_add(neg(V::splat(p4)), err.add(err2).sub(c_lo));
+
+    let (hi, lo) = two_sum(r, tail);
+    return (n, hi, lo)
+}
+
+/// fdlibm's `__kernel_sin`
+#[inline(always)]
+unsafe fn sin_kernel<T: Elementary, V: Lanes<T>> (x: V, y: V) -> V {
+    let z = x.mul(x);
+    let v = z.mul(x);
+    let r = poly(z, &T::SIN_POLY[1..]);
+
+    // x - ((z * (y / 2 - v * r) - y) - v * S1)
+    let t = z.mul(V::splat(T::HALF).mul(y).sub(v.mul(r))).sub(y);
+    return x.sub(t.sub(v.mul(V::splat(T::SIN_POLY[0]))))
+}
+
+/// fdlibm's `__kernel_cos`
+#[inline(always)]
+unsafe fn cos_kernel<T: Elementary, V: Lanes<T>> (x: V, y: V) -> V {
+    let one = V::splat(T::ONE);
+    let z = x.mul(x);
+    let hz = V::splat(T::HALF).mul(z);
+    let w = one.sub(hz);
+    let r = z.mul(poly(z, T::COS_POLY));
+
+    // w + (((1 - w) - hz) + (z * r - x * y))
+    return w.add(one.sub(w).sub(hz).add(z.mul(r).sub(x.mul(y))))
+}
+
+/// Evaluates the polynomial with the given coefficients (in increasing degree) through Horner's method
+#[inline(always)]
+unsafe fn poly<T: Elementary, V: Lanes<T>> (x: V, coefs: &[T]) -> V {
+    let (last, rest) = coefs.split_last().unwrap_unchecked();
+    let mut y = V::splat(*last);
+    for c in rest.iter().rev() {
+        y = y.mul_add(x, V::splat(*c));
+    }
+    return y
+}
+
+/// `a + b` as an unevaluated sum of two floats
+#[inline(always)]
+unsafe fn two_sum<T: Elementary, V: Lanes<T>> (a: V, b: V) -> (V, V) {
+    let s = a.add(b);
+    let bb = s.sub(a);
+    return (s, a.sub(s.sub(bb)).add(b.sub(bb)))
+}
+
+/// `a + b` as an unevaluated sum of two floats, for `|a| >= |b|`
+#[inline(always)]
+unsafe fn fast_two_sum<T: Elementary, V: Lanes<T>> (a: V, b: V) -> (V, V) {
+    let s = a.add(b);
+    return (s, b.sub(s.sub(a)))
+}
+
+/// `a * b` as an unevaluated sum of two floats
+#[inline(always)]
+unsafe fn two_prod<T: Elementary, V: Lanes<T>> (a: V, b: V) -> (V, V) {
+    let p = a.mul(b);
+    if V::FUSED {
+        return (p, a.mul_add(b, neg(p)))
+    }
+
+    // Dekker's product, through halves of the operands whose products are exact
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let err = ah.mul(bh).sub(p).add(ah.mul(bl)).add(al.mul(bh)).add(al.mul(bl));
+    return (p, err)
+}
+
+#[inline(always)]
+unsafe fn split<T: Elementary, V: Lanes<T>> (a: V) -> (V, V) {
+    let c = V::splat(T::SPLITTER).mul(a);
+    let hi = c.sub(c.sub(a));
+    return (hi, a.sub(hi))
+}
+
+#[inline(always)]
+unsafe fn neg<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    x.xor(V::splat(T::NEG_ZERO))
+}
+
+#[inline(always)]
+unsafe fn abs<T: Elementary, V: Lanes<T>> (x: V) -> V {
+    x.and(V::splat(T::ABS_MASK))
+}
+
+/// Lanes with their sign bit set
+#[inline(always)]
+unsafe fn is_sign_negative<T: Elementary, V: Lanes<T>> (x: V) -> V::Mask {
+    x.and(V::splat(T::NEG_ZERO)).or(V::splat(T::ONE)).lt(V::splat(T::ZERO))
+}
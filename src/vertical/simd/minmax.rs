@@ -0,0 +1,76 @@
+use super::*;
+
+/// Lane-wise minimum and maximum.
+///
+/// Floating point lanes follow the semantics of [`f32::min`] and [`f32::max`] (if only one lane is NaN, the other one is returned),
+/// except for [`clamp`](MinMax::clamp), which leaves NaN lanes untouched.
+pub(crate) trait MinMax<T: Copy>: Register<T> {
+    unsafe fn min (self, rhs: Self) -> Self;
+    unsafe fn max (self, rhs: Self) -> Self;
+
+    #[inline(always)]
+    unsafe fn clamp (self, lo: Self, hi: Self) -> Self {
+        self.min(hi).max(lo)
+    }
+}
+
+macro_rules! impl_minmax {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalMin for [$t] {}
+            #[cfg(not($meta))]
+            impl VerticalMax for [$t] {}
+            #[cfg(not($meta))]
+            impl VerticalClamp for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalMin for [$t] {
+                #[inline]
+                unsafe fn min_assign_unchecked (&mut self, rhs: &Self) {
+                    vectorized!($t => zip_assign(self, rhs, |x, y| MinMax::<$t>::min(x, y)))
+                }
+
+                #[inline]
+                fn min_assign_scalar (&mut self, rhs: $t) {
+                    unsafe {
+                        vectorized!($t => {
+                            let rhs = Vector::<$t>::splat(rhs);
+                            map_assign(self, |x| MinMax::<$t>::min(x, rhs))
+                        })
+                    }
+                }
+            }
+
+            #[docfg($meta)]
+            impl SimdVerticalMax for [$t] {
+                #[inline]
+                unsafe fn max_assign_unchecked (&mut self, rhs: &Self) {
+                    vectorized!($t => zip_assign(self, rhs, |x, y| MinMax::<$t>::max(x, y)))
+                }
+
+                #[inline]
+                fn max_assign_scalar (&mut self, rhs: $t) {
+                    unsafe {
+                        vectorized!($t => {
+                            let rhs = Vector::<$t>::splat(rhs);
+                            map_assign(self, |x| MinMax::<$t>::max(x, rhs))
+                        })
+                    }
+                }
+            }
+
+            #[docfg($meta)]
+            impl SimdVerticalClamp for [$t] {
+                #[inline]
+                unsafe fn clamp_assign_unchecked (&mut self, lo: $t, hi: $t) {
+                    vectorized!($t => {
+                        let lo = Vector::<$t>::splat(lo);
+                        let hi = Vector::<$t>::splat(hi);
+                        map_assign(self, |x| MinMax::<$t>::clamp(x, lo, hi))
+                    })
+                }
+            }
+        )+
+    };
+}
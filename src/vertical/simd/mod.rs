@@ -0,0 +1,442 @@
+//! Architecture-independent half of the SIMD backends.
+//!
+//! Every backend implements [`Register`] (and the operation traits of the submodules) for it's vector types, and
+//! picks the widest one for every element type through [`Vectorize`]. The macros defined here then implement the
+//! public traits on top of them, so that the loops are only written once.
+
+// Targets without a backend only keep the scalar defaults, and none of the machinery
+#![cfg_attr(not(any(
+    feature = "portable",
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+)), allow(dead_code, unused_macros, unused_imports))]
+
+use core::{mem::MaybeUninit, ptr::addr_of};
+use crate::sealed::Selector;
+
+macro_rules! impl_default {
+    ($trait:ident => $($t:ty),+) => {
+        $(
+            impl $trait for [$t] {}
+        )+
+    };
+}
+
+/// Runs `$body` with the loops (and the `Vector` alias) of the widest registers for `$t` allowed by
+/// the runtime cap on their width (see [`width`](crate::width)).
+macro_rules! vectorized {
+    ($t:ty => $body:expr) => {
+        match $crate::width::tier::<$t>() {
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx512f"))]
+            $crate::width::Tier::W256 => {
+                #[allow(unused_imports)]
+                use $crate::vertical::simd::w256::*;
+                $body
+            },
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx"))]
+            $crate::width::Tier::W128 => {
+                #[allow(unused_imports)]
+                use $crate::vertical::simd::w128::*;
+                $body
+            },
+            $crate::width::Tier::Full => $body
+        }
+    };
+}
+
+#[macro_use]
+mod arith;
+pub(crate) use arith::*;
+#[macro_use]
+mod minmax;
+pub(crate) use minmax::*;
+#[macro_use]
+mod fma;
+pub(crate) use fma::*;
+#[macro_use]
+mod unary;
+pub(crate) use unary::*;
+#[macro_use]
+mod round;
+pub(crate) use round::*;
+#[macro_use]
+mod cmp;
+pub(crate) use cmp::*;
+#[macro_use]
+mod select;
+pub(crate) use select::*;
+#[macro_use]
+mod bitwise;
+pub(crate) use bitwise::*;
+#[macro_use]
+mod shift;
+pub(crate) use shift::*;
+// Only the backends have registers to widen half-precision elements into
+#[cfg(all(feature = "half", any(
+    feature = "portable",
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+)))]
+#[macro_use]
+mod widen;
+#[cfg(all(feature = "half", any(
+    feature = "portable",
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+)))]
+pub(crate) use widen::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+#[macro_use]
+pub(crate) mod math;
+
+/// A SIMD register, viewed as a set of lanes of type `T`.
+pub trait Register<T: Copy>: Copy {
+    const LANES: usize = core::mem::size_of::<Self>() / core::mem::size_of::<T>();
+
+    unsafe fn load (ptr: *const T) -> Self;
+    unsafe fn store (self, ptr: *mut T);
+    unsafe fn splat (value: T) -> Self;
+
+    /// Loads the first `len` lanes from `ptr`, zeroing the remaining ones.
+    #[inline(always)]
+    unsafe fn load_partial (ptr: *const T, len: usize) -> Self {
+        let mut buf = MaybeUninit::<Self>::zeroed();
+        core::ptr::copy_nonoverlapping(ptr, buf.as_mut_ptr().cast::<T>(), len);
+        return buf.assume_init()
+    }
+
+    /// Stores the first `len` lanes into `ptr`.
+    #[inline(always)]
+    unsafe fn store_partial (self, ptr: *mut T, len: usize) {
+        core::ptr::copy_nonoverlapping(addr_of!(self).cast::<T>(), ptr, len)
+    }
+}
+
+/// Element types with a vector register available on the current target.
+pub trait Vectorize: Copy {
+    /// Widest register enabled for this element type.
+    type Vector: Register<Self>;
+    /// Instruction set of that register, as reported by [`kernels`](crate::kernels).
+    const ISA: &'static str;
+
+    /// Widest register of at most 256 bits, for when the width is capped at runtime (see [`width`](crate::width)).
+    type Vector256: Register<Self>;
+    /// Instruction set of [`Vector256`](Vectorize::Vector256).
+    const ISA256: &'static str = Self::ISA;
+    /// Widest register of at most 128 bits.
+    type Vector128: Register<Self>;
+    /// Instruction set of [`Vector128`](Vectorize::Vector128).
+    const ISA128: &'static str = Self::ISA;
+}
+
+pub type Vector<T> = <T as Vectorize>::Vector;
+
+/// Defines the loops every kernel is built on, over the registers named by `Vector` in the invoking module.
+///
+/// They are defined once for every register width kernels can be capped to at runtime (see [`vectorized`]).
+macro_rules! drivers {
+    () => {
+        /// Applies `f` to `lhs` in place, one vector at a time.
+        ///
+        /// The trailing elements are padded with zeros into a full vector, so `f` always sees whole registers.
+        #[inline(always)]
+        pub(crate) unsafe fn map_assign<T: Vectorize> (lhs: &mut [T], f: impl Fn(Vector<T>) -> Vector<T>) {
+            let ptr = lhs.as_mut_ptr();
+            map_raw(ptr, ptr, lhs.len(), f)
+        }
+
+        /// Applies `f` to `src`, storing the result in `dst`.
+        ///
+        /// # Safety
+        /// `dst` must be at least as long as `src`
+        #[inline(always)]
+        pub(crate) unsafe fn map_into<T: Vectorize> (src: &[T], dst: &mut [MaybeUninit<T>], f: impl Fn(Vector<T>) -> Vector<T>) {
+            map_raw(src.as_ptr(), dst.as_mut_ptr().cast(), src.len(), f)
+        }
+
+        #[inline(always)]
+        unsafe fn map_raw<T: Vectorize> (src: *const T, dst: *mut T, len: usize, f: impl Fn(Vector<T>) -> Vector<T>) {
+            let lanes = <Vector<T> as Register<T>>::LANES;
+
+            let mut i = 0;
+            while i + lanes <= len {
+                f(Vector::<T>::load(src.add(i))).store(dst.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                f(Vector::<T>::load_partial(src.add(i), len - i)).store_partial(dst.add(i), len - i);
+            }
+        }
+
+        /// Applies `f` to `lhs` and `rhs`, storing the result in `lhs`.
+        ///
+        /// # Safety
+        /// `rhs` must be at least as long as `lhs`
+        #[inline(always)]
+        pub(crate) unsafe fn zip_assign<T: Vectorize> (lhs: &mut [T], rhs: &[T], f: impl Fn(Vector<T>, Vector<T>) -> Vector<T>) {
+            let lanes = <Vector<T> as Register<T>>::LANES;
+            let len = lhs.len();
+            let ptr = lhs.as_mut_ptr();
+            let rhs = rhs.as_ptr();
+
+            let mut i = 0;
+            while i + lanes <= len {
+                f(Vector::<T>::load(ptr.add(i)), Vector::<T>::load(rhs.add(i))).store(ptr.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                let rem = len - i;
+                f(Vector::<T>::load_partial(ptr.add(i), rem), Vector::<T>::load_partial(rhs.add(i), rem))
+                    .store_partial(ptr.add(i), rem);
+            }
+        }
+
+        /// Applies `f` to `lhs`, `a` and `b`, storing the result in `lhs`.
+        ///
+        /// # Safety
+        /// `a` and `b` must be at least as long as `lhs`
+        #[inline(always)]
+        pub(crate) unsafe fn zip3_assign<T: Vectorize> (lhs: &mut [T], a: &[T], b: &[T], f: impl Fn(Vector<T>, Vector<T>, Vector<T>) -> Vector<T>) {
+            let lanes = <Vector<T> as Register<T>>::LANES;
+            let len = lhs.len();
+            let ptr = lhs.as_mut_ptr();
+            let a = a.as_ptr();
+            let b = b.as_ptr();
+
+            let mut i = 0;
+            while i + lanes <= len {
+                f(Vector::<T>::load(ptr.add(i)), Vector::<T>::load(a.add(i)), Vector::<T>::load(b.add(i))).store(ptr.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                let rem = len - i;
+                f(
+                    Vector::<T>::load_partial(ptr.add(i), rem),
+                    Vector::<T>::load_partial(a.add(i), rem),
+                    Vector::<T>::load_partial(b.add(i), rem)
+                ).store_partial(ptr.add(i), rem);
+            }
+        }
+
+        /// Applies `f` to `lhs` and `rhs`, repeating `rhs` as many times as needed, and storing the result in `lhs`.
+        ///
+        /// Patterns shorter than a vector are expanded once into the handful of registers that make up a full cycle.
+        ///
+        /// # Safety
+        /// `rhs` mustn't be empty, and it's length must evenly divide the length of `lhs`
+        #[inline(always)]
+        pub(crate) unsafe fn zip_cyclic_assign<T: Vectorize> (lhs: &mut [T], rhs: &[T], f: impl Fn(Vector<T>, Vector<T>) -> Vector<T>) {
+            let lanes = <Vector<T> as Register<T>>::LANES;
+            let len = lhs.len();
+            let period = rhs.len();
+            let ptr = lhs.as_mut_ptr();
+
+            // No register has more than 64 lanes
+            let mut buf = MaybeUninit::<[T; 64]>::uninit();
+            let buf = buf.as_mut_ptr().cast::<T>();
+
+            if period < lanes {
+                let count = period / gcd(period, lanes);
+                let mut pattern = [MaybeUninit::<Vector<T>>::uninit(); 64];
+                for (r, reg) in pattern.iter_mut().take(count).enumerate() {
+                    for j in 0..lanes {
+                        buf.add(j).write(*rhs.get_unchecked((r * lanes + j) % period));
+                    }
+                    reg.write(Vector::<T>::load(buf));
+                }
+
+                let mut i = 0;
+                let mut r = 0;
+                while i + lanes <= len {
+                    f(Vector::<T>::load(ptr.add(i)), pattern.get_unchecked(r).assume_init()).store(ptr.add(i));
+                    i += lanes;
+                    r += 1;
+                    if r == count { r = 0 }
+                }
+
+                if i < len {
+                    let rem = len - i;
+                    f(Vector::<T>::load_partial(ptr.add(i), rem), pattern.get_unchecked(r).assume_init())
+                        .store_partial(ptr.add(i), rem);
+                }
+
+                return
+            }
+
+            let rhs = rhs.as_ptr();
+            let mut i = 0;
+            let mut offset = 0;
+            while i + lanes <= len {
+                let y = if offset + lanes <= period {
+                    Vector::<T>::load(rhs.add(offset))
+                } else {
+                    // The vector wraps around the end of the pattern
+                    let head = period - offset;
+                    core::ptr::copy_nonoverlapping(rhs.add(offset), buf, head);
+                    core::ptr::copy_nonoverlapping(rhs, buf.add(head), lanes - head);
+                    Vector::<T>::load(buf)
+                };
+
+                f(Vector::<T>::load(ptr.add(i)), y).store(ptr.add(i));
+                i += lanes;
+                offset += lanes;
+                if offset >= period { offset -= period }
+            }
+
+            // Since the pattern divides `lhs`, the tail always ends with the pattern
+            if i < len {
+                let rem = len - i;
+                f(Vector::<T>::load_partial(ptr.add(i), rem), Vector::<T>::load_partial(rhs.add(offset), rem))
+                    .store_partial(ptr.add(i), rem);
+            }
+        }
+
+        /// Folds every slice in `sources`, in order, into `lhs` with `f`.
+        ///
+        /// Every vector of `lhs` is loaded and stored once, with all the sources streamed through it.
+        ///
+        /// # Safety
+        /// Every slice in `sources` must be at least as long as `lhs`
+        #[inline(always)]
+        pub(crate) unsafe fn fold_many_assign<T: Vectorize> (lhs: &mut [T], sources: &[&[T]], f: impl Fn(Vector<T>, Vector<T>) -> Vector<T>) {
+            let ptr = lhs.as_mut_ptr();
+            fold_many_raw(ptr, sources, ptr, lhs.len(), f, |x| x)
+        }
+
+        /// Folds every slice in `sources`, in order, into the vectors of `init` with `f`, storing `finish` of the result into `dst`.
+        ///
+        /// # Safety
+        /// `init` and every slice in `sources` must have at least `len` elements, and `dst` must be valid for `len` writes
+        #[inline(always)]
+        pub(crate) unsafe fn fold_many_raw<T: Vectorize> (
+            init: *const T,
+            sources: &[&[T]],
+            dst: *mut T,
+            len: usize,
+            f: impl Fn(Vector<T>, Vector<T>) -> Vector<T>,
+            finish: impl Fn(Vector<T>) -> Vector<T>
+        ) {
+            let lanes = <Vector<T> as Register<T>>::LANES;
+
+            let mut i = 0;
+            while i + lanes <= len {
+                let mut acc = Vector::<T>::load(init.add(i));
+                for source in sources {
+                    acc = f(acc, Vector::<T>::load(source.as_ptr().add(i)));
+                }
+                finish(acc).store(dst.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                let rem = len - i;
+                let mut acc = Vector::<T>::load_partial(init.add(i), rem);
+                for source in sources {
+                    acc = f(acc, Vector::<T>::load_partial(source.as_ptr().add(i), rem));
+                }
+                finish(acc).store_partial(dst.add(i), rem);
+            }
+        }
+
+        /// Loads `n` lanes from `ptr`, zeroing the remaining ones if it's less than a full register.
+        #[inline(always)]
+        pub(crate) unsafe fn load_lanes<T: Vectorize> (ptr: *const T, n: usize) -> Vector<T> {
+            if n == <Vector<T> as Register<T>>::LANES {
+                return Vector::<T>::load(ptr)
+            }
+            return Vector::<T>::load_partial(ptr, n)
+        }
+
+        /// Packs the bitmasks returned by `f` for every chunk of `len` elements into `out`.
+        ///
+        /// `f` receives the offset of the chunk and the number of elements in it, which is only less than a full register for the last one.
+        /// The bits past the last element are cleared.
+        ///
+        /// # Safety
+        /// `out` must hold at least [`words_for(len)`](crate::mask::words_for) words.
+        #[inline(always)]
+        pub(crate) unsafe fn pack_raw<T: Vectorize> (len: usize, out: &mut [u64], f: impl Fn(usize, usize) -> u64) {
+            let lanes = <Vector<T> as Register<T>>::LANES;
+            let mut out = out.as_mut_ptr();
+
+            let mut i = 0;
+            while i < len {
+                // Registers never have more than 64 lanes, so they never straddle two words
+                let mut word = 0;
+                let mut shift = 0;
+                while shift < 64 && i < len {
+                    let n = lanes.min(len - i);
+                    word |= (f(i, n) & (u64::MAX >> (64 - n))) << shift;
+                    i += lanes;
+                    shift += lanes;
+                }
+
+                out.write(word);
+                out = out.add(1);
+            }
+        }
+
+        /// Stores `lhs[i]` blended with `rhs(i, n)` into `dst[i]`, for every chunk of `len` elements.
+        ///
+        /// `rhs` receives the offset of the chunk and the number of elements in it, which is only less than a full register for the last one.
+        ///
+        /// # Safety
+        /// `dst` and `lhs` must be valid for `len` elements (they may alias), and `mask` must cover them.
+        #[inline(always)]
+        pub(crate) unsafe fn blend_raw<T: Vectorize, M: ?Sized + Selector> (len: usize, mask: &M, dst: *mut T, lhs: *const T, rhs: impl Fn(usize, usize) -> Vector<T>) where Vector<T>: Blend<T> {
+            let lanes = <Vector<T> as Register<T>>::LANES;
+
+            let mut i = 0;
+            while i + lanes <= len {
+                // Registers never have more than 64 lanes, so their bits never straddle two words
+                let bits = mask.bits(i, lanes);
+                Vector::<T>::load(lhs.add(i)).blend(rhs(i, lanes), bits).store(dst.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                let rem = len - i;
+                let bits = mask.bits(i, rem);
+                Vector::<T>::load_partial(lhs.add(i), rem).blend(rhs(i, rem), bits).store_partial(dst.add(i), rem);
+            }
+        }
+    };
+}
+
+drivers!();
+
+/// The loops over registers of at most 256 bits.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx512f"))]
+pub(crate) mod w256 {
+    use super::*;
+
+    pub(crate) type Vector<T> = <T as Vectorize>::Vector256;
+    drivers!();
+    #[cfg(feature = "half")]
+    widen_drivers!();
+}
+
+/// The loops over registers of at most 128 bits.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx"))]
+pub(crate) mod w128 {
+    use super::*;
+
+    pub(crate) type Vector<T> = <T as Vectorize>::Vector128;
+    drivers!();
+    #[cfg(feature = "half")]
+    widen_drivers!();
+}
+
+#[inline(always)]
+fn gcd (mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    return a
+}
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Lane-wise rounding to integral values.
+pub(crate) trait Round<T: Copy>: Register<T> {
+    unsafe fn floor (self) -> Self;
+    unsafe fn ceil (self) -> Self;
+    unsafe fn trunc (self) -> Self;
+    /// Rounds half-way cases away from zero
+    unsafe fn round (self) -> Self;
+    /// Rounds half-way cases to the nearest even integer
+    unsafe fn round_ties_even (self) -> Self;
+}
+
+macro_rules! impl_round {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalRound for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalRound for [$t] {
+                impl_round! { @op $t: floor_assign & floor_into_unchecked => floor }
+                impl_round! { @op $t: ceil_assign & ceil_into_unchecked => ceil }
+                impl_round! { @op $t: trunc_assign & trunc_into_unchecked => trunc }
+                impl_round! { @op $t: round_assign & round_into_unchecked => round }
+                impl_round! { @op $t: round_ties_even_assign & round_ties_even_into_unchecked => round_ties_even }
+            }
+        )+
+    };
+
+    (@op $t:ident: $assign:ident & $into:ident => $f:ident) => {
+        #[inline]
+        fn $assign (&mut self) {
+            unsafe { vectorized!($t => map_assign(self, |x| Round::<$t>::$f(x))) }
+        }
+
+        #[inline]
+        unsafe fn $into (&self, out: &mut [MaybeUninit<$t>]) {
+            vectorized!($t => map_into(self, out, |x| Round::<$t>::$f(x)))
+        }
+    };
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+/// Lane-wise selection driven by a bitmask, with the bit for lane `i` on bit `i`.
+pub(crate) trait Blend<T: Copy>: Register<T> {
+    /// Selects `rhs` on the lanes whose bit is set, and `self` otherwise.
+    unsafe fn blend (self, rhs: Self, bits: u64) -> Self;
+}
+
+macro_rules! impl_select {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalSelect for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalSelect for [$t] {
+                #[inline]
+                unsafe fn blend_assign_unchecked<M: ?Sized + Selector> (&mut self, mask: &M, rhs: &Self) {
+                    let ptr = self.as_mut_ptr();
+                    let rhs = rhs.as_ptr();
+                    vectorized!($t => blend_raw::<$t, M>(self.len(), mask, ptr, ptr, |i, n| load_lanes(rhs.add(i), n)))
+                }
+
+                #[inline]
+                unsafe fn blend_assign_scalar_unchecked<M: ?Sized + Selector> (&mut self, mask: &M, rhs: $t) {
+                    let ptr = self.as_mut_ptr();
+                    vectorized!($t => {
+                        let rhs = Vector::<$t>::splat(rhs);
+                        blend_raw::<$t, M>(self.len(), mask, ptr, ptr, |_, _| rhs)
+                    })
+                }
+
+                #[inline]
+                unsafe fn select_into_unchecked<M: ?Sized + Selector> (&self, mask: &M, if_false: &Self, out: &mut [MaybeUninit<$t>]) {
+                    let if_true = self.as_ptr();
+                    vectorized!($t => blend_raw::<$t, M>(self.len(), mask, out.as_mut_ptr().cast(), if_false.as_ptr(), |i, n| load_lanes(if_true.add(i), n)))
+                }
+            }
+        )+
+    };
+}
@@ -0,0 +1,79 @@
+use crate::sealed::Integer;
+use super::*;
+
+/// Lane-wise bit shifts. Counts must be less than the width of the lanes.
+pub(crate) trait Shift<T: Integer>: Bitwise<T> {
+    const BITS: u32 = 8 * core::mem::size_of::<T>() as u32;
+
+    unsafe fn shl (self, n: u32) -> Self;
+    /// Arithmetic on signed lanes, logical on unsigned ones.
+    unsafe fn shr (self, n: u32) -> Self;
+    unsafe fn shr_logical (self, n: u32) -> Self;
+
+    unsafe fn shlv (self, counts: Self) -> Self;
+    /// Arithmetic on signed lanes, logical on unsigned ones.
+    unsafe fn shrv (self, counts: Self) -> Self;
+    unsafe fn shrv_logical (self, counts: Self) -> Self;
+
+    // `BITS - n` is out of range when `n` is zero, so we shift by one and then by `(BITS - 1) - n`
+    #[inline(always)]
+    unsafe fn rotl (self, n: u32) -> Self {
+        self.shl(n).or(self.shr_logical(1).shr_logical(n ^ (Self::BITS - 1)))
+    }
+
+    #[inline(always)]
+    unsafe fn rotr (self, n: u32) -> Self {
+        self.shr_logical(n).or(self.shl(1).shl(n ^ (Self::BITS - 1)))
+    }
+
+    #[inline(always)]
+    unsafe fn rotlv (self, counts: Self) -> Self {
+        let inv = counts.xor(Self::splat(num_traits::NumCast::from(Self::BITS - 1).unwrap_unchecked()));
+        self.shlv(counts).or(self.shr_logical(1).shrv_logical(inv))
+    }
+
+    #[inline(always)]
+    unsafe fn rotrv (self, counts: Self) -> Self {
+        let inv = counts.xor(Self::splat(num_traits::NumCast::from(Self::BITS - 1).unwrap_unchecked()));
+        self.shrv_logical(counts).or(self.shl(1).shlv(inv))
+    }
+}
+
+macro_rules! impl_shift {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            #[cfg(not($meta))]
+            impl VerticalShift for [$t] {}
+            #[cfg(not($meta))]
+            impl VerticalRotate for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalShift for [$t] {
+                impl_shift! { @op $t: shl_assign_unchecked & shl_assign_scalar => shlv & shl }
+                impl_shift! { @op $t: shr_assign_unchecked & shr_assign_scalar => shrv & shr }
+            }
+
+            #[docfg($meta)]
+            impl SimdVerticalRotate for [$t] {
+                impl_shift! { @op $t: rotate_left_assign_unchecked & rotate_left_assign_scalar => rotlv & rotl }
+                impl_shift! { @op $t: rotate_right_assign_unchecked & rotate_right_assign_scalar => rotrv & rotr }
+            }
+        )+
+    };
+
+    (@op $t:ident: $unchecked:ident & $scalar:ident => $fv:ident & $f:ident) => {
+        #[inline]
+        unsafe fn $unchecked (&mut self, counts: &Self) {
+            vectorized!($t => {
+                let mask = Vector::<$t>::splat((<$t>::BITS - 1) as $t);
+                zip_assign(self, counts, |x, n| Shift::<$t>::$fv(x, Bitwise::<$t>::and(n, mask)))
+            })
+        }
+
+        #[inline]
+        fn $scalar (&mut self, n: u32) {
+            let n = n % <$t>::BITS;
+            unsafe { vectorized!($t => map_assign(self, |x| Shift::<$t>::$f(x, n))) }
+        }
+    };
+}
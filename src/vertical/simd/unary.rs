@@ -0,0 +1,55 @@
+use super::*;
+
+/// Lane-wise absolute value and negation, wrapping around for integers.
+pub(crate) trait Sign<T: Copy>: Register<T> {
+    unsafe fn abs (self) -> Self;
+    unsafe fn neg (self) -> Self;
+}
+
+/// Lane-wise floating point operations.
+pub(crate) trait Unary<T: Copy>: Register<T> {
+    unsafe fn sqrt (self) -> Self;
+    unsafe fn square (self) -> Self;
+    unsafe fn rsqrt (self, refine: bool) -> Self;
+    unsafe fn recip (self, refine: bool) -> Self;
+}
+
+macro_rules! impl_unary_op {
+    (
+        #[cfg($meta:meta)]
+        $trait:ident as $op:ident $params:tt => $register:ident::$f:ident for $($t:ident),+
+    ) => {
+        $(
+            impl_unary_op! {
+                @impl #[cfg($meta)] $trait as $op $params => $register::$f for $t
+            }
+        )+
+    };
+
+    (
+        @impl #[cfg($meta:meta)]
+        $trait:ident as $op:ident ($($param:ident: $param_ty:ty)?) => $register:ident::$f:ident for $t:ident
+    ) => {
+        #[cfg(not($meta))]
+        impl $trait for [$t] {}
+
+        concat_idents!(r#trait = Simd, $trait {
+            #[docfg($meta)]
+            impl r#trait for [$t] {
+                concat_idents!(f = $op, _assign {
+                    #[inline]
+                    fn f (&mut self $(, $param: $param_ty)?) {
+                        unsafe { vectorized!($t => map_assign(self, |x| $register::<$t>::$f(x $(, $param)?))) }
+                    }
+                });
+
+                concat_idents!(f = $op, _into_unchecked {
+                    #[inline]
+                    unsafe fn f (&self, out: &mut [MaybeUninit<$t>] $(, $param: $param_ty)?) {
+                        vectorized!($t => map_into(self, out, |x| $register::<$t>::$f(x $(, $param)?)))
+                    }
+                });
+            }
+        });
+    };
+}
@@ -0,0 +1,498 @@
+use core::mem::MaybeUninit;
+use super::*;
+
+/// Half-precision floats, computed on as `f32` lanes and rounded back to nearest.
+pub(crate) trait Widen<T: Copy>: Register<f32> {
+    /// Loads `LANES` elements, converted to `f32`.
+    unsafe fn load_wide (ptr: *const T) -> Self;
+    /// Stores the lanes, rounded to `T`.
+    unsafe fn store_narrow (self, ptr: *mut T);
+
+    /// Loads the first `len` elements from `ptr`, zeroing the remaining lanes.
+    #[inline(always)]
+    unsafe fn load_wide_partial (ptr: *const T, len: usize) -> Self {
+        // No register holds more than 16 `f32` lanes
+        let mut buf = MaybeUninit::<[T; 16]>::zeroed();
+        core::ptr::copy_nonoverlapping(ptr, buf.as_mut_ptr().cast::<T>(), len);
+        return Self::load_wide(buf.as_ptr().cast())
+    }
+
+    /// Stores the first `len` lanes into `ptr`.
+    #[inline(always)]
+    unsafe fn store_narrow_partial (self, ptr: *mut T, len: usize) {
+        let mut buf = MaybeUninit::<[T; 16]>::uninit();
+        self.store_narrow(buf.as_mut_ptr().cast());
+        core::ptr::copy_nonoverlapping(buf.as_ptr().cast::<T>(), ptr, len)
+    }
+}
+
+macro_rules! impl_widen {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            impl_widen! { @binary #[cfg($meta)] $t: VerticalAdd as add_assign_unchecked => Arith::add }
+            impl_widen! { @binary #[cfg($meta)] $t: VerticalSub as sub_assign_unchecked => Arith::sub }
+            impl_widen! { @binary #[cfg($meta)] $t: VerticalMul as mul_assign_unchecked => FloatArith::mul }
+            impl_widen! { @binary #[cfg($meta)] $t: VerticalDiv as div_assign_unchecked => FloatArith::div }
+
+            #[cfg(not($meta))]
+            impl VerticalMin for [$t] {}
+            #[cfg(not($meta))]
+            impl VerticalMax for [$t] {}
+            #[cfg(not($meta))]
+            impl VerticalClamp for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalMin for [$t] {
+                #[inline]
+                unsafe fn min_assign_unchecked (&mut self, rhs: &Self) {
+                    vectorized!(f32 => widen_zip_assign(self, rhs, |x, y| MinMax::<f32>::min(x, y)))
+                }
+
+                #[inline]
+                fn min_assign_scalar (&mut self, rhs: $t) {
+                    unsafe {
+                        vectorized!(f32 => {
+                            let rhs = Vector::<f32>::splat(rhs.to_f32());
+                            widen_map_assign(self, |x| MinMax::<f32>::min(x, rhs))
+                        })
+                    }
+                }
+            }
+
+            #[docfg($meta)]
+            impl SimdVerticalMax for [$t] {
+                #[inline]
+                unsafe fn max_assign_unchecked (&mut self, rhs: &Self) {
+                    vectorized!(f32 => widen_zip_assign(self, rhs, |x, y| MinMax::<f32>::max(x, y)))
+                }
+
+                #[inline]
+                fn max_assign_scalar (&mut self, rhs: $t) {
+                    unsafe {
+                        vectorized!(f32 => {
+                            let rhs = Vector::<f32>::splat(rhs.to_f32());
+                            widen_map_assign(self, |x| MinMax::<f32>::max(x, rhs))
+                        })
+                    }
+                }
+            }
+
+            #[docfg($meta)]
+            impl SimdVerticalClamp for [$t] {
+                #[inline]
+                unsafe fn clamp_assign_unchecked (&mut self, lo: $t, hi: $t) {
+                    vectorized!(f32 => {
+                        let lo = Vector::<f32>::splat(lo.to_f32());
+                        let hi = Vector::<f32>::splat(hi.to_f32());
+                        widen_map_assign(self, |x| MinMax::<f32>::clamp(x, lo, hi))
+                    })
+                }
+            }
+
+            #[cfg(not($meta))]
+            impl VerticalMulAdd for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalMulAdd for [$t] {
+                #[inline]
+                unsafe fn mul_add_assign_unchecked (&mut self, a: &Self, b: &Self) {
+                    vectorized!(f32 => widen_zip3_assign(self, a, b, |x, a, b| MulAdd::<f32>::mul_add(x, a, b)))
+                }
+
+                #[inline]
+                unsafe fn axpy_unchecked (&mut self, alpha: $t, x: &Self) {
+                    vectorized!(f32 => {
+                        let alpha = Vector::<f32>::splat(alpha.to_f32());
+                        widen_zip_assign(self, x, |y, x| MulAdd::<f32>::mul_add(x, alpha, y))
+                    })
+                }
+
+                #[inline]
+                fn affine_assign (&mut self, scale: $t, offset: $t) {
+                    unsafe {
+                        vectorized!(f32 => {
+                            let scale = Vector::<f32>::splat(scale.to_f32());
+                            let offset = Vector::<f32>::splat(offset.to_f32());
+                            widen_map_assign(self, |x| MulAdd::<f32>::mul_add(x, scale, offset))
+                        })
+                    }
+                }
+            }
+
+            #[cfg(not($meta))]
+            impl VerticalAccumulate for [$t] {}
+            #[cfg(not($meta))]
+            impl VerticalMean for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalAccumulate for [$t] {
+                #[inline]
+                unsafe fn add_assign_many_unchecked (&mut self, sources: &[&Self]) {
+                    let ptr = self.as_mut_ptr();
+                    vectorized!(f32 => widen_fold_many_raw(ptr, sources, ptr, self.len(), |x| x))
+                }
+            }
+
+            #[docfg($meta)]
+            impl SimdVerticalMean for [$t] {
+                #[inline]
+                unsafe fn mean_of_unchecked (&mut self, sources: &[&Self]) {
+                    let (first, rest) = sources.split_first().unwrap_unchecked();
+                    vectorized!(f32 => {
+                        let count = Vector::<f32>::splat(sources.len() as f32);
+                        widen_fold_many_raw(first.as_ptr(), rest, self.as_mut_ptr(), self.len(), |x| FloatArith::<f32>::div(x, count))
+                    })
+                }
+            }
+
+            #[cfg(any(feature = "std", feature = "libm"))]
+            impl_widen! { @unary #[cfg($meta)] $t: VerticalSqrt as sqrt() => Unary::sqrt }
+            #[cfg(any(feature = "std", feature = "libm"))]
+            impl_widen! { @unary #[cfg($meta)] $t: VerticalRsqrt as rsqrt(refine: bool) => Unary::rsqrt }
+            impl_widen! { @unary #[cfg($meta)] $t: VerticalRecip as recip(refine: bool) => Unary::recip }
+            impl_widen! { @unary #[cfg($meta)] $t: VerticalSquare as square() => Unary::square }
+
+            #[cfg(not($meta))]
+            impl VerticalRound for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalRound for [$t] {
+                impl_widen! { @round floor_assign & floor_into_unchecked => floor }
+                impl_widen! { @round ceil_assign & ceil_into_unchecked => ceil }
+                impl_widen! { @round trunc_assign & trunc_into_unchecked => trunc }
+                impl_widen! { @round round_assign & round_into_unchecked => round }
+                impl_widen! { @round round_ties_even_assign & round_ties_even_into_unchecked => round_ties_even }
+            }
+
+            #[cfg(not($meta))]
+            impl VerticalConvert<f32> for [$t] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalConvert<f32> for [$t] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<f32>]) {
+                    vectorized!(f32 => widen_into(self, out))
+                }
+            }
+
+            #[cfg(not($meta))]
+            impl VerticalConvert<$t> for [f32] {}
+
+            #[docfg($meta)]
+            impl SimdVerticalConvert<$t> for [f32] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$t>]) {
+                    vectorized!(f32 => narrow_into(self, out))
+                }
+            }
+
+            impl SimdVerticalConvert<$t> for [$t] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$t>]) {
+                    core::ptr::copy_nonoverlapping(self.as_ptr(), out.as_mut_ptr().cast(), self.len())
+                }
+            }
+
+            impl_widen! {
+                @convert $t => i8, u8, i16, u16, i32, u32, i64, u64, f64
+            }
+        )+
+    };
+
+    (@binary #[cfg($meta:meta)] $t:ident: $trait:ident as $unchecked:ident => $register:ident::$f:ident) => {
+        #[cfg(not($meta))]
+        impl $trait for [$t] {}
+
+        concat_idents!(r#trait = Simd, $trait {
+            #[docfg($meta)]
+            impl r#trait for [$t] {
+                #[inline]
+                unsafe fn $unchecked (&mut self, rhs: &Self) {
+                    vectorized!(f32 => widen_zip_assign(self, rhs, |x, y| $register::<f32>::$f(x, y)))
+                }
+            }
+        });
+    };
+
+    (
+        @unary #[cfg($meta:meta)]
+        $t:ident: $trait:ident as $op:ident ($($param:ident: $param_ty:ty)?) => $register:ident::$f:ident
+    ) => {
+        #[cfg(not($meta))]
+        impl $trait for [$t] {}
+
+        concat_idents!(r#trait = Simd, $trait {
+            #[docfg($meta)]
+            impl r#trait for [$t] {
+                concat_idents!(f = $op, _assign {
+                    #[inline]
+                    fn f (&mut self $(, $param: $param_ty)?) {
+                        unsafe { vectorized!(f32 => widen_map_assign(self, |x| $register::<f32>::$f(x $(, $param)?))) }
+                    }
+                });
+
+                concat_idents!(f = $op, _into_unchecked {
+                    #[inline]
+                    unsafe fn f (&self, out: &mut [MaybeUninit<Self::Element>] $(, $param: $param_ty)?) {
+                        vectorized!(f32 => widen_map_into(self, out, |x| $register::<f32>::$f(x $(, $param)?)))
+                    }
+                });
+            }
+        });
+    };
+
+    (@round $assign:ident & $into:ident => $f:ident) => {
+        #[inline]
+        fn $assign (&mut self) {
+            unsafe { vectorized!(f32 => widen_map_assign(self, |x| Round::<f32>::$f(x))) }
+        }
+
+        #[inline]
+        unsafe fn $into (&self, out: &mut [MaybeUninit<Self::Element>]) {
+            vectorized!(f32 => widen_map_into(self, out, |x| Round::<f32>::$f(x)))
+        }
+    };
+
+    // Conversions from and into the remaining element types go through `f32` (or `f64`) one element at a time
+    (@convert $t:ident => $($other:ident),+) => {
+        $(
+            impl VerticalConvert<$other> for [$t] {}
+            impl VerticalConvert<$t> for [$other] {}
+        )+
+    };
+}
+
+// The sign only takes a bit, so it can be handled without converting
+macro_rules! impl_sign {
+    (#[cfg($meta:meta)] $($t:ident),+) => {
+        $(
+            impl_sign! { @op #[cfg($meta)] $t: VerticalAbs as abs_assign & abs_into_unchecked => and 0x7fff }
+            impl_sign! { @op #[cfg($meta)] $t: VerticalNeg as neg_assign & neg_into_unchecked => xor 0x8000 }
+        )+
+    };
+
+    (@op #[cfg($meta:meta)] $t:ident: $trait:ident as $assign:ident & $into:ident => $f:ident $bits:literal) => {
+        #[cfg(not($meta))]
+        impl $trait for [$t] {}
+
+        concat_idents!(r#trait = Simd, $trait {
+            #[docfg($meta)]
+            impl r#trait for [$t] {
+                #[inline]
+                fn $assign (&mut self) {
+                    unsafe {
+                        vectorized!(u16 => {
+                            let bits = <Vector<u16> as Register<u16>>::splat($bits);
+                            map_assign(bytemuck::cast_slice_mut::<$t, u16>(self), |x| Bitwise::<u16>::$f(x, bits))
+                        })
+                    }
+                }
+
+                #[inline]
+                unsafe fn $into (&self, out: &mut [MaybeUninit<$t>]) {
+                    let out = core::slice::from_raw_parts_mut(out.as_mut_ptr().cast(), out.len());
+                    vectorized!(u16 => {
+                        let bits = <Vector<u16> as Register<u16>>::splat($bits);
+                        map_into(bytemuck::cast_slice::<$t, u16>(self), out, |x| Bitwise::<u16>::$f(x, bits))
+                    })
+                }
+            }
+        });
+    };
+}
+
+// Selection only moves bits around, so it's shared with `u16`
+macro_rules! impl_widen_select {
+    ($($t:ident),+) => {
+        $(
+            impl SimdVerticalSelect for [$t] {
+                #[inline]
+                unsafe fn blend_assign_unchecked<M: ?Sized + Selector> (&mut self, mask: &M, rhs: &Self) {
+                    VerticalSelect::blend_assign_unchecked(bytemuck::cast_slice_mut::<$t, u16>(self), mask, bytemuck::cast_slice(rhs))
+                }
+
+                #[inline]
+                unsafe fn blend_assign_scalar_unchecked<M: ?Sized + Selector> (&mut self, mask: &M, rhs: $t) {
+                    VerticalSelect::blend_assign_scalar_unchecked(bytemuck::cast_slice_mut::<$t, u16>(self), mask, rhs.to_bits())
+                }
+
+                #[inline]
+                unsafe fn select_into_unchecked<M: ?Sized + Selector> (&self, mask: &M, if_false: &Self, out: &mut [MaybeUninit<$t>]) {
+                    let out = core::slice::from_raw_parts_mut(out.as_mut_ptr().cast(), out.len());
+                    VerticalSelect::select_into_unchecked(bytemuck::cast_slice::<$t, u16>(self), mask, bytemuck::cast_slice(if_false), out)
+                }
+            }
+        )+
+    };
+}
+
+/// Defines the loops half-precision kernels are built on, over the `f32` registers named by `Vector` in the
+/// invoking module, the same way as [`drivers`].
+macro_rules! widen_drivers {
+    () => {
+        /// Applies `f` to `lhs` in place, on `f32` lanes.
+        #[inline(always)]
+        pub(crate) unsafe fn widen_map_assign<T: Copy> (lhs: &mut [T], f: impl Fn(Vector<f32>) -> Vector<f32>) where Vector<f32>: Widen<T> {
+            let ptr = lhs.as_mut_ptr();
+            widen_map_raw(ptr, ptr, lhs.len(), f)
+        }
+
+        /// Applies `f` to `src` on `f32` lanes, storing the result in `dst`.
+        ///
+        /// # Safety
+        /// `dst` must be at least as long as `src`
+        #[inline(always)]
+        pub(crate) unsafe fn widen_map_into<T: Copy> (src: &[T], dst: &mut [MaybeUninit<T>], f: impl Fn(Vector<f32>) -> Vector<f32>) where Vector<f32>: Widen<T> {
+            widen_map_raw(src.as_ptr(), dst.as_mut_ptr().cast(), src.len(), f)
+        }
+
+        #[inline(always)]
+        unsafe fn widen_map_raw<T: Copy> (src: *const T, dst: *mut T, len: usize, f: impl Fn(Vector<f32>) -> Vector<f32>) where Vector<f32>: Widen<T> {
+            let lanes = <Vector<f32> as Register<f32>>::LANES;
+
+            let mut i = 0;
+            while i + lanes <= len {
+                f(<Vector<f32> as Widen<T>>::load_wide(src.add(i))).store_narrow(dst.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                f(<Vector<f32> as Widen<T>>::load_wide_partial(src.add(i), len - i)).store_narrow_partial(dst.add(i), len - i);
+            }
+        }
+
+        /// Applies `f` to `lhs` and `rhs` on `f32` lanes, storing the result in `lhs`.
+        ///
+        /// # Safety
+        /// `rhs` must be at least as long as `lhs`
+        #[inline(always)]
+        pub(crate) unsafe fn widen_zip_assign<T: Copy> (lhs: &mut [T], rhs: &[T], f: impl Fn(Vector<f32>, Vector<f32>) -> Vector<f32>) where Vector<f32>: Widen<T> {
+            let lanes = <Vector<f32> as Register<f32>>::LANES;
+            let len = lhs.len();
+            let ptr = lhs.as_mut_ptr();
+            let rhs = rhs.as_ptr();
+
+            let mut i = 0;
+            while i + lanes <= len {
+                f(<Vector<f32> as Widen<T>>::load_wide(ptr.add(i)), <Vector<f32> as Widen<T>>::load_wide(rhs.add(i))).store_narrow(ptr.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                let rem = len - i;
+                f(<Vector<f32> as Widen<T>>::load_wide_partial(ptr.add(i), rem), <Vector<f32> as Widen<T>>::load_wide_partial(rhs.add(i), rem))
+                    .store_narrow_partial(ptr.add(i), rem);
+            }
+        }
+
+        /// Sums every slice in `sources`, in order, into the vectors of `init`, storing `finish` of the result into `dst`.
+        ///
+        /// The sums are kept as `f32` lanes, and only rounded once they are stored.
+        ///
+        /// # Safety
+        /// `init` and every slice in `sources` must have at least `len` elements, and `dst` must be valid for `len` writes
+        #[inline(always)]
+        pub(crate) unsafe fn widen_fold_many_raw<T: Copy> (
+            init: *const T,
+            sources: &[&[T]],
+            dst: *mut T,
+            len: usize,
+            finish: impl Fn(Vector<f32>) -> Vector<f32>
+        ) where Vector<f32>: Widen<T> {
+            let lanes = <Vector<f32> as Register<f32>>::LANES;
+
+            let mut i = 0;
+            while i + lanes <= len {
+                let mut acc = <Vector<f32> as Widen<T>>::load_wide(init.add(i));
+                for source in sources {
+                    acc = Arith::<f32>::add(acc, <Vector<f32> as Widen<T>>::load_wide(source.as_ptr().add(i)));
+                }
+                finish(acc).store_narrow(dst.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                let rem = len - i;
+                let mut acc = <Vector<f32> as Widen<T>>::load_wide_partial(init.add(i), rem);
+                for source in sources {
+                    acc = Arith::<f32>::add(acc, <Vector<f32> as Widen<T>>::load_wide_partial(source.as_ptr().add(i), rem));
+                }
+                finish(acc).store_narrow_partial(dst.add(i), rem);
+            }
+        }
+
+        /// Applies `f` to `lhs`, `a` and `b` on `f32` lanes, storing the result in `lhs`.
+        ///
+        /// # Safety
+        /// `a` and `b` must be at least as long as `lhs`
+        #[inline(always)]
+        pub(crate) unsafe fn widen_zip3_assign<T: Copy> (lhs: &mut [T], a: &[T], b: &[T], f: impl Fn(Vector<f32>, Vector<f32>, Vector<f32>) -> Vector<f32>) where Vector<f32>: Widen<T> {
+            let lanes = <Vector<f32> as Register<f32>>::LANES;
+            let len = lhs.len();
+            let ptr = lhs.as_mut_ptr();
+            let a = a.as_ptr();
+            let b = b.as_ptr();
+
+            let mut i = 0;
+            while i + lanes <= len {
+                f(<Vector<f32> as Widen<T>>::load_wide(ptr.add(i)), <Vector<f32> as Widen<T>>::load_wide(a.add(i)), <Vector<f32> as Widen<T>>::load_wide(b.add(i))).store_narrow(ptr.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                let rem = len - i;
+                f(
+                    <Vector<f32> as Widen<T>>::load_wide_partial(ptr.add(i), rem),
+                    <Vector<f32> as Widen<T>>::load_wide_partial(a.add(i), rem),
+                    <Vector<f32> as Widen<T>>::load_wide_partial(b.add(i), rem)
+                ).store_narrow_partial(ptr.add(i), rem);
+            }
+        }
+
+        /// Converts `src` into `f32`.
+        ///
+        /// # Safety
+        /// `dst` must be at least as long as `src`
+        #[inline(always)]
+        pub(crate) unsafe fn widen_into<T: Copy> (src: &[T], dst: &mut [MaybeUninit<f32>]) where Vector<f32>: Widen<T> {
+            let lanes = <Vector<f32> as Register<f32>>::LANES;
+            let len = src.len();
+            let src = src.as_ptr();
+            let dst = dst.as_mut_ptr().cast::<f32>();
+
+            let mut i = 0;
+            while i + lanes <= len {
+                <Vector<f32> as Widen<T>>::load_wide(src.add(i)).store(dst.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                <Vector<f32> as Widen<T>>::load_wide_partial(src.add(i), len - i).store_partial(dst.add(i), len - i);
+            }
+        }
+
+        /// Converts `src` from `f32`, rounding to nearest.
+        ///
+        /// # Safety
+        /// `dst` must be at least as long as `src`
+        #[inline(always)]
+        pub(crate) unsafe fn narrow_into<T: Copy> (src: &[f32], dst: &mut [MaybeUninit<T>]) where Vector<f32>: Widen<T> {
+            let lanes = <Vector<f32> as Register<f32>>::LANES;
+            let len = src.len();
+            let src = src.as_ptr();
+            let dst = dst.as_mut_ptr().cast::<T>();
+
+            let mut i = 0;
+            while i + lanes <= len {
+                <Vector<f32> as Widen<T>>::store_narrow(Vector::<f32>::load(src.add(i)), dst.add(i));
+                i += lanes;
+            }
+
+            if i < len {
+                <Vector<f32> as Widen<T>>::store_narrow_partial(Vector::<f32>::load_partial(src.add(i), len - i), dst.add(i), len - i);
+            }
+        }
+    };
+}
+
+widen_drivers!();
@@ -0,0 +1,65 @@
+use core::arch::wasm32::*;
+use docfg::docfg;
+use crate::sealed::Lane;
+use super::*;
+
+impl_accumulate! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+impl_mean! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64
+}
+
+impl_lane! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64
+}
+
+macro_rules! impl_arith {
+    ($($t:ty),+ => $add:ident, $sub:ident) => {
+        $(
+            impl Arith<$t> for v128 {
+                #[inline(always)]
+                unsafe fn add (self, rhs: Self) -> Self {
+                    $add(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn sub (self, rhs: Self) -> Self {
+                    $sub(self, rhs)
+                }
+            }
+        )+
+    };
+
+    ($t:ty => $add:ident, $sub:ident, $mul:ident, $div:ident) => {
+        impl_arith! { $t => $add, $sub }
+
+        impl FloatArith<$t> for v128 {
+            #[inline(always)]
+            unsafe fn mul (self, rhs: Self) -> Self {
+                $mul(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn div (self, rhs: Self) -> Self {
+                $div(self, rhs)
+            }
+        }
+    };
+}
+
+/* FLOATS */
+impl_arith! { f32 => f32x4_add, f32x4_sub, f32x4_mul, f32x4_div }
+impl_arith! { f64 => f64x2_add, f64x2_sub, f64x2_mul, f64x2_div }
+
+/* INTEGERS */
+impl_arith! { i8, u8 => i8x16_add, i8x16_sub }
+impl_arith! { i16, u16 => i16x8_add, i16x8_sub }
+impl_arith! { i32, u32 => i32x4_add, i32x4_sub }
+impl_arith! { i64, u64 => i64x2_add, i64x2_sub }
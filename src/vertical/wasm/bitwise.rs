@@ -0,0 +1,48 @@
+use core::arch::wasm32::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_bitwise! {
+    #[cfg(target_feature = "simd128")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_register {
+    ($($t:ty),+) => {
+        $(
+            impl Bitwise<$t> for v128 {
+                #[inline(always)]
+                unsafe fn and (self, rhs: Self) -> Self {
+                    v128_and(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn or (self, rhs: Self) -> Self {
+                    v128_or(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn xor (self, rhs: Self) -> Self {
+                    v128_xor(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn andnot (self, rhs: Self) -> Self {
+                    v128_andnot(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn not (self) -> Self {
+                    v128_not(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_register! {
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
@@ -0,0 +1,50 @@
+use core::arch::wasm32::*;
+use docfg::docfg;
+use super::*;
+use super::minmax::flip_epi64;
+
+impl_cmp! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_cmp {
+    ($($t:ty as $bitmask:ident => $eq:expr, $lt:expr, $le:expr),+) => {
+        $(
+            impl Compare<$t> for v128 {
+                #[inline(always)]
+                unsafe fn eq (self, rhs: Self) -> u64 {
+                    $bitmask($eq(self, rhs)) as u64
+                }
+
+                #[inline(always)]
+                unsafe fn lt (self, rhs: Self) -> u64 {
+                    $bitmask($lt(self, rhs)) as u64
+                }
+
+                #[inline(always)]
+                unsafe fn le (self, rhs: Self) -> u64 {
+                    $bitmask($le(self, rhs)) as u64
+                }
+            }
+        )+
+    };
+}
+
+impl_cmp! {
+    f32 as i32x4_bitmask => f32x4_eq, f32x4_lt, f32x4_le,
+    f64 as i64x2_bitmask => f64x2_eq, f64x2_lt, f64x2_le,
+    i8 as i8x16_bitmask => i8x16_eq, i8x16_lt, i8x16_le,
+    u8 as i8x16_bitmask => i8x16_eq, u8x16_lt, u8x16_le,
+    i16 as i16x8_bitmask => i16x8_eq, i16x8_lt, i16x8_le,
+    u16 as i16x8_bitmask => i16x8_eq, u16x8_lt, u16x8_le,
+    i32 as i32x4_bitmask => i32x4_eq, i32x4_lt, i32x4_le,
+    u32 as i32x4_bitmask => i32x4_eq, u32x4_lt, u32x4_le,
+    i64 as i64x2_bitmask => i64x2_eq, i64x2_lt, i64x2_le,
+    // There are no unsigned 64-bit comparisons
+    u64 as i64x2_bitmask => i64x2_eq,
+        |x, y| i64x2_lt(flip_epi64(x), flip_epi64(y)),
+        |x, y| i64x2_le(flip_epi64(x), flip_epi64(y))
+}
@@ -0,0 +1,362 @@
+use core::arch::wasm32::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+// Conversions go through blocks of 32-bit lanes, so that every pair of element types shares the same block size
+/// Number of elements converted at once
+const BLOCK: usize = 4;
+type Epi32 = v128;
+type Ps = v128;
+/// A block of 64-bit lanes, split into two registers
+type Pd = [v128; 2];
+type Epi64 = [v128; 2];
+
+/// Integers of up to 32 bits, converted through a block of 32-bit lanes.
+trait Lanes: Copy {
+    const MIN: f64;
+    const MAX: f64;
+
+    /// Loads a block of elements, extended to 32 bits with `as` semantics (unsigned 32-bit elements keep their bits).
+    unsafe fn load (ptr: *const Self) -> Epi32;
+    /// Stores a block of 32-bit lanes, truncated to the element type.
+    unsafe fn store (v: Epi32, ptr: *mut Self);
+
+    /// Converts lanes loaded from this element type to floats.
+    #[inline(always)]
+    unsafe fn to_ps (v: Epi32) -> Ps {
+        f32x4_convert_i32x4(v)
+    }
+
+    #[inline(always)]
+    unsafe fn to_pd (v: Epi32) -> Pd {
+        [f64x2_convert_low_i32x4(v), f64x2_convert_low_i32x4(high_half(v))]
+    }
+
+    /// Extends lanes loaded from this element type to 64 bits.
+    #[inline(always)]
+    unsafe fn to_epi64 (v: Epi32) -> Epi64 {
+        [i64x2_extend_low_i32x4(v), i64x2_extend_high_i32x4(v)]
+    }
+
+    /// Converts floats to this element type, rounding towards zero and saturating (with NaN converting to zero).
+    #[inline(always)]
+    unsafe fn from_ps (v: Ps) -> Epi32 {
+        // The conversion saturates to `i32` and turns NaN into zero by itself, and `max` lets NaN through
+        let v = f32x4_min(f32x4_max(v, f32x4_splat(Self::MIN as f32)), f32x4_splat(Self::MAX as f32));
+        i32x4_trunc_sat_f32x4(v)
+    }
+
+    #[inline(always)]
+    unsafe fn from_pd (v: Pd) -> Epi32 {
+        let v = v.map(|x| f64x2_min(f64x2_max(x, f64x2_splat(Self::MIN)), f64x2_splat(Self::MAX)));
+        let v = v.map(|x| i32x4_trunc_sat_f64x2_zero(x));
+        i64x2_shuffle::<0, 2>(v[0], v[1])
+    }
+}
+
+macro_rules! impl_convert {
+    ($($a:ident),+ => $bs:tt as |$src:ident, $dst:ident| $f:expr) => {
+        $(
+            impl_convert! { @row $a => $bs as |$src, $dst| $f }
+        )+
+    };
+
+    (@row $a:ident => [$($b:ident),+] as |$src:ident, $dst:ident| $f:expr) => {
+        $(
+            #[cfg(not(target_feature = "simd128"))]
+            impl VerticalConvert<$b> for [$a] {}
+
+            #[docfg(target_feature = "simd128")]
+            impl SimdVerticalConvert<$b> for [$a] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$b>]) {
+                    type A = $a;
+                    type B = $b;
+                    convert_raw(self.as_ptr(), out.as_mut_ptr().cast(), self.len(), |$src: *const A, $dst: *mut B| $f)
+                }
+            }
+        )+
+    };
+
+    // Conversions that only copy bits around
+    (copy: $($a:ident),+ => $bs:tt) => {
+        $(
+            impl_convert! { @copy $a => $bs }
+        )+
+    };
+
+    (@copy $a:ident => [$($b:ident),+]) => {
+        $(
+            impl SimdVerticalConvert<$b> for [$a] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$b>]) {
+                    core::ptr::copy_nonoverlapping(self.as_ptr().cast::<$b>(), out.as_mut_ptr().cast(), self.len())
+                }
+            }
+        )+
+    };
+
+    (scalar: $($a:ident),+ => $bs:tt) => {
+        $(
+            impl_convert! { @scalar $a => $bs }
+        )+
+    };
+
+    (@scalar $a:ident => [$($b:ident),+]) => {
+        $(
+            impl VerticalConvert<$b> for [$a] {}
+        )+
+    };
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(A::load(src), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [f32]
+    as |src, dst| store_ps(A::to_ps(A::load(src)), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [f64]
+    as |src, dst| store_pd(A::to_pd(A::load(src)), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [i64, u64]
+    as |src, dst| store_epi64(A::to_epi64(A::load(src)), dst.cast())
+}
+
+impl_convert! {
+    f32 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(B::from_ps(load_ps(src)), dst)
+}
+
+impl_convert! {
+    f64 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(B::from_pd(load_pd(src)), dst)
+}
+
+impl_convert! {
+    i64, u64 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(narrow_epi64(load_epi64(src.cast())), dst)
+}
+
+impl_convert! {
+    f32 => [f64]
+    as |src, dst| store_pd(cvtps_pd(load_ps(src)), dst)
+}
+
+impl_convert! {
+    f64 => [f32]
+    as |src, dst| store_ps(cvtpd_ps(load_pd(src)), dst)
+}
+
+impl_convert! {
+    copy: i64, u64 => [i64, u64]
+}
+
+impl_convert! {
+    copy: f32 => [f32]
+}
+
+impl_convert! {
+    copy: f64 => [f64]
+}
+
+// There are no conversions between 64-bit integers and floats
+impl_convert! {
+    scalar: i64, u64 => [f32, f64]
+}
+
+impl_convert! {
+    scalar: f32, f64 => [i64, u64]
+}
+
+/// Converts `len` elements from `src` into `dst`, one block at a time.
+///
+/// The trailing elements are padded with zeros into a full block.
+#[inline(always)]
+unsafe fn convert_raw<A: Copy, B: Copy> (src: *const A, dst: *mut B, len: usize, f: impl Fn(*const A, *mut B)) {
+    let mut i = 0;
+    while i + BLOCK <= len {
+        f(src.add(i), dst.add(i));
+        i += BLOCK;
+    }
+
+    if i < len {
+        let rem = len - i;
+        let mut lhs = MaybeUninit::<[A; BLOCK]>::zeroed();
+        let mut rhs = MaybeUninit::<[B; BLOCK]>::uninit();
+        core::ptr::copy_nonoverlapping(src.add(i), lhs.as_mut_ptr().cast::<A>(), rem);
+        f(lhs.as_ptr().cast(), rhs.as_mut_ptr().cast());
+        core::ptr::copy_nonoverlapping(rhs.as_ptr().cast::<B>(), dst.add(i), rem);
+    }
+}
+
+/* INTEGERS */
+macro_rules! impl_lanes {
+    (
+        $t:ident {
+            load: |$load_ptr:ident| $load:expr,
+            store: |$store_v:ident, $store_ptr:ident| $store:expr
+        }
+    ) => {
+        impl Lanes for $t {
+            const MIN: f64 = $t::MIN as f64;
+            const MAX: f64 = $t::MAX as f64;
+
+            #[inline(always)]
+            unsafe fn load ($load_ptr: *const Self) -> Epi32 {
+                $load
+            }
+
+            #[inline(always)]
+            unsafe fn store ($store_v: Epi32, $store_ptr: *mut Self) {
+                $store
+            }
+        }
+    };
+}
+
+impl_lanes! {
+    i8 {
+        load: |ptr| i32x4_extend_low_i16x8(i16x8_extend_low_i8x16(v128_load32_zero(ptr.cast()))),
+        store: |v, ptr| store_epi8(v, ptr.cast())
+    }
+}
+
+impl_lanes! {
+    u8 {
+        load: |ptr| u32x4_extend_low_u16x8(u16x8_extend_low_u8x16(v128_load32_zero(ptr.cast()))),
+        store: |v, ptr| store_epi8(v, ptr.cast())
+    }
+}
+
+impl_lanes! {
+    i16 {
+        load: |ptr| i32x4_extend_low_i16x8(v128_load64_zero(ptr.cast())),
+        store: |v, ptr| v128_store64_lane::<0>(i8x16_shuffle::<0, 1, 4, 5, 8, 9, 12, 13, 0, 0, 0, 0, 0, 0, 0, 0>(v, v), ptr.cast())
+    }
+}
+
+impl_lanes! {
+    u16 {
+        load: |ptr| u32x4_extend_low_u16x8(v128_load64_zero(ptr.cast())),
+        store: |v, ptr| i16::store(v, ptr.cast())
+    }
+}
+
+impl_lanes! {
+    i32 {
+        load: |ptr| v128_load(ptr.cast()),
+        store: |v, ptr| v128_store(ptr.cast(), v)
+    }
+}
+
+/// Truncates a block of 32-bit lanes to bytes.
+#[inline(always)]
+unsafe fn store_epi8 (v: Epi32, ptr: *mut i8) {
+    let v = i8x16_shuffle::<0, 4, 8, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0>(v, v);
+    v128_store32_lane::<0>(v, ptr.cast())
+}
+
+impl Lanes for u32 {
+    const MIN: f64 = u32::MIN as f64;
+    const MAX: f64 = u32::MAX as f64;
+
+    #[inline(always)]
+    unsafe fn load (ptr: *const Self) -> Epi32 {
+        i32::load(ptr.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn store (v: Epi32, ptr: *mut Self) {
+        i32::store(v, ptr.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn to_ps (v: Epi32) -> Ps {
+        f32x4_convert_u32x4(v)
+    }
+
+    #[inline(always)]
+    unsafe fn to_pd (v: Epi32) -> Pd {
+        [f64x2_convert_low_u32x4(v), f64x2_convert_low_u32x4(high_half(v))]
+    }
+
+    #[inline(always)]
+    unsafe fn to_epi64 (v: Epi32) -> Epi64 {
+        [u64x2_extend_low_u32x4(v), u64x2_extend_high_u32x4(v)]
+    }
+
+    // The unsigned conversions saturate on their own
+    #[inline(always)]
+    unsafe fn from_ps (v: Ps) -> Epi32 {
+        u32x4_trunc_sat_f32x4(v)
+    }
+
+    #[inline(always)]
+    unsafe fn from_pd (v: Pd) -> Epi32 {
+        let v = v.map(|x| u32x4_trunc_sat_f64x2_zero(x));
+        i64x2_shuffle::<0, 2>(v[0], v[1])
+    }
+}
+
+/* FLOATS */
+#[inline(always)]
+unsafe fn load_ps (ptr: *const f32) -> Ps {
+    v128_load(ptr.cast())
+}
+
+#[inline(always)]
+unsafe fn store_ps (v: Ps, ptr: *mut f32) {
+    v128_store(ptr.cast(), v)
+}
+
+#[inline(always)]
+unsafe fn load_pd (ptr: *const f64) -> Pd {
+    [v128_load(ptr.cast()), v128_load(ptr.add(2).cast())]
+}
+
+#[inline(always)]
+unsafe fn store_pd (v: Pd, ptr: *mut f64) {
+    v128_store(ptr.cast(), v[0]);
+    v128_store(ptr.add(2).cast(), v[1])
+}
+
+#[inline(always)]
+unsafe fn load_epi64 (ptr: *const i64) -> Epi64 {
+    [v128_load(ptr.cast()), v128_load(ptr.add(2).cast())]
+}
+
+#[inline(always)]
+unsafe fn store_epi64 (v: Epi64, ptr: *mut i64) {
+    v128_store(ptr.cast(), v[0]);
+    v128_store(ptr.add(2).cast(), v[1])
+}
+
+/// Moves the high 64 bits of `v` into the low ones.
+#[inline(always)]
+unsafe fn high_half (v: v128) -> v128 {
+    i64x2_shuffle::<1, 1>(v, v)
+}
+
+/// Truncates a block of 64-bit lanes to 32 bits.
+#[inline(always)]
+unsafe fn narrow_epi64 (v: Epi64) -> Epi32 {
+    i32x4_shuffle::<0, 2, 4, 6>(v[0], v[1])
+}
+
+#[inline(always)]
+unsafe fn cvtps_pd (v: Ps) -> Pd {
+    [f64x2_promote_low_f32x4(v), f64x2_promote_low_f32x4(high_half(v))]
+}
+
+#[inline(always)]
+unsafe fn cvtpd_ps (v: Pd) -> Ps {
+    i64x2_shuffle::<0, 2>(f32x4_demote_f64x2_zero(v[0]), f32x4_demote_f64x2_zero(v[1]))
+}
@@ -0,0 +1,29 @@
+use core::arch::wasm32::*;
+use docfg::docfg;
+use super::*;
+
+impl_mul_add! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64
+}
+
+impl_default! {
+    VerticalMulAdd =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
+
+// There's no fused multiply-add in `simd128`, so it's rounded twice
+impl MulAdd<f32> for v128 {
+    #[inline(always)]
+    unsafe fn mul_add (self, a: Self, b: Self) -> Self {
+        f32x4_add(f32x4_mul(self, a), b)
+    }
+}
+
+impl MulAdd<f64> for v128 {
+    #[inline(always)]
+    unsafe fn mul_add (self, a: Self, b: Self) -> Self {
+        f64x2_add(f64x2_mul(self, a), b)
+    }
+}
@@ -0,0 +1,101 @@
+use core::arch::wasm32::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::vertical::math::*;
+use crate::vertical::simd::math::*;
+use super::*;
+
+impl_math! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64
+}
+
+// There are no half-precision conversions to widen `f16` through
+#[cfg(feature = "half")]
+impl_widen_math! {
+    #[cfg(any())]
+    f16
+}
+
+#[cfg(feature = "half")]
+impl_widen_math! {
+    #[cfg(target_feature = "simd128")]
+    bf16
+}
+
+/* REGISTERS */
+// Every lane type shares the same register, so masks are registers too
+macro_rules! impl_lanes {
+    (
+        $t:ty {
+            $add:ident, $sub:ident, $mul:ident, $div:ident,
+            $shl:ident, $shr:ident,
+            $lt:ident, $le:ident, $eq:ident, $ne:ident
+        }
+    ) => {
+        impl Lanes<$t> for v128 {
+            type Mask = v128;
+            // There's no fused multiply-add in `simd128`
+            const FUSED: bool = false;
+
+            #[inline(always)]
+            unsafe fn add (self, rhs: Self) -> Self { $add(self, rhs) }
+            #[inline(always)]
+            unsafe fn sub (self, rhs: Self) -> Self { $sub(self, rhs) }
+            #[inline(always)]
+            unsafe fn mul (self, rhs: Self) -> Self { $mul(self, rhs) }
+            #[inline(always)]
+            unsafe fn div (self, rhs: Self) -> Self { $div(self, rhs) }
+            #[inline(always)]
+            unsafe fn and (self, rhs: Self) -> Self { v128_and(self, rhs) }
+            #[inline(always)]
+            unsafe fn or (self, rhs: Self) -> Self { v128_or(self, rhs) }
+            #[inline(always)]
+            unsafe fn xor (self, rhs: Self) -> Self { v128_xor(self, rhs) }
+            #[inline(always)]
+            unsafe fn shl (self, count: i32) -> Self { $shl(self, count as u32) }
+            #[inline(always)]
+            unsafe fn shr (self, count: i32) -> Self { $shr(self, count as u32) }
+
+            #[inline(always)]
+            unsafe fn lt (self, rhs: Self) -> v128 { $lt(self, rhs) }
+            #[inline(always)]
+            unsafe fn le (self, rhs: Self) -> v128 { $le(self, rhs) }
+            #[inline(always)]
+            unsafe fn eq (self, rhs: Self) -> v128 { $eq(self, rhs) }
+
+            #[inline(always)]
+            unsafe fn unord (self, rhs: Self) -> v128 {
+                // Only NaN lanes compare unequal to themselves
+                v128_or($ne(self, self), $ne(rhs, rhs))
+            }
+
+            #[inline(always)]
+            unsafe fn mask_and (lhs: v128, rhs: v128) -> v128 { v128_and(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn mask_or (lhs: v128, rhs: v128) -> v128 { v128_or(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn mask_andnot (lhs: v128, rhs: v128) -> v128 { v128_andnot(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn any (mask: v128) -> bool { v128_any_true(mask) }
+            #[inline(always)]
+            unsafe fn select (mask: v128, a: Self, b: Self) -> Self { v128_bitselect(a, b, mask) }
+        }
+    };
+}
+
+impl_lanes! {
+    f32 {
+        f32x4_add, f32x4_sub, f32x4_mul, f32x4_div,
+        u32x4_shl, u32x4_shr,
+        f32x4_lt, f32x4_le, f32x4_eq, f32x4_ne
+    }
+}
+
+impl_lanes! {
+    f64 {
+        f64x2_add, f64x2_sub, f64x2_mul, f64x2_div,
+        u64x2_shl, u64x2_shr,
+        f64x2_lt, f64x2_le, f64x2_eq, f64x2_ne
+    }
+}
@@ -0,0 +1,84 @@
+use core::arch::wasm32::*;
+use docfg::docfg;
+use super::*;
+
+impl_minmax! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* FLOATS */
+macro_rules! impl_float {
+    ($t:ty { $pmin:ident, $pmax:ident, $ne:ident }) => {
+        impl MinMax<$t> for v128 {
+            // `pmin(a, b)` is `b < a ? b : a`, which returns `a` if either of them is NaN
+            #[inline(always)]
+            unsafe fn min (self, rhs: Self) -> Self {
+                let nan = $ne(rhs, rhs);
+                v128_bitselect(self, $pmin(rhs, self), nan)
+            }
+
+            #[inline(always)]
+            unsafe fn max (self, rhs: Self) -> Self {
+                let nan = $ne(rhs, rhs);
+                v128_bitselect(self, $pmax(rhs, self), nan)
+            }
+
+            #[inline(always)]
+            unsafe fn clamp (self, lo: Self, hi: Self) -> Self {
+                // Keeping `self` as the first operand lets NaN lanes through
+                $pmax($pmin(self, hi), lo)
+            }
+        }
+    };
+}
+
+impl_float! { f32 { f32x4_pmin, f32x4_pmax, f32x4_ne } }
+impl_float! { f64 { f64x2_pmin, f64x2_pmax, f64x2_ne } }
+
+/* INTEGERS */
+macro_rules! impl_int {
+    ($t:ty { $min:expr, $max:expr }) => {
+        impl MinMax<$t> for v128 {
+            #[inline(always)]
+            unsafe fn min (self, rhs: Self) -> Self {
+                $min(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn max (self, rhs: Self) -> Self {
+                $max(self, rhs)
+            }
+        }
+    };
+}
+
+impl_int! { i8 { i8x16_min, i8x16_max } }
+impl_int! { u8 { u8x16_min, u8x16_max } }
+impl_int! { i16 { i16x8_min, i16x8_max } }
+impl_int! { u16 { u16x8_min, u16x8_max } }
+impl_int! { i32 { i32x4_min, i32x4_max } }
+impl_int! { u32 { u32x4_min, u32x4_max } }
+
+// There are no 64-bit `min` and `max` instructions, nor unsigned 64-bit comparisons
+impl_int! {
+    i64 {
+        |x, y| v128_bitselect(y, x, i64x2_gt(x, y)),
+        |x, y| v128_bitselect(x, y, i64x2_gt(x, y))
+    }
+}
+
+impl_int! {
+    u64 {
+        |x, y| v128_bitselect(y, x, i64x2_gt(flip_epi64(x), flip_epi64(y))),
+        |x, y| v128_bitselect(x, y, i64x2_gt(flip_epi64(x), flip_epi64(y)))
+    }
+}
+
+/// Flips the sign bit of 64-bit lanes, so that signed comparisons order them as unsigned.
+#[inline(always)]
+pub(super) unsafe fn flip_epi64 (x: v128) -> v128 {
+    v128_xor(x, i64x2_splat(i64::MIN))
+}
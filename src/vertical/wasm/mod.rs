@@ -0,0 +1,64 @@
+//! WebAssembly backend, on the 128-bit `simd128` registers.
+
+use docfg::docfg;
+use concat_idents::concat_idents;
+use super::*;
+use super::simd::*;
+
+mod register;
+mod arith;
+mod minmax;
+mod fma;
+mod unary;
+mod round;
+mod cmp;
+mod select;
+mod bitwise;
+mod shift;
+mod convert;
+#[cfg(feature = "half")]
+mod widen;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod math;
+
+/* ADDITIONS */
+impl_arith_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalAdd as add => Arith::add for
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* SUBTRACTIONS */
+impl_arith_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalSub as sub => Arith::sub for
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* MULTIPLICATIONS */
+impl_arith_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalMul as mul => FloatArith::mul for f32, f64
+}
+
+impl_default! {
+    VerticalMul =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
+
+/* DIVISIONS */
+impl_arith_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalDiv as div => FloatArith::div for f32, f64
+}
+
+impl_default! {
+    VerticalDiv =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
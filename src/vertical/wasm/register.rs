@@ -0,0 +1,46 @@
+use core::arch::wasm32::*;
+use super::*;
+
+// Every element type shares the same register, like the integers do on x86
+macro_rules! impl_register {
+    ($($t:ty => $splat:ident),+) => {
+        $(
+            impl Register<$t> for v128 {
+                #[inline(always)]
+                unsafe fn load (ptr: *const $t) -> Self {
+                    v128_load(ptr.cast())
+                }
+
+                #[inline(always)]
+                unsafe fn store (self, ptr: *mut $t) {
+                    v128_store(ptr.cast(), self)
+                }
+
+                #[inline(always)]
+                unsafe fn splat (value: $t) -> Self {
+                    $splat(value)
+                }
+            }
+
+            impl Vectorize for $t {
+                type Vector = v128;
+                type Vector256 = v128;
+                type Vector128 = v128;
+                const ISA: &'static str = "simd128";
+            }
+        )+
+    };
+}
+
+impl_register! {
+    f32 => f32x4_splat,
+    f64 => f64x2_splat,
+    i8 => i8x16_splat,
+    u8 => u8x16_splat,
+    i16 => i16x8_splat,
+    u16 => u16x8_splat,
+    i32 => i32x4_splat,
+    u32 => u32x4_splat,
+    i64 => i64x2_splat,
+    u64 => u64x2_splat
+}
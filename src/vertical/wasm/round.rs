@@ -0,0 +1,65 @@
+use core::arch::wasm32::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_round! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64
+}
+
+macro_rules! impl_float {
+    (
+        $t:ty {
+            $floor:ident, $ceil:ident, $trunc:ident, $nearest:ident,
+            $splat:ident, $add:ident, $sub:ident, $abs:ident, $ge:ident
+        }
+    ) => {
+        impl Round<$t> for v128 {
+            #[inline(always)]
+            unsafe fn floor (self) -> Self {
+                $floor(self)
+            }
+
+            #[inline(always)]
+            unsafe fn ceil (self) -> Self {
+                $ceil(self)
+            }
+
+            #[inline(always)]
+            unsafe fn trunc (self) -> Self {
+                $trunc(self)
+            }
+
+            #[inline(always)]
+            unsafe fn round (self) -> Self {
+                // Step away from the truncated value if we were at least half-way to the next integer.
+                // `self - trunc` is exact, so values just under one half don't get rounded up.
+                let sign = v128_and(self, $splat(-0.0));
+                let trunc = $trunc(self);
+                let half = $ge($abs($sub(self, trunc)), $splat(0.5));
+                let step = v128_and(half, v128_or($splat(1.0), sign));
+                return v128_or($add(trunc, step), sign);
+            }
+
+            #[inline(always)]
+            unsafe fn round_ties_even (self) -> Self {
+                $nearest(self)
+            }
+        }
+    };
+}
+
+impl_float! {
+    f32 {
+        f32x4_floor, f32x4_ceil, f32x4_trunc, f32x4_nearest,
+        f32x4_splat, f32x4_add, f32x4_sub, f32x4_abs, f32x4_ge
+    }
+}
+
+impl_float! {
+    f64 {
+        f64x2_floor, f64x2_ceil, f64x2_trunc, f64x2_nearest,
+        f64x2_splat, f64x2_add, f64x2_sub, f64x2_abs, f64x2_ge
+    }
+}
@@ -0,0 +1,67 @@
+use core::arch::wasm32::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::sealed::Selector;
+use super::*;
+
+impl_select! {
+    #[cfg(target_feature = "simd128")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* LANE MASKS */
+/// Expands the low bits of `bits` into the lanes of a register, with every bit of lane `i` set if bit `i` is.
+macro_rules! impl_expand {
+    ($($name:ident: $splat:ident($bits:ty) & $sel:expr => $eq:ident),+) => {
+        $(
+            #[inline(always)]
+            unsafe fn $name (bits: u64) -> v128 {
+                let sel = $sel;
+                return $eq(v128_and($splat(bits as $bits), sel), sel)
+            }
+        )+
+    };
+}
+
+impl_expand! {
+    expand_16: u16x8_splat(u16) & u16x8(1, 2, 4, 8, 16, 32, 64, 128) => i16x8_eq,
+    expand_32: u32x4_splat(u32) & u32x4(1, 2, 4, 8) => i32x4_eq,
+    expand_64: u64x2_splat(u64) & u64x2(1, 2) => i64x2_eq
+}
+
+// 8-bit lanes test the low byte of `bits` on the low half, and the high byte on the high half
+#[inline(always)]
+unsafe fn expand_8 (bits: u64) -> v128 {
+    const BYTES: u64 = 0x0101010101010101;
+    let sel = u8x16(1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128);
+    let v = u64x2((bits & 0xff) * BYTES, (bits >> 8 & 0xff) * BYTES);
+    return i8x16_eq(v128_and(v, sel), sel)
+}
+
+macro_rules! impl_blend {
+    ($($t:ty => $expand:ident),+) => {
+        $(
+            impl Blend<$t> for v128 {
+                #[inline(always)]
+                unsafe fn blend (self, rhs: Self, bits: u64) -> Self {
+                    v128_bitselect(rhs, self, $expand(bits))
+                }
+            }
+        )+
+    };
+}
+
+impl_blend! {
+    f32 => expand_32,
+    f64 => expand_64,
+    i8 => expand_8,
+    u8 => expand_8,
+    i16 => expand_16,
+    u16 => expand_16,
+    i32 => expand_32,
+    u32 => expand_32,
+    i64 => expand_64,
+    u64 => expand_64
+}
@@ -0,0 +1,119 @@
+use core::arch::wasm32::*;
+use docfg::docfg;
+use super::*;
+
+impl_shift! {
+    #[cfg(target_feature = "simd128")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/// Shifts the lanes of `x` by their counts, one bit of the counts at a time.
+///
+/// `shift(x, k)` shifts every lane of `x` by `k`, while `select(x, y, k)` picks `y` on the lanes whose count has bit `k` set.
+#[inline(always)]
+unsafe fn shift_lanes (mut x: v128, bits: u32, shift: impl Fn(v128, u32) -> v128, select: impl Fn(v128, v128, u32) -> v128) -> v128 {
+    let mut k = 1;
+    while k < bits {
+        x = select(x, shift(x, k), k);
+        k <<= 1;
+    }
+    return x
+}
+
+/* LANE SELECTION */
+macro_rules! impl_select_bit {
+    ($($name:ident => $splat:ident($bits:ty), $eq:ident),+) => {
+        $(
+            /// Picks `y` on the lanes where `counts` has bit `k` set, and `x` otherwise.
+            #[inline(always)]
+            unsafe fn $name (x: v128, y: v128, counts: v128, k: u32) -> v128 {
+                let bit = $splat(k as $bits);
+                return v128_bitselect(y, x, $eq(v128_and(counts, bit), bit))
+            }
+        )+
+    };
+}
+
+impl_select_bit! {
+    select_bit_8 => i8x16_splat(i8), i8x16_eq,
+    select_bit_16 => i16x8_splat(i16), i16x8_eq,
+    select_bit_32 => i32x4_splat(i32), i32x4_eq,
+    select_bit_64 => i64x2_splat(i64), i64x2_eq
+}
+
+/// Implements [`Shift`] for a pair of signed and unsigned lanes of the same width.
+///
+/// Shifts by a single count are native, while shifts by a count per lane are done one bit of the counts at a time.
+macro_rules! impl_register {
+    ($si:ident & $us:ident: $bits:literal => $shl:ident, $sra:ident, $srl:ident, $select:ident) => {
+        impl Shift<$si> for v128 {
+            #[inline(always)]
+            unsafe fn shl (self, n: u32) -> Self {
+                $shl(self, n)
+            }
+
+            #[inline(always)]
+            unsafe fn shr (self, n: u32) -> Self {
+                $sra(self, n)
+            }
+
+            #[inline(always)]
+            unsafe fn shr_logical (self, n: u32) -> Self {
+                $srl(self, n)
+            }
+
+            #[inline(always)]
+            unsafe fn shlv (self, counts: Self) -> Self {
+                shift_lanes(self, $bits, |x, k| $shl(x, k), |x, y, k| $select(x, y, counts, k))
+            }
+
+            #[inline(always)]
+            unsafe fn shrv (self, counts: Self) -> Self {
+                shift_lanes(self, $bits, |x, k| $sra(x, k), |x, y, k| $select(x, y, counts, k))
+            }
+
+            #[inline(always)]
+            unsafe fn shrv_logical (self, counts: Self) -> Self {
+                shift_lanes(self, $bits, |x, k| $srl(x, k), |x, y, k| $select(x, y, counts, k))
+            }
+        }
+
+        impl Shift<$us> for v128 {
+            #[inline(always)]
+            unsafe fn shl (self, n: u32) -> Self {
+                $shl(self, n)
+            }
+
+            #[inline(always)]
+            unsafe fn shr (self, n: u32) -> Self {
+                $srl(self, n)
+            }
+
+            #[inline(always)]
+            unsafe fn shr_logical (self, n: u32) -> Self {
+                $srl(self, n)
+            }
+
+            #[inline(always)]
+            unsafe fn shlv (self, counts: Self) -> Self {
+                shift_lanes(self, $bits, |x, k| $shl(x, k), |x, y, k| $select(x, y, counts, k))
+            }
+
+            #[inline(always)]
+            unsafe fn shrv (self, counts: Self) -> Self {
+                shift_lanes(self, $bits, |x, k| $srl(x, k), |x, y, k| $select(x, y, counts, k))
+            }
+
+            #[inline(always)]
+            unsafe fn shrv_logical (self, counts: Self) -> Self {
+                shift_lanes(self, $bits, |x, k| $srl(x, k), |x, y, k| $select(x, y, counts, k))
+            }
+        }
+    };
+}
+
+impl_register! { i8 & u8: 8 => i8x16_shl, i8x16_shr, u8x16_shr, select_bit_8 }
+impl_register! { i16 & u16: 16 => i16x8_shl, i16x8_shr, u16x8_shr, select_bit_16 }
+impl_register! { i32 & u32: 32 => i32x4_shl, i32x4_shr, u32x4_shr, select_bit_32 }
+impl_register! { i64 & u64: 64 => i64x2_shl, i64x2_shr, u64x2_shr, select_bit_64 }
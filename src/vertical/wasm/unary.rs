@@ -0,0 +1,94 @@
+use core::arch::wasm32::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_unary_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalAbs as abs() => Sign::abs for f32, f64, i8, i16, i32, i64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalNeg as neg() => Sign::neg for f32, f64, i8, i16, i32, i64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalSqrt as sqrt() => Unary::sqrt for f32, f64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalRsqrt as rsqrt(refine: bool) => Unary::rsqrt for f32, f64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalRecip as recip(refine: bool) => Unary::recip for f32, f64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "simd128")]
+    VerticalSquare as square() => Unary::square for f32, f64
+}
+
+macro_rules! impl_sign {
+    ($($t:ty => $abs:ident & $neg:ident),+) => {
+        $(
+            impl Sign<$t> for v128 {
+                #[inline(always)]
+                unsafe fn abs (self) -> Self {
+                    $abs(self)
+                }
+
+                #[inline(always)]
+                unsafe fn neg (self) -> Self {
+                    $neg(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_sign! {
+    f32 => f32x4_abs & f32x4_neg,
+    f64 => f64x2_abs & f64x2_neg,
+    i8 => i8x16_abs & i8x16_neg,
+    i16 => i16x8_abs & i16x8_neg,
+    i32 => i32x4_abs & i32x4_neg,
+    i64 => i64x2_abs & i64x2_neg
+}
+
+/* FLOATS */
+// There are no estimate instructions, so the exact values are computed instead
+macro_rules! impl_float {
+    ($t:ty { $splat:ident, $mul:ident, $div:ident, $sqrt:ident }) => {
+        impl Unary<$t> for v128 {
+            #[inline(always)]
+            unsafe fn sqrt (self) -> Self {
+                $sqrt(self)
+            }
+
+            #[inline(always)]
+            unsafe fn square (self) -> Self {
+                $mul(self, self)
+            }
+
+            #[inline(always)]
+            unsafe fn rsqrt (self, _refine: bool) -> Self {
+                $div($splat(1.0), $sqrt(self))
+            }
+
+            #[inline(always)]
+            unsafe fn recip (self, _refine: bool) -> Self {
+                $div($splat(1.0), self)
+            }
+        }
+    };
+}
+
+impl_float! { f32 { f32x4_splat, f32x4_mul, f32x4_div, f32x4_sqrt } }
+impl_float! { f64 { f64x2_splat, f64x2_mul, f64x2_div, f64x2_sqrt } }
@@ -0,0 +1,57 @@
+use core::arch::wasm32::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use half::{f16, bf16};
+use crate::sealed::Selector;
+use super::*;
+
+// There are no half-precision conversions in `simd128`
+impl_widen! {
+    #[cfg(any())]
+    f16
+}
+
+impl_widen! {
+    #[cfg(target_feature = "simd128")]
+    bf16
+}
+
+impl VerticalConvert<bf16> for [f16] {}
+impl VerticalConvert<f16> for [bf16] {}
+
+// Half-precision floats don't have their own comparisons yet
+impl_default! {
+    VerticalCmp =>
+    f16, bf16
+}
+
+impl_sign! {
+    #[cfg(target_feature = "simd128")]
+    f16, bf16
+}
+
+impl_widen_select! {
+    f16, bf16
+}
+
+/* BF16 */
+// A `bf16` is the top half of an `f32`
+impl Widen<bf16> for v128 {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const bf16) -> Self {
+        i32x4_shl(u32x4_extend_low_u16x8(v128_load64_zero(ptr.cast())), 16)
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut bf16) {
+        // Adding just under half an ulp (plus one if the truncated result is odd) rounds ties to even
+        let lsb = v128_and(u32x4_shr(self, 16), u32x4_splat(1));
+        let rounded = i32x4_add(i32x4_add(self, u32x4_splat(0x7fff)), lsb);
+
+        // NaNs are made quiet instead, so that rounding can't carry them into infinities
+        let nan = f32x4_ne(self, self);
+        let x = v128_bitselect(v128_or(self, u32x4_splat(0x00400000)), rounded, nan);
+        let x = i8x16_shuffle::<2, 3, 6, 7, 10, 11, 14, 15, 0, 0, 0, 0, 0, 0, 0, 0>(x, x);
+        v128_store64_lane::<0>(x, ptr.cast())
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use docfg::docfg;
+use crate::sealed::Lane;
+use super::*;
+
+impl_accumulate! {
+    #[cfg(target_feature = "sse")]
+    f32
+}
+
+impl_accumulate! {
+    #[cfg(target_feature = "sse2")]
+    f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+impl_mean! {
+    #[cfg(target_feature = "sse")]
+    f32
+}
+
+impl_mean! {
+    #[cfg(target_feature = "sse2")]
+    f64
+}
+
+impl_lane! {
+    #[cfg(target_feature = "sse")]
+    f32
+}
+
+impl_lane! {
+    #[cfg(target_feature = "sse2")]
+    f64
+}
+
+macro_rules! impl_arith {
+    (
+        #[cfg($meta:meta)]
+        $vec:ty: $($t:ty),+ => $add:ident, $sub:ident
+    ) => {
+        $(
+            #[cfg($meta)]
+            impl Arith<$t> for $vec {
+                #[inline(always)]
+                unsafe fn add (self, rhs: Self) -> Self {
+                    $add(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn sub (self, rhs: Self) -> Self {
+                    $sub(self, rhs)
+                }
+            }
+        )+
+    };
+
+    (
+        #[cfg($meta:meta)]
+        $vec:ty: $t:ty => $add:ident, $sub:ident, $mul:ident, $div:ident
+    ) => {
+        impl_arith! {
+            #[cfg($meta)]
+            $vec: $t => $add, $sub
+        }
+
+        #[cfg($meta)]
+        impl FloatArith<$t> for $vec {
+            #[inline(always)]
+            unsafe fn mul (self, rhs: Self) -> Self {
+                $mul(self, rhs)
+            }
+
+            #[inline(always)]
+            unsafe fn div (self, rhs: Self) -> Self {
+                $div(self, rhs)
+            }
+        }
+    };
+}
+
+/* FLOATS */
+impl_arith! {
+    #[cfg(target_feature = "sse")]
+    __m128: f32 => _mm_add_ps, _mm_sub_ps, _mm_mul_ps, _mm_div_ps
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx")]
+    __m256: f32 => _mm256_add_ps, _mm256_sub_ps, _mm256_mul_ps, _mm256_div_ps
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx512f")]
+    __m512: f32 => _mm512_add_ps, _mm512_sub_ps, _mm512_mul_ps, _mm512_div_ps
+}
+
+impl_arith! {
+    #[cfg(target_feature = "sse2")]
+    __m128d: f64 => _mm_add_pd, _mm_sub_pd, _mm_mul_pd, _mm_div_pd
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx")]
+    __m256d: f64 => _mm256_add_pd, _mm256_sub_pd, _mm256_mul_pd, _mm256_div_pd
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx512f")]
+    __m512d: f64 => _mm512_add_pd, _mm512_sub_pd, _mm512_mul_pd, _mm512_div_pd
+}
+
+/* INTEGERS */
+impl_arith! {
+    #[cfg(target_feature = "sse2")]
+    __m128i: i8, u8 => _mm_add_epi8, _mm_sub_epi8
+}
+
+impl_arith! {
+    #[cfg(target_feature = "sse2")]
+    __m128i: i16, u16 => _mm_add_epi16, _mm_sub_epi16
+}
+
+impl_arith! {
+    #[cfg(target_feature = "sse2")]
+    __m128i: i32, u32 => _mm_add_epi32, _mm_sub_epi32
+}
+
+impl_arith! {
+    #[cfg(target_feature = "sse2")]
+    __m128i: i64, u64 => _mm_add_epi64, _mm_sub_epi64
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx2")]
+    __m256i: i8, u8 => _mm256_add_epi8, _mm256_sub_epi8
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx2")]
+    __m256i: i16, u16 => _mm256_add_epi16, _mm256_sub_epi16
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx2")]
+    __m256i: i32, u32 => _mm256_add_epi32, _mm256_sub_epi32
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx2")]
+    __m256i: i64, u64 => _mm256_add_epi64, _mm256_sub_epi64
+}
+
+impl_arith! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    __m512i: i8, u8 => _mm512_add_epi8, _mm512_sub_epi8
+}
+
+impl_arith! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    __m512i: i16, u16 => _mm512_add_epi16, _mm512_sub_epi16
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx512f")]
+    __m512i: i32, u32 => _mm512_add_epi32, _mm512_sub_epi32
+}
+
+impl_arith! {
+    #[cfg(target_feature = "avx512f")]
+    __m512i: i64, u64 => _mm512_add_epi64, _mm512_sub_epi64
+}
@@ -0,0 +1,75 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_bitwise! {
+    #[cfg(target_feature = "sse2")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+macro_rules! impl_register {
+    (
+        #[cfg($meta:meta)]
+        $vec:ty: $and:ident, $or:ident, $xor:ident, $andnot:ident, $ones:expr => $($t:ty),+
+    ) => {
+        $(
+            #[cfg($meta)]
+            impl Bitwise<$t> for $vec {
+                #[inline(always)]
+                unsafe fn and (self, rhs: Self) -> Self {
+                    $and(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn or (self, rhs: Self) -> Self {
+                    $or(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn xor (self, rhs: Self) -> Self {
+                    $xor(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn andnot (self, rhs: Self) -> Self {
+                    // The intrinsics complement their first operand
+                    $andnot(rhs, self)
+                }
+
+                #[inline(always)]
+                unsafe fn not (self) -> Self {
+                    $xor(self, $ones)
+                }
+            }
+        )+
+    };
+}
+
+impl_register! {
+    #[cfg(target_feature = "sse2")]
+    __m128i: _mm_and_si128, _mm_or_si128, _mm_xor_si128, _mm_andnot_si128, _mm_set1_epi32(-1) =>
+    i8, u8, i16, u16, i32, u32, i64, u64
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx2")]
+    __m256i: _mm256_and_si256, _mm256_or_si256, _mm256_xor_si256, _mm256_andnot_si256, _mm256_set1_epi32(-1) =>
+    i8, u8, i16, u16, i32, u32, i64, u64
+}
+
+impl_register! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    __m512i: _mm512_and_si512, _mm512_or_si512, _mm512_xor_si512, _mm512_andnot_si512, _mm512_set1_epi32(-1) =>
+    i8, u8, i16, u16
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx512f")]
+    __m512i: _mm512_and_si512, _mm512_or_si512, _mm512_xor_si512, _mm512_andnot_si512, _mm512_set1_epi32(-1) =>
+    i32, u32, i64, u64
+}
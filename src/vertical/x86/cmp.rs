@@ -0,0 +1,319 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use docfg::docfg;
+use super::*;
+
+impl_cmp! {
+    #[cfg(target_feature = "sse")]
+    f32
+}
+
+impl_cmp! {
+    #[cfg(target_feature = "sse2")]
+    f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* FLOATS */
+macro_rules! impl_float {
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty {
+            $eq:expr, $lt:expr, $le:expr,
+            $movemask:expr
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Compare<$t> for $vec {
+            #[inline(always)]
+            unsafe fn eq (self, rhs: Self) -> u64 {
+                $movemask($eq(self, rhs)) as u64
+            }
+
+            #[inline(always)]
+            unsafe fn lt (self, rhs: Self) -> u64 {
+                $movemask($lt(self, rhs)) as u64
+            }
+
+            #[inline(always)]
+            unsafe fn le (self, rhs: Self) -> u64 {
+                $movemask($le(self, rhs)) as u64
+            }
+        }
+    };
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse")]
+    f32 as __m128 {
+        _mm_cmpeq_ps, _mm_cmplt_ps, _mm_cmple_ps,
+        _mm_movemask_ps
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f32 as __m256 {
+        _mm256_cmp_ps::<_CMP_EQ_OQ>, _mm256_cmp_ps::<_CMP_LT_OQ>, _mm256_cmp_ps::<_CMP_LE_OQ>,
+        _mm256_movemask_ps
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f32 as __m512 {
+        _mm512_cmp_ps_mask::<_CMP_EQ_OQ>, _mm512_cmp_ps_mask::<_CMP_LT_OQ>, _mm512_cmp_ps_mask::<_CMP_LE_OQ>,
+        core::convert::identity
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse2")]
+    f64 as __m128d {
+        _mm_cmpeq_pd, _mm_cmplt_pd, _mm_cmple_pd,
+        _mm_movemask_pd
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f64 as __m256d {
+        _mm256_cmp_pd::<_CMP_EQ_OQ>, _mm256_cmp_pd::<_CMP_LT_OQ>, _mm256_cmp_pd::<_CMP_LE_OQ>,
+        _mm256_movemask_pd
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f64 as __m512d {
+        _mm512_cmp_pd_mask::<_CMP_EQ_OQ>, _mm512_cmp_pd_mask::<_CMP_LT_OQ>, _mm512_cmp_pd_mask::<_CMP_LE_OQ>,
+        core::convert::identity
+    }
+}
+
+/* INTEGERS */
+macro_rules! impl_int {
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty {
+            $eq:expr, $gt:expr,
+            $movemask:expr
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Compare<$t> for $vec {
+            #[inline(always)]
+            unsafe fn eq (self, rhs: Self) -> u64 {
+                $movemask($eq(self, rhs)) as u64
+            }
+
+            #[inline(always)]
+            unsafe fn lt (self, rhs: Self) -> u64 {
+                $movemask($gt(rhs, self)) as u64
+            }
+
+            #[inline(always)]
+            unsafe fn le (self, rhs: Self) -> u64 {
+                !($movemask($gt(self, rhs)) as u64) & <Self as Compare<$t>>::BITS
+            }
+        }
+    };
+
+    // AVX-512 compares straight into masks
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty {
+            mask: $eq:ident, $lt:ident, $le:ident
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Compare<$t> for $vec {
+            #[inline(always)]
+            unsafe fn eq (self, rhs: Self) -> u64 {
+                $eq(self, rhs) as u64
+            }
+
+            #[inline(always)]
+            unsafe fn lt (self, rhs: Self) -> u64 {
+                $lt(self, rhs) as u64
+            }
+
+            #[inline(always)]
+            unsafe fn le (self, rhs: Self) -> u64 {
+                $le(self, rhs) as u64
+            }
+        }
+    };
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i8 as __m128i { _mm_cmpeq_epi8, _mm_cmpgt_epi8, _mm_movemask_epi8 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u8 as __m128i {
+        _mm_cmpeq_epi8,
+        |x, y| _mm_cmpgt_epi8(flip_epi8(x), flip_epi8(y)),
+        _mm_movemask_epi8
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i16 as __m128i { _mm_cmpeq_epi16, _mm_cmpgt_epi16, movemask_epi16_128 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u16 as __m128i {
+        _mm_cmpeq_epi16,
+        |x, y| _mm_cmpgt_epi16(flip_epi16(x), flip_epi16(y)),
+        movemask_epi16_128
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i32 as __m128i { _mm_cmpeq_epi32, _mm_cmpgt_epi32, |x| _mm_movemask_ps(_mm_castsi128_ps(x)) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u32 as __m128i {
+        _mm_cmpeq_epi32,
+        |x, y| _mm_cmpgt_epi32(flip_epi32(x), flip_epi32(y)),
+        |x| _mm_movemask_ps(_mm_castsi128_ps(x))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i64 as __m128i { cmpeq_epi64_128, cmpgt_epi64_128, |x| _mm_movemask_pd(_mm_castsi128_pd(x)) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u64 as __m128i {
+        cmpeq_epi64_128,
+        |x, y| cmpgt_epi64_128(flip_epi64(x), flip_epi64(y)),
+        |x| _mm_movemask_pd(_mm_castsi128_pd(x))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i8 as __m256i { _mm256_cmpeq_epi8, _mm256_cmpgt_epi8, |x| _mm256_movemask_epi8(x) as u32 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    u8 as __m256i {
+        _mm256_cmpeq_epi8,
+        |x, y| _mm256_cmpgt_epi8(_mm256_xor_si256(x, _mm256_set1_epi8(i8::MIN)), _mm256_xor_si256(y, _mm256_set1_epi8(i8::MIN))),
+        |x| _mm256_movemask_epi8(x) as u32
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i16 as __m256i { _mm256_cmpeq_epi16, _mm256_cmpgt_epi16, movemask_epi16_256 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    u16 as __m256i {
+        _mm256_cmpeq_epi16,
+        |x, y| _mm256_cmpgt_epi16(_mm256_xor_si256(x, _mm256_set1_epi16(i16::MIN)), _mm256_xor_si256(y, _mm256_set1_epi16(i16::MIN))),
+        movemask_epi16_256
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i32 as __m256i { _mm256_cmpeq_epi32, _mm256_cmpgt_epi32, |x| _mm256_movemask_ps(_mm256_castsi256_ps(x)) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    u32 as __m256i {
+        _mm256_cmpeq_epi32,
+        |x, y| _mm256_cmpgt_epi32(_mm256_xor_si256(x, _mm256_set1_epi32(i32::MIN)), _mm256_xor_si256(y, _mm256_set1_epi32(i32::MIN))),
+        |x| _mm256_movemask_ps(_mm256_castsi256_ps(x))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i64 as __m256i { _mm256_cmpeq_epi64, _mm256_cmpgt_epi64, |x| _mm256_movemask_pd(_mm256_castsi256_pd(x)) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    u64 as __m256i {
+        _mm256_cmpeq_epi64,
+        |x, y| _mm256_cmpgt_epi64(_mm256_xor_si256(x, _mm256_set1_epi64x(i64::MIN)), _mm256_xor_si256(y, _mm256_set1_epi64x(i64::MIN))),
+        |x| _mm256_movemask_pd(_mm256_castsi256_pd(x))
+    }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i8 as __m512i { mask: _mm512_cmpeq_epi8_mask, _mm512_cmplt_epi8_mask, _mm512_cmple_epi8_mask }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    u8 as __m512i { mask: _mm512_cmpeq_epi8_mask, _mm512_cmplt_epu8_mask, _mm512_cmple_epu8_mask }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i16 as __m512i { mask: _mm512_cmpeq_epi16_mask, _mm512_cmplt_epi16_mask, _mm512_cmple_epi16_mask }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    u16 as __m512i { mask: _mm512_cmpeq_epi16_mask, _mm512_cmplt_epu16_mask, _mm512_cmple_epu16_mask }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    i32 as __m512i { mask: _mm512_cmpeq_epi32_mask, _mm512_cmplt_epi32_mask, _mm512_cmple_epi32_mask }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    u32 as __m512i { mask: _mm512_cmpeq_epi32_mask, _mm512_cmplt_epu32_mask, _mm512_cmple_epu32_mask }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    i64 as __m512i { mask: _mm512_cmpeq_epi64_mask, _mm512_cmplt_epi64_mask, _mm512_cmple_epi64_mask }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    u64 as __m512i { mask: _mm512_cmpeq_epi64_mask, _mm512_cmplt_epu64_mask, _mm512_cmple_epu64_mask }
+}
+
+/// Bitmask of the sign bits of every 16-bit lane
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+unsafe fn movemask_epi16_128 (x: __m128i) -> i32 {
+    // Comparison results saturate into bytes
+    _mm_movemask_epi8(_mm_packs_epi16(x, _mm_setzero_si128()))
+}
+
+/// Bitmask of the sign bits of every 16-bit lane
+#[cfg(target_feature = "avx2")]
+#[inline(always)]
+unsafe fn movemask_epi16_256 (x: __m256i) -> u32 {
+    // Packing works within each 128-bit half, leaving their bytes on bits 0..8 and 16..24
+    let bits = _mm256_movemask_epi8(_mm256_packs_epi16(x, _mm256_setzero_si256())) as u32;
+    (bits & 0xff) | ((bits >> 8) & 0xff00)
+}
@@ -0,0 +1,229 @@
+//! Emulation of instructions missing from older extensions
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// Selects `y` on the lanes where `mask` is set, and `x` otherwise.
+#[inline(always)]
+pub(super) unsafe fn blendv_ps_128 (x: __m128, y: __m128, mask: __m128) -> __m128 {
+    #[cfg(target_feature = "sse4.1")]
+    return _mm_blendv_ps(x, y, mask);
+    #[cfg(not(target_feature = "sse4.1"))]
+    return _mm_or_ps(_mm_and_ps(mask, y), _mm_andnot_ps(mask, x));
+}
+
+/// Selects `y` on the lanes where `mask` is set, and `x` otherwise.
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn blendv_pd_128 (x: __m128d, y: __m128d, mask: __m128d) -> __m128d {
+    #[cfg(target_feature = "sse4.1")]
+    return _mm_blendv_pd(x, y, mask);
+    #[cfg(not(target_feature = "sse4.1"))]
+    return _mm_or_pd(_mm_and_pd(mask, y), _mm_andnot_pd(mask, x));
+}
+
+/// Selects `y` on the bytes where `mask` is set, and `x` otherwise.
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn blendv_epi8_128 (x: __m128i, y: __m128i, mask: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    return _mm_blendv_epi8(x, y, mask);
+    #[cfg(not(target_feature = "sse4.1"))]
+    return _mm_or_si128(_mm_and_si128(mask, y), _mm_andnot_si128(mask, x));
+}
+
+/// Minimum given the result of `x > y`
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn cmp_min_128 (x: __m128i, y: __m128i, gt: __m128i) -> __m128i {
+    blendv_epi8_128(x, y, gt)
+}
+
+/// Maximum given the result of `x > y`
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn cmp_max_128 (x: __m128i, y: __m128i, gt: __m128i) -> __m128i {
+    blendv_epi8_128(y, x, gt)
+}
+
+/// Signed 64-bit `x > y`, emulated through 32-bit comparisons before SSE4.2
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn cmpgt_epi64_128 (x: __m128i, y: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.2")]
+    return _mm_cmpgt_epi64(x, y);
+
+    #[cfg(not(target_feature = "sse4.2"))]
+    {
+        // If the high halves are equal, the sign of `y - x` tells us how the (unsigned) low halves compare
+        let gt = _mm_or_si128(
+            _mm_and_si128(_mm_cmpeq_epi32(x, y), _mm_sub_epi64(y, x)),
+            _mm_cmpgt_epi32(x, y)
+        );
+        // Broadcast the high half of every result: [3, 3, 1, 1]
+        return _mm_shuffle_epi32(gt, 0b11_11_01_01);
+    }
+}
+
+/// Signed 64-bit `x == y`, emulated through 32-bit comparisons before SSE4.1
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn cmpeq_epi64_128 (x: __m128i, y: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    return _mm_cmpeq_epi64(x, y);
+
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        // Both halves must be equal: [1, 0, 3, 2]
+        let eq = _mm_cmpeq_epi32(x, y);
+        return _mm_and_si128(eq, _mm_shuffle_epi32(eq, 0b10_11_00_01));
+    }
+}
+
+/// Flips the sign bit of every 8-bit lane, mapping unsigned order into signed order.
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn flip_epi8 (x: __m128i) -> __m128i {
+    _mm_xor_si128(x, _mm_set1_epi8(i8::MIN))
+}
+
+/// Flips the sign bit of every 16-bit lane, mapping unsigned order into signed order.
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn flip_epi16 (x: __m128i) -> __m128i {
+    _mm_xor_si128(x, _mm_set1_epi16(i16::MIN))
+}
+
+/// Flips the sign bit of every 32-bit lane, mapping unsigned order into signed order.
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn flip_epi32 (x: __m128i) -> __m128i {
+    _mm_xor_si128(x, _mm_set1_epi32(i32::MIN))
+}
+
+/// Flips the sign bit of every 64-bit lane, mapping unsigned order into signed order.
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+pub(super) unsafe fn flip_epi64 (x: __m128i) -> __m128i {
+    _mm_xor_si128(x, _mm_set1_epi64x(i64::MIN))
+}
+
+/// Flips the sign bit of every 64-bit lane, mapping unsigned order into signed order.
+#[cfg(all(target_feature = "avx2", not(target_feature = "avx512vl")))]
+#[inline(always)]
+pub(super) unsafe fn flip_epi64_256 (x: __m256i) -> __m256i {
+    _mm256_xor_si256(x, _mm256_set1_epi64x(i64::MIN))
+}
+
+macro_rules! impl_bitwise_512 {
+    ($($name:ident = $native:ident | $int:ident for $vec:ty: $to_int:ident & $from_int:ident),+ $(,)?) => {
+        $(
+            /// Bitwise operation on floating point lanes, which needs AVX512DQ natively
+            #[cfg(target_feature = "avx512f")]
+            #[inline(always)]
+            pub(super) unsafe fn $name (x: $vec, y: $vec) -> $vec {
+                #[cfg(target_feature = "avx512dq")]
+                return $native(x, y);
+                #[cfg(not(target_feature = "avx512dq"))]
+                return $from_int($int($to_int(x), $to_int(y)));
+            }
+        )+
+    };
+}
+
+impl_bitwise_512! {
+    and_ps_512 = _mm512_and_ps | _mm512_and_si512 for __m512: _mm512_castps_si512 & _mm512_castsi512_ps,
+    andnot_ps_512 = _mm512_andnot_ps | _mm512_andnot_si512 for __m512: _mm512_castps_si512 & _mm512_castsi512_ps,
+    or_ps_512 = _mm512_or_ps | _mm512_or_si512 for __m512: _mm512_castps_si512 & _mm512_castsi512_ps,
+    xor_ps_512 = _mm512_xor_ps | _mm512_xor_si512 for __m512: _mm512_castps_si512 & _mm512_castsi512_ps,
+    and_pd_512 = _mm512_and_pd | _mm512_and_si512 for __m512d: _mm512_castpd_si512 & _mm512_castsi512_pd,
+    andnot_pd_512 = _mm512_andnot_pd | _mm512_andnot_si512 for __m512d: _mm512_castpd_si512 & _mm512_castsi512_pd,
+    or_pd_512 = _mm512_or_pd | _mm512_or_si512 for __m512d: _mm512_castpd_si512 & _mm512_castsi512_pd,
+    xor_pd_512 = _mm512_xor_pd | _mm512_xor_si512 for __m512d: _mm512_castpd_si512 & _mm512_castsi512_pd,
+}
+
+macro_rules! impl_round_128 {
+    (
+        $(#[cfg($meta:meta)])?
+        $name:ident = $native:ident for $vec:ty {
+            $magic:literal, $splat:ident, $and:ident, $andnot:ident, $or:ident,
+            $add:ident, $sub:ident, $cmplt:ident, $cmpgt:ident, $blend:ident
+        }
+    ) => {
+        /// Rounds every lane to an integer according to `ROUNDING`, emulated through the lanes' mantissa before SSE4.1
+        $(#[cfg($meta)])?
+        #[inline(always)]
+        pub(super) unsafe fn $name<const ROUNDING: i32> (x: $vec) -> $vec {
+            #[cfg(target_feature = "sse4.1")]
+            return $native::<ROUNDING>(x);
+
+            #[cfg(not(target_feature = "sse4.1"))]
+            {
+                let sign = $and(x, $splat(-0.0));
+                let abs = $andnot($splat(-0.0), x);
+                let one = $splat(1.0);
+
+                // Adding and subtracting 2^mantissa rounds away the fractional bits (to nearest, with ties to even).
+                // Larger values, infinities and NaN are already integral.
+                let magic = $splat($magic);
+                let even = $blend(abs, $sub($add(abs, magic), magic), $cmplt(abs, magic));
+
+                let r = match ROUNDING & 0b11 {
+                    _MM_FROUND_TO_NEAREST_INT => even,
+                    _MM_FROUND_TO_NEG_INF => {
+                        let even = $or(even, sign);
+                        $sub(even, $and($cmpgt(even, x), one))
+                    },
+                    _MM_FROUND_TO_POS_INF => {
+                        let even = $or(even, sign);
+                        $add(even, $and($cmplt(even, x), one))
+                    },
+                    _ => $sub(even, $and($cmpgt(even, abs), one))
+                };
+
+                // Rounding never changes the sign, so this also fixes up zeros
+                return $or(r, sign);
+            }
+        }
+    };
+}
+
+impl_round_128! {
+    round_ps_128 = _mm_round_ps for __m128 {
+        8388608.0, _mm_set1_ps, _mm_and_ps, _mm_andnot_ps, _mm_or_ps,
+        _mm_add_ps, _mm_sub_ps, _mm_cmplt_ps, _mm_cmpgt_ps, blendv_ps_128
+    }
+}
+
+impl_round_128! {
+    #[cfg(target_feature = "sse2")]
+    round_pd_128 = _mm_round_pd for __m128d {
+        4503599627370496.0, _mm_set1_pd, _mm_and_pd, _mm_andnot_pd, _mm_or_pd,
+        _mm_add_pd, _mm_sub_pd, _mm_cmplt_pd, _mm_cmpgt_pd, blendv_pd_128
+    }
+}
+
+macro_rules! impl_shift_256 {
+    ($($name:ident = $native:ident | $half:ident),+ $(,)?) => {
+        $(
+            /// Shifts every lane by `count` bits, through both 128-bit halves before AVX2
+            #[cfg(target_feature = "avx")]
+            #[inline(always)]
+            pub(super) unsafe fn $name (x: __m256i, count: __m128i) -> __m256i {
+                #[cfg(target_feature = "avx2")]
+                return $native(x, count);
+                #[cfg(not(target_feature = "avx2"))]
+                return _mm256_set_m128i($half(_mm256_extractf128_si256::<1>(x), count), $half(_mm256_castsi256_si128(x), count));
+            }
+        )+
+    };
+}
+
+impl_shift_256! {
+    sll_epi32_256 = _mm256_sll_epi32 | _mm_sll_epi32,
+    srl_epi32_256 = _mm256_srl_epi32 | _mm_srl_epi32,
+    sll_epi64_256 = _mm256_sll_epi64 | _mm_sll_epi64,
+    srl_epi64_256 = _mm256_srl_epi64 | _mm_srl_epi64,
+}
@@ -0,0 +1,734 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+// Conversions go through blocks of 32-bit lanes, so that every pair of element types shares the same block size.
+// With AVX2, the 128-bit blocks are kept around for when the width is capped.
+
+/// Runs `$body` with the blocks (and helpers) of the widest registers for `$t` allowed by the runtime cap on their
+/// width (see [`width`](crate::width)).
+macro_rules! blocks {
+    ($t:ty => $body:expr) => {
+        match crate::width::tier::<$t>() {
+            #[cfg(target_feature = "avx2")]
+            crate::width::Tier::W128 => {
+                use sse2::*;
+                $body
+            },
+            _ => {
+                #[cfg(target_feature = "avx2")]
+                use avx2::*;
+                #[cfg(not(target_feature = "avx2"))]
+                use sse2::*;
+                $body
+            }
+        }
+    };
+}
+
+/// Defines [`Lanes`](sse2::Lanes) in the invoking module, on top of it's block types and helpers.
+macro_rules! block_lanes {
+    () => {
+        /// Integers of up to 32 bits, converted through a block of 32-bit lanes.
+        pub(super) trait Lanes: Copy {
+            const MIN: f64;
+            const MAX: f64;
+
+            /// Loads a block of elements, extended to 32 bits with `as` semantics (unsigned 32-bit elements keep their bits).
+            unsafe fn load (ptr: *const Self) -> Epi32;
+            /// Stores a block of 32-bit lanes, truncated to the element type.
+            unsafe fn store (v: Epi32, ptr: *mut Self);
+
+            /// Converts lanes loaded from this element type to floats.
+            #[inline(always)]
+            unsafe fn to_ps (v: Epi32) -> Ps {
+                cvtepi32_ps(v)
+            }
+
+            #[inline(always)]
+            unsafe fn to_pd (v: Epi32) -> Pd {
+                cvtepi32_pd(v)
+            }
+
+            /// Extends lanes loaded from this element type to 64 bits.
+            #[inline(always)]
+            unsafe fn to_epi64 (v: Epi32) -> Epi64 {
+                cvtepi32_epi64(v)
+            }
+
+            /// Converts floats to this element type, rounding towards zero and saturating (with NaN converting to zero).
+            #[inline(always)]
+            unsafe fn from_ps (v: Ps) -> Epi32 {
+                let v = clamp_ps(v, Self::MIN as f32, Self::MAX as f32);
+                let res = cvttps_epi32(v);
+
+                // `i32::MAX` rounds up to 2^31 as a float, which converts to `i32::MIN`
+                if Self::MAX as f32 as f64 > Self::MAX {
+                    return flip_overflow(res, v)
+                }
+                return res
+            }
+
+            #[inline(always)]
+            unsafe fn from_pd (v: Pd) -> Epi32 {
+                cvttpd_epi32(clamp_pd(v, <Self as Lanes>::MIN, <Self as Lanes>::MAX))
+            }
+        }
+    };
+}
+
+macro_rules! impl_convert {
+    ($($a:ident),+ => $bs:tt as |$src:ident, $dst:ident| $f:expr) => {
+        $(
+            impl_convert! { @row $a => $bs as |$src, $dst| $f }
+        )+
+    };
+
+    (@row $a:ident => [$($b:ident),+] as |$src:ident, $dst:ident| $f:expr) => {
+        $(
+            #[cfg(not(target_feature = "sse2"))]
+            impl VerticalConvert<$b> for [$a] {}
+
+            #[docfg(target_feature = "sse2")]
+            impl SimdVerticalConvert<$b> for [$a] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$b>]) {
+                    type A = $a;
+                    type B = $b;
+                    blocks!($a => convert_raw::<BLOCK, A, B>(self.as_ptr(), out.as_mut_ptr().cast(), self.len(), |$src, $dst| $f))
+                }
+            }
+        )+
+    };
+
+    // Conversions that only copy bits around
+    (copy: $($a:ident),+ => $bs:tt) => {
+        $(
+            impl_convert! { @copy $a => $bs }
+        )+
+    };
+
+    (@copy $a:ident => [$($b:ident),+]) => {
+        $(
+            impl SimdVerticalConvert<$b> for [$a] {
+                #[inline]
+                unsafe fn convert_into_unchecked (&self, out: &mut [MaybeUninit<$b>]) {
+                    core::ptr::copy_nonoverlapping(self.as_ptr().cast::<$b>(), out.as_mut_ptr().cast(), self.len())
+                }
+            }
+        )+
+    };
+
+    (scalar: $($a:ident),+ => $bs:tt) => {
+        $(
+            impl_convert! { @scalar $a => $bs }
+        )+
+    };
+
+    (@scalar $a:ident => [$($b:ident),+]) => {
+        $(
+            impl VerticalConvert<$b> for [$a] {}
+        )+
+    };
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(A::load(src), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [f32]
+    as |src, dst| store_ps(A::to_ps(A::load(src)), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [f64]
+    as |src, dst| store_pd(A::to_pd(A::load(src)), dst)
+}
+
+impl_convert! {
+    i8, u8, i16, u16, i32, u32 => [i64, u64]
+    as |src, dst| store_epi64(A::to_epi64(A::load(src)), dst.cast())
+}
+
+impl_convert! {
+    f32 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(B::from_ps(load_ps(src)), dst)
+}
+
+impl_convert! {
+    f64 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(B::from_pd(load_pd(src)), dst)
+}
+
+impl_convert! {
+    i64, u64 => [i8, u8, i16, u16, i32, u32]
+    as |src, dst| B::store(narrow_epi64(load_epi64(src.cast())), dst)
+}
+
+impl_convert! {
+    f32 => [f64]
+    as |src, dst| store_pd(cvtps_pd(load_ps(src)), dst)
+}
+
+impl_convert! {
+    f64 => [f32]
+    as |src, dst| store_ps(cvtpd_ps(load_pd(src)), dst)
+}
+
+impl_convert! {
+    copy: i64, u64 => [i64, u64]
+}
+
+impl_convert! {
+    copy: f32 => [f32]
+}
+
+impl_convert! {
+    copy: f64 => [f64]
+}
+
+// There are no conversions between 64-bit integers and floats before AVX-512DQ
+impl_convert! {
+    scalar: i64, u64 => [f32, f64]
+}
+
+impl_convert! {
+    scalar: f32, f64 => [i64, u64]
+}
+
+/// Converts `len` elements from `src` into `dst`, one block at a time.
+///
+/// The trailing elements are padded with zeros into a full block.
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+unsafe fn convert_raw<const BLOCK: usize, A: Copy, B: Copy> (src: *const A, dst: *mut B, len: usize, f: impl Fn(*const A, *mut B)) {
+    let mut i = 0;
+    while i + BLOCK <= len {
+        f(src.add(i), dst.add(i));
+        i += BLOCK;
+    }
+
+    if i < len {
+        let rem = len - i;
+        let mut lhs = MaybeUninit::<[A; BLOCK]>::zeroed();
+        let mut rhs = MaybeUninit::<[B; BLOCK]>::uninit();
+        core::ptr::copy_nonoverlapping(src.add(i), lhs.as_mut_ptr().cast::<A>(), rem);
+        f(lhs.as_ptr().cast(), rhs.as_mut_ptr().cast());
+        core::ptr::copy_nonoverlapping(rhs.as_ptr().cast::<B>(), dst.add(i), rem);
+    }
+}
+
+macro_rules! impl_lanes {
+    (
+        $t:ident {
+            load: |$load_ptr:ident| $load:expr,
+            store: |$store_v:ident, $store_ptr:ident| $store:expr
+        }
+    ) => {
+        impl Lanes for $t {
+            const MIN: f64 = $t::MIN as f64;
+            const MAX: f64 = $t::MAX as f64;
+
+            #[inline(always)]
+            unsafe fn load ($load_ptr: *const Self) -> Epi32 {
+                $load
+            }
+
+            #[inline(always)]
+            unsafe fn store ($store_v: Epi32, $store_ptr: *mut Self) {
+                $store
+            }
+        }
+    };
+}
+
+/// Blocks of 8 elements, in 256-bit registers.
+#[cfg(target_feature = "avx2")]
+mod avx2 {
+    use super::*;
+
+    /// Number of elements converted at once
+    pub(super) const BLOCK: usize = 8;
+    pub(super) type Epi32 = __m256i;
+    pub(super) type Ps = __m256;
+    /// A block of 64-bit lanes, split into two registers
+    pub(super) type Pd = [__m256d; 2];
+    pub(super) type Epi64 = [__m256i; 2];
+
+    block_lanes!();
+
+    /* INTEGERS */
+    impl_lanes! {
+        i8 {
+            load: |ptr| _mm256_cvtepi8_epi32(_mm_loadl_epi64(ptr.cast())),
+            store: |v, ptr| store_epi8(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        u8 {
+            load: |ptr| _mm256_cvtepu8_epi32(_mm_loadl_epi64(ptr.cast())),
+            store: |v, ptr| store_epi8(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        i16 {
+            load: |ptr| _mm256_cvtepi16_epi32(_mm_loadu_si128(ptr.cast())),
+            store: |v, ptr| store_epi16(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        u16 {
+            load: |ptr| _mm256_cvtepu16_epi32(_mm_loadu_si128(ptr.cast())),
+            store: |v, ptr| store_epi16(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        i32 {
+            load: |ptr| _mm256_loadu_si256(ptr.cast()),
+            store: |v, ptr| _mm256_storeu_si256(ptr.cast(), v)
+        }
+    }
+
+    /// Truncates a block of 32-bit lanes to bytes.
+    #[inline(always)]
+    unsafe fn store_epi8 (v: Epi32, ptr: *mut i8) {
+        // Masking keeps the saturating packs from clamping
+        let v = _mm256_and_si256(v, _mm256_set1_epi32(0xff));
+        let v = _mm256_packs_epi32(v, v);
+        let v = _mm256_packus_epi16(v, v);
+        let v = _mm_unpacklo_epi32(_mm256_castsi256_si128(v), _mm256_extracti128_si256::<1>(v));
+        _mm_storel_epi64(ptr.cast(), v)
+    }
+
+    /// Truncates a block of 32-bit lanes to 16 bits.
+    #[inline(always)]
+    unsafe fn store_epi16 (v: Epi32, ptr: *mut i16) {
+        // Sign-extending the low half keeps the saturating pack from clamping
+        let v = _mm256_srai_epi32::<16>(_mm256_slli_epi32::<16>(v));
+        let v = _mm256_permute4x64_epi64::<0b00_00_10_00>(_mm256_packs_epi32(v, v));
+        _mm_storeu_si128(ptr.cast(), _mm256_castsi256_si128(v))
+    }
+
+    impl Lanes for u32 {
+        const MIN: f64 = u32::MIN as f64;
+        const MAX: f64 = u32::MAX as f64;
+
+        #[inline(always)]
+        unsafe fn load (ptr: *const Self) -> Epi32 {
+            i32::load(ptr.cast())
+        }
+
+        #[inline(always)]
+        unsafe fn store (v: Epi32, ptr: *mut Self) {
+            i32::store(v, ptr.cast())
+        }
+
+        #[inline(always)]
+        unsafe fn to_ps (v: Epi32) -> Ps {
+            // Both halves convert exactly, so the sum is rounded only once
+            let hi = _mm256_cvtepi32_ps(_mm256_srli_epi32::<16>(v));
+            let lo = _mm256_cvtepi32_ps(_mm256_and_si256(v, _mm256_set1_epi32(0xffff)));
+            return _mm256_add_ps(_mm256_mul_ps(hi, _mm256_set1_ps(65536.0)), lo)
+        }
+
+        #[inline(always)]
+        unsafe fn to_pd (v: Epi32) -> Pd {
+            // Lanes past `i32::MAX` convert as negative numbers, 2^32 below their value
+            return cvtepi32_pd(v).map(|x| {
+                let neg = _mm256_cmp_pd::<_CMP_LT_OQ>(x, _mm256_setzero_pd());
+                _mm256_add_pd(x, _mm256_and_pd(neg, _mm256_set1_pd(4294967296.0)))
+            })
+        }
+
+        #[inline(always)]
+        unsafe fn to_epi64 (v: Epi32) -> Epi64 {
+            return [
+                _mm256_cvtepu32_epi64(_mm256_castsi256_si128(v)),
+                _mm256_cvtepu32_epi64(_mm256_extracti128_si256::<1>(v))
+            ]
+        }
+
+        #[inline(always)]
+        unsafe fn from_ps (v: Ps) -> Epi32 {
+            // Lanes past `i32::MAX` are converted 2^31 lower, and get their top bit set back afterwards
+            let v = _mm256_max_ps(v, _mm256_setzero_ps());
+            let big = _mm256_cmp_ps::<_CMP_GE_OQ>(v, _mm256_set1_ps(2147483648.0));
+            let over = _mm256_cmp_ps::<_CMP_GE_OQ>(v, _mm256_set1_ps(4294967296.0));
+            let res = _mm256_cvttps_epi32(_mm256_sub_ps(v, _mm256_and_ps(big, _mm256_set1_ps(2147483648.0))));
+            let res = _mm256_xor_si256(res, _mm256_and_si256(_mm256_castps_si256(big), _mm256_set1_epi32(i32::MIN)));
+            return _mm256_or_si256(res, _mm256_castps_si256(over))
+        }
+
+        #[inline(always)]
+        unsafe fn from_pd (v: Pd) -> Epi32 {
+            // Same as `from_ps`, with the top bits computed as `-2^31` converted on the lanes past `i32::MAX`
+            let big = clamp_pd(v, <Self as Lanes>::MIN, <Self as Lanes>::MAX).map(|x| _mm256_and_pd(
+                _mm256_cmp_pd::<_CMP_GE_OQ>(x, _mm256_set1_pd(2147483648.0)),
+                _mm256_set1_pd(-2147483648.0)
+            ));
+            let v = clamp_pd(v, <Self as Lanes>::MIN, <Self as Lanes>::MAX);
+            let low = cvttpd_epi32([_mm256_add_pd(v[0], big[0]), _mm256_add_pd(v[1], big[1])]);
+            return _mm256_xor_si256(low, cvttpd_epi32(big))
+        }
+    }
+
+    /* FLOATS */
+    #[inline(always)]
+    pub(super) unsafe fn load_ps (ptr: *const f32) -> Ps {
+        _mm256_loadu_ps(ptr)
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn store_ps (v: Ps, ptr: *mut f32) {
+        _mm256_storeu_ps(ptr, v)
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn load_pd (ptr: *const f64) -> Pd {
+        [_mm256_loadu_pd(ptr), _mm256_loadu_pd(ptr.add(4))]
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn store_pd (v: Pd, ptr: *mut f64) {
+        _mm256_storeu_pd(ptr, v[0]);
+        _mm256_storeu_pd(ptr.add(4), v[1])
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn load_epi64 (ptr: *const i64) -> Epi64 {
+        [_mm256_loadu_si256(ptr.cast()), _mm256_loadu_si256(ptr.add(4).cast())]
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn store_epi64 (v: Epi64, ptr: *mut i64) {
+        _mm256_storeu_si256(ptr.cast(), v[0]);
+        _mm256_storeu_si256(ptr.add(4).cast(), v[1])
+    }
+
+    #[inline(always)]
+    unsafe fn cvtepi32_ps (v: Epi32) -> Ps {
+        _mm256_cvtepi32_ps(v)
+    }
+
+    #[inline(always)]
+    unsafe fn cvttps_epi32 (v: Ps) -> Epi32 {
+        _mm256_cvttps_epi32(v)
+    }
+
+    #[inline(always)]
+    unsafe fn cvtepi32_pd (v: Epi32) -> Pd {
+        [_mm256_cvtepi32_pd(_mm256_castsi256_si128(v)), _mm256_cvtepi32_pd(_mm256_extracti128_si256::<1>(v))]
+    }
+
+    #[inline(always)]
+    unsafe fn cvttpd_epi32 (v: Pd) -> Epi32 {
+        _mm256_setr_m128i(_mm256_cvttpd_epi32(v[0]), _mm256_cvttpd_epi32(v[1]))
+    }
+
+    #[inline(always)]
+    unsafe fn cvtepi32_epi64 (v: Epi32) -> Epi64 {
+        [_mm256_cvtepi32_epi64(_mm256_castsi256_si128(v)), _mm256_cvtepi32_epi64(_mm256_extracti128_si256::<1>(v))]
+    }
+
+    /// Truncates a block of 64-bit lanes to 32 bits.
+    #[inline(always)]
+    pub(super) unsafe fn narrow_epi64 (v: Epi64) -> Epi32 {
+        // Picking the low halves works within 128-bit lanes, which leaves the 64-bit pairs out of order
+        let v = _mm256_shuffle_ps::<0b10_00_10_00>(_mm256_castsi256_ps(v[0]), _mm256_castsi256_ps(v[1]));
+        _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_castps_si256(v))
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn cvtps_pd (v: Ps) -> Pd {
+        [_mm256_cvtps_pd(_mm256_castps256_ps128(v)), _mm256_cvtps_pd(_mm256_extractf128_ps::<1>(v))]
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn cvtpd_ps (v: Pd) -> Ps {
+        _mm256_setr_m128(_mm256_cvtpd_ps(v[0]), _mm256_cvtpd_ps(v[1]))
+    }
+
+    /// Turns the lanes of `res` where `v` reached 2^31 into `i32::MAX`.
+    #[inline(always)]
+    unsafe fn flip_overflow (res: Epi32, v: Ps) -> Epi32 {
+        let over = _mm256_cmp_ps::<_CMP_GE_OQ>(v, _mm256_set1_ps(2147483648.0));
+        _mm256_xor_si256(res, _mm256_castps_si256(over))
+    }
+
+    /// Clamps `v` between `lo` and `hi`, turning NaN lanes into zeros.
+    #[inline(always)]
+    unsafe fn clamp_ps (v: Ps, lo: f32, hi: f32) -> Ps {
+        let v = _mm256_and_ps(v, _mm256_cmp_ps::<_CMP_ORD_Q>(v, v));
+        _mm256_min_ps(_mm256_max_ps(v, _mm256_set1_ps(lo)), _mm256_set1_ps(hi))
+    }
+
+    /// Clamps `v` between `lo` and `hi`, turning NaN lanes into zeros.
+    #[inline(always)]
+    unsafe fn clamp_pd (v: Pd, lo: f64, hi: f64) -> Pd {
+        v.map(|v| {
+            let v = _mm256_and_pd(v, _mm256_cmp_pd::<_CMP_ORD_Q>(v, v));
+            _mm256_min_pd(_mm256_max_pd(v, _mm256_set1_pd(lo)), _mm256_set1_pd(hi))
+        })
+    }
+}
+
+/// Blocks of 4 elements, in 128-bit registers.
+#[cfg(target_feature = "sse2")]
+mod sse2 {
+    use super::*;
+
+    /// Number of elements converted at once
+    pub(super) const BLOCK: usize = 4;
+    pub(super) type Epi32 = __m128i;
+    pub(super) type Ps = __m128;
+    /// A block of 64-bit lanes, split into two registers
+    pub(super) type Pd = [__m128d; 2];
+    pub(super) type Epi64 = [__m128i; 2];
+
+    block_lanes!();
+
+    /* INTEGERS */
+    impl_lanes! {
+        i8 {
+            load: |ptr| {
+                let v = _mm_cvtsi32_si128(ptr.cast::<i32>().read_unaligned());
+                #[cfg(target_feature = "sse4.1")]
+                return _mm_cvtepi8_epi32(v);
+                #[cfg(not(target_feature = "sse4.1"))]
+                return _mm_srai_epi32::<24>(_mm_unpacklo_epi16(_mm_unpacklo_epi8(v, v), _mm_unpacklo_epi8(v, v)));
+            },
+            store: |v, ptr| store_epi8(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        u8 {
+            load: |ptr| {
+                let v = _mm_cvtsi32_si128(ptr.cast::<i32>().read_unaligned());
+                #[cfg(target_feature = "sse4.1")]
+                return _mm_cvtepu8_epi32(v);
+                #[cfg(not(target_feature = "sse4.1"))]
+                return _mm_unpacklo_epi16(_mm_unpacklo_epi8(v, _mm_setzero_si128()), _mm_setzero_si128());
+            },
+            store: |v, ptr| store_epi8(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        i16 {
+            load: |ptr| {
+                let v = _mm_loadl_epi64(ptr.cast());
+                #[cfg(target_feature = "sse4.1")]
+                return _mm_cvtepi16_epi32(v);
+                #[cfg(not(target_feature = "sse4.1"))]
+                return _mm_srai_epi32::<16>(_mm_unpacklo_epi16(v, v));
+            },
+            store: |v, ptr| store_epi16(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        u16 {
+            load: |ptr| {
+                let v = _mm_loadl_epi64(ptr.cast());
+                #[cfg(target_feature = "sse4.1")]
+                return _mm_cvtepu16_epi32(v);
+                #[cfg(not(target_feature = "sse4.1"))]
+                return _mm_unpacklo_epi16(v, _mm_setzero_si128());
+            },
+            store: |v, ptr| store_epi16(v, ptr.cast())
+        }
+    }
+
+    impl_lanes! {
+        i32 {
+            load: |ptr| _mm_loadu_si128(ptr.cast()),
+            store: |v, ptr| _mm_storeu_si128(ptr.cast(), v)
+        }
+    }
+
+    /// Truncates a block of 32-bit lanes to bytes.
+    #[inline(always)]
+    unsafe fn store_epi8 (v: Epi32, ptr: *mut i8) {
+        // Masking keeps the saturating packs from clamping
+        let v = _mm_and_si128(v, _mm_set1_epi32(0xff));
+        let v = _mm_packs_epi32(v, v);
+        let v = _mm_packus_epi16(v, v);
+        ptr.cast::<i32>().write_unaligned(_mm_cvtsi128_si32(v))
+    }
+
+    /// Truncates a block of 32-bit lanes to 16 bits.
+    #[inline(always)]
+    unsafe fn store_epi16 (v: Epi32, ptr: *mut i16) {
+        // Sign-extending the low half keeps the saturating pack from clamping
+        let v = _mm_srai_epi32::<16>(_mm_slli_epi32::<16>(v));
+        _mm_storel_epi64(ptr.cast(), _mm_packs_epi32(v, v))
+    }
+
+    impl Lanes for u32 {
+        const MIN: f64 = u32::MIN as f64;
+        const MAX: f64 = u32::MAX as f64;
+
+        #[inline(always)]
+        unsafe fn load (ptr: *const Self) -> Epi32 {
+            i32::load(ptr.cast())
+        }
+
+        #[inline(always)]
+        unsafe fn store (v: Epi32, ptr: *mut Self) {
+            i32::store(v, ptr.cast())
+        }
+
+        #[inline(always)]
+        unsafe fn to_ps (v: Epi32) -> Ps {
+            // Both halves convert exactly, so the sum is rounded only once
+            let hi = _mm_cvtepi32_ps(_mm_srli_epi32::<16>(v));
+            let lo = _mm_cvtepi32_ps(_mm_and_si128(v, _mm_set1_epi32(0xffff)));
+            return _mm_add_ps(_mm_mul_ps(hi, _mm_set1_ps(65536.0)), lo)
+        }
+
+        #[inline(always)]
+        unsafe fn to_pd (v: Epi32) -> Pd {
+            // Lanes past `i32::MAX` convert as negative numbers, 2^32 below their value
+            return cvtepi32_pd(v).map(|x| {
+                let neg = _mm_cmplt_pd(x, _mm_setzero_pd());
+                _mm_add_pd(x, _mm_and_pd(neg, _mm_set1_pd(4294967296.0)))
+            })
+        }
+
+        #[inline(always)]
+        unsafe fn to_epi64 (v: Epi32) -> Epi64 {
+            return [_mm_unpacklo_epi32(v, _mm_setzero_si128()), _mm_unpackhi_epi32(v, _mm_setzero_si128())]
+        }
+
+        #[inline(always)]
+        unsafe fn from_ps (v: Ps) -> Epi32 {
+            // Lanes past `i32::MAX` are converted 2^31 lower, and get their top bit set back afterwards
+            let v = _mm_max_ps(v, _mm_setzero_ps());
+            let big = _mm_cmpge_ps(v, _mm_set1_ps(2147483648.0));
+            let over = _mm_cmpge_ps(v, _mm_set1_ps(4294967296.0));
+            let res = _mm_cvttps_epi32(_mm_sub_ps(v, _mm_and_ps(big, _mm_set1_ps(2147483648.0))));
+            let res = _mm_xor_si128(res, _mm_and_si128(_mm_castps_si128(big), _mm_set1_epi32(i32::MIN)));
+            return _mm_or_si128(res, _mm_castps_si128(over))
+        }
+
+        #[inline(always)]
+        unsafe fn from_pd (v: Pd) -> Epi32 {
+            // Same as `from_ps`, with the top bits computed as `-2^31` converted on the lanes past `i32::MAX`
+            let big = clamp_pd(v, <Self as Lanes>::MIN, <Self as Lanes>::MAX).map(|x| _mm_and_pd(
+                _mm_cmpge_pd(x, _mm_set1_pd(2147483648.0)),
+                _mm_set1_pd(-2147483648.0)
+            ));
+            let v = clamp_pd(v, <Self as Lanes>::MIN, <Self as Lanes>::MAX);
+            let low = cvttpd_epi32([_mm_add_pd(v[0], big[0]), _mm_add_pd(v[1], big[1])]);
+            return _mm_xor_si128(low, cvttpd_epi32(big))
+        }
+    }
+
+    /* FLOATS */
+    #[inline(always)]
+    pub(super) unsafe fn load_ps (ptr: *const f32) -> Ps {
+        _mm_loadu_ps(ptr)
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn store_ps (v: Ps, ptr: *mut f32) {
+        _mm_storeu_ps(ptr, v)
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn load_pd (ptr: *const f64) -> Pd {
+        [_mm_loadu_pd(ptr), _mm_loadu_pd(ptr.add(2))]
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn store_pd (v: Pd, ptr: *mut f64) {
+        _mm_storeu_pd(ptr, v[0]);
+        _mm_storeu_pd(ptr.add(2), v[1])
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn load_epi64 (ptr: *const i64) -> Epi64 {
+        [_mm_loadu_si128(ptr.cast()), _mm_loadu_si128(ptr.add(2).cast())]
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn store_epi64 (v: Epi64, ptr: *mut i64) {
+        _mm_storeu_si128(ptr.cast(), v[0]);
+        _mm_storeu_si128(ptr.add(2).cast(), v[1])
+    }
+
+    #[inline(always)]
+    unsafe fn cvtepi32_ps (v: Epi32) -> Ps {
+        _mm_cvtepi32_ps(v)
+    }
+
+    #[inline(always)]
+    unsafe fn cvttps_epi32 (v: Ps) -> Epi32 {
+        _mm_cvttps_epi32(v)
+    }
+
+    #[inline(always)]
+    unsafe fn cvtepi32_pd (v: Epi32) -> Pd {
+        [_mm_cvtepi32_pd(v), _mm_cvtepi32_pd(_mm_unpackhi_epi64(v, v))]
+    }
+
+    #[inline(always)]
+    unsafe fn cvttpd_epi32 (v: Pd) -> Epi32 {
+        _mm_unpacklo_epi64(_mm_cvttpd_epi32(v[0]), _mm_cvttpd_epi32(v[1]))
+    }
+
+    #[inline(always)]
+    unsafe fn cvtepi32_epi64 (v: Epi32) -> Epi64 {
+        let sign = _mm_srai_epi32::<31>(v);
+        [_mm_unpacklo_epi32(v, sign), _mm_unpackhi_epi32(v, sign)]
+    }
+
+    /// Truncates a block of 64-bit lanes to 32 bits.
+    #[inline(always)]
+    pub(super) unsafe fn narrow_epi64 (v: Epi64) -> Epi32 {
+        _mm_castps_si128(_mm_shuffle_ps::<0b10_00_10_00>(_mm_castsi128_ps(v[0]), _mm_castsi128_ps(v[1])))
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn cvtps_pd (v: Ps) -> Pd {
+        [_mm_cvtps_pd(v), _mm_cvtps_pd(_mm_movehl_ps(v, v))]
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn cvtpd_ps (v: Pd) -> Ps {
+        _mm_movelh_ps(_mm_cvtpd_ps(v[0]), _mm_cvtpd_ps(v[1]))
+    }
+
+    /// Turns the lanes of `res` where `v` reached 2^31 into `i32::MAX`.
+    #[inline(always)]
+    unsafe fn flip_overflow (res: Epi32, v: Ps) -> Epi32 {
+        _mm_xor_si128(res, _mm_castps_si128(_mm_cmpge_ps(v, _mm_set1_ps(2147483648.0))))
+    }
+
+    /// Clamps `v` between `lo` and `hi`, turning NaN lanes into zeros.
+    #[inline(always)]
+    unsafe fn clamp_ps (v: Ps, lo: f32, hi: f32) -> Ps {
+        let v = _mm_and_ps(v, _mm_cmpord_ps(v, v));
+        _mm_min_ps(_mm_max_ps(v, _mm_set1_ps(lo)), _mm_set1_ps(hi))
+    }
+
+    /// Clamps `v` between `lo` and `hi`, turning NaN lanes into zeros.
+    #[inline(always)]
+    unsafe fn clamp_pd (v: Pd, lo: f64, hi: f64) -> Pd {
+        v.map(|v| {
+            let v = _mm_and_pd(v, _mm_cmpord_pd(v, v));
+            _mm_min_pd(_mm_max_pd(v, _mm_set1_pd(lo)), _mm_set1_pd(hi))
+        })
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use docfg::docfg;
+use super::*;
+
+impl_mul_add! {
+    #[cfg(target_feature = "sse")]
+    f32
+}
+
+impl_mul_add! {
+    #[cfg(target_feature = "sse2")]
+    f64
+}
+
+impl_default! {
+    VerticalMulAdd =>
+    u8, u16, u32, u64,
+    i8, i16, i32, i64
+}
+
+macro_rules! impl_float {
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty: $fmadd:ident $(#[cfg($fma:meta)])? | $mul:ident + $add:ident
+    ) => {
+        #[cfg($meta)]
+        impl MulAdd<$t> for $vec {
+            #[inline(always)]
+            unsafe fn mul_add (self, a: Self, b: Self) -> Self {
+                #[cfg(all($($fma)?))]
+                return $fmadd(self, a, b);
+                #[cfg(not(all($($fma)?)))]
+                return $add($mul(self, a), b);
+            }
+        }
+    };
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse")]
+    f32 as __m128: _mm_fmadd_ps #[cfg(target_feature = "fma")] | _mm_mul_ps + _mm_add_ps
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f32 as __m256: _mm256_fmadd_ps #[cfg(target_feature = "fma")] | _mm256_mul_ps + _mm256_add_ps
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f32 as __m512: _mm512_fmadd_ps | _mm512_mul_ps + _mm512_add_ps
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse2")]
+    f64 as __m128d: _mm_fmadd_pd #[cfg(target_feature = "fma")] | _mm_mul_pd + _mm_add_pd
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f64 as __m256d: _mm256_fmadd_pd #[cfg(target_feature = "fma")] | _mm256_mul_pd + _mm256_add_pd
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f64 as __m512d: _mm512_fmadd_pd | _mm512_mul_pd + _mm512_add_pd
+}
@@ -0,0 +1,183 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::vertical::math::*;
+use crate::vertical::simd::math::*;
+use super::*;
+
+// Shifting the lanes' bits needs SSE2
+impl_math! {
+    #[cfg(target_feature = "sse2")]
+    f32, f64
+}
+
+#[cfg(feature = "half")]
+impl_widen_math! {
+    #[cfg(target_feature = "f16c")]
+    f16
+}
+
+#[cfg(feature = "half")]
+impl_widen_math! {
+    #[cfg(target_feature = "sse2")]
+    bf16
+}
+
+/* REGISTERS */
+macro_rules! impl_lanes {
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty: $mask:ty {
+            fused: $fused:expr,
+            $add:ident, $sub:ident, $mul:ident, $div:ident,
+            $and:ident, $or:ident, $xor:ident,
+            shl: $shl:expr, shr: $shr:expr,
+            lt: $lt:expr, le: $le:expr, eq: $eq:expr, unord: $unord:expr,
+            mask: $mask_and:expr, $mask_or:expr, $mask_andnot:expr,
+            any: $any:expr,
+            select: $select:expr
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Lanes<$t> for $vec {
+            type Mask = $mask;
+            const FUSED: bool = $fused;
+
+            #[inline(always)]
+            unsafe fn add (self, rhs: Self) -> Self { $add(self, rhs) }
+            #[inline(always)]
+            unsafe fn sub (self, rhs: Self) -> Self { $sub(self, rhs) }
+            #[inline(always)]
+            unsafe fn mul (self, rhs: Self) -> Self { $mul(self, rhs) }
+            #[inline(always)]
+            unsafe fn div (self, rhs: Self) -> Self { $div(self, rhs) }
+            #[inline(always)]
+            unsafe fn and (self, rhs: Self) -> Self { $and(self, rhs) }
+            #[inline(always)]
+            unsafe fn or (self, rhs: Self) -> Self { $or(self, rhs) }
+            #[inline(always)]
+            unsafe fn xor (self, rhs: Self) -> Self { $xor(self, rhs) }
+            #[inline(always)]
+            unsafe fn shl (self, count: i32) -> Self { $shl(self, _mm_cvtsi32_si128(count)) }
+            #[inline(always)]
+            unsafe fn shr (self, count: i32) -> Self { $shr(self, _mm_cvtsi32_si128(count)) }
+
+            #[inline(always)]
+            unsafe fn lt (self, rhs: Self) -> $mask { $lt(self, rhs) }
+            #[inline(always)]
+            unsafe fn le (self, rhs: Self) -> $mask { $le(self, rhs) }
+            #[inline(always)]
+            unsafe fn eq (self, rhs: Self) -> $mask { $eq(self, rhs) }
+            #[inline(always)]
+            unsafe fn unord (self, rhs: Self) -> $mask { $unord(self, rhs) }
+
+            #[inline(always)]
+            unsafe fn mask_and (lhs: $mask, rhs: $mask) -> $mask { $mask_and(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn mask_or (lhs: $mask, rhs: $mask) -> $mask { $mask_or(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn mask_andnot (lhs: $mask, rhs: $mask) -> $mask { $mask_andnot(lhs, rhs) }
+            #[inline(always)]
+            unsafe fn any (mask: $mask) -> bool { $any(mask) }
+            #[inline(always)]
+            unsafe fn select (mask: $mask, a: Self, b: Self) -> Self { $select(mask, a, b) }
+        }
+    };
+}
+
+impl_lanes! {
+    #[cfg(target_feature = "sse2")]
+    f32 as __m128: __m128 {
+        fused: cfg!(target_feature = "fma"),
+        _mm_add_ps, _mm_sub_ps, _mm_mul_ps, _mm_div_ps,
+        _mm_and_ps, _mm_or_ps, _mm_xor_ps,
+        shl: |x, n| _mm_castsi128_ps(_mm_sll_epi32(_mm_castps_si128(x), n)),
+        shr: |x, n| _mm_castsi128_ps(_mm_srl_epi32(_mm_castps_si128(x), n)),
+        lt: _mm_cmplt_ps, le: _mm_cmple_ps, eq: _mm_cmpeq_ps, unord: _mm_cmpunord_ps,
+        mask: _mm_and_ps, _mm_or_ps, |x, y| _mm_andnot_ps(y, x),
+        any: |mask| _mm_movemask_ps(mask) != 0,
+        select: |mask, x, y| blendv_ps_128(y, x, mask)
+    }
+}
+
+impl_lanes! {
+    #[cfg(target_feature = "avx")]
+    f32 as __m256: __m256 {
+        fused: cfg!(target_feature = "fma"),
+        _mm256_add_ps, _mm256_sub_ps, _mm256_mul_ps, _mm256_div_ps,
+        _mm256_and_ps, _mm256_or_ps, _mm256_xor_ps,
+        shl: |x, n| _mm256_castsi256_ps(sll_epi32_256(_mm256_castps_si256(x), n)),
+        shr: |x, n| _mm256_castsi256_ps(srl_epi32_256(_mm256_castps_si256(x), n)),
+        lt: _mm256_cmp_ps::<_CMP_LT_OQ>, le: _mm256_cmp_ps::<_CMP_LE_OQ>,
+        eq: _mm256_cmp_ps::<_CMP_EQ_OQ>, unord: _mm256_cmp_ps::<_CMP_UNORD_Q>,
+        mask: _mm256_and_ps, _mm256_or_ps, |x, y| _mm256_andnot_ps(y, x),
+        any: |mask| _mm256_movemask_ps(mask) != 0,
+        select: |mask, x, y| _mm256_blendv_ps(y, x, mask)
+    }
+}
+
+impl_lanes! {
+    #[cfg(target_feature = "avx512f")]
+    f32 as __m512: __mmask16 {
+        fused: true,
+        _mm512_add_ps, _mm512_sub_ps, _mm512_mul_ps, _mm512_div_ps,
+        and_ps_512, or_ps_512, xor_ps_512,
+        shl: |x, n| _mm512_castsi512_ps(_mm512_sll_epi32(_mm512_castps_si512(x), n)),
+        shr: |x, n| _mm512_castsi512_ps(_mm512_srl_epi32(_mm512_castps_si512(x), n)),
+        lt: _mm512_cmp_ps_mask::<_CMP_LT_OQ>, le: _mm512_cmp_ps_mask::<_CMP_LE_OQ>,
+        eq: _mm512_cmp_ps_mask::<_CMP_EQ_OQ>, unord: _mm512_cmp_ps_mask::<_CMP_UNORD_Q>,
+        mask: |x, y| x & y, |x, y| x | y, |x: __mmask16, y: __mmask16| x & !y,
+        any: |mask| mask != 0,
+        select: |mask, x, y| _mm512_mask_blend_ps(mask, y, x)
+    }
+}
+
+impl_lanes! {
+    #[cfg(target_feature = "sse2")]
+    f64 as __m128d: __m128d {
+        fused: cfg!(target_feature = "fma"),
+        _mm_add_pd, _mm_sub_pd, _mm_mul_pd, _mm_div_pd,
+        _mm_and_pd, _mm_or_pd, _mm_xor_pd,
+        shl: |x, n| _mm_castsi128_pd(_mm_sll_epi64(_mm_castpd_si128(x), n)),
+        shr: |x, n| _mm_castsi128_pd(_mm_srl_epi64(_mm_castpd_si128(x), n)),
+        lt: _mm_cmplt_pd, le: _mm_cmple_pd, eq: _mm_cmpeq_pd, unord: _mm_cmpunord_pd,
+        mask: _mm_and_pd, _mm_or_pd, |x, y| _mm_andnot_pd(y, x),
+        any: |mask| _mm_movemask_pd(mask) != 0,
+        select: |mask, x, y| blendv_pd_128(y, x, mask)
+    }
+}
+
+impl_lanes! {
+    #[cfg(target_feature = "avx")]
+    f64 as __m256d: __m256d {
+        fused: cfg!(target_feature = "fma"),
+        _mm256_add_pd, _mm256_sub_pd, _mm256_mul_pd, _mm256_div_pd,
+        _mm256_and_pd, _mm256_or_pd, _mm256_xor_pd,
+        shl: |x, n| _mm256_castsi256_pd(sll_epi64_256(_mm256_castpd_si256(x), n)),
+        shr: |x, n| _mm256_castsi256_pd(srl_epi64_256(_mm256_castpd_si256(x), n)),
+        lt: _mm256_cmp_pd::<_CMP_LT_OQ>, le: _mm256_cmp_pd::<_CMP_LE_OQ>,
+        eq: _mm256_cmp_pd::<_CMP_EQ_OQ>, unord: _mm256_cmp_pd::<_CMP_UNORD_Q>,
+        mask: _mm256_and_pd, _mm256_or_pd, |x, y| _mm256_andnot_pd(y, x),
+        any: |mask| _mm256_movemask_pd(mask) != 0,
+        select: |mask, x, y| _mm256_blendv_pd(y, x, mask)
+    }
+}
+
+impl_lanes! {
+    #[cfg(target_feature = "avx512f")]
+    f64 as __m512d: __mmask8 {
+        fused: true,
+        _mm512_add_pd, _mm512_sub_pd, _mm512_mul_pd, _mm512_div_pd,
+        and_pd_512, or_pd_512, xor_pd_512,
+        shl: |x, n| _mm512_castsi512_pd(_mm512_sll_epi64(_mm512_castpd_si512(x), n)),
+        shr: |x, n| _mm512_castsi512_pd(_mm512_srl_epi64(_mm512_castpd_si512(x), n)),
+        lt: _mm512_cmp_pd_mask::<_CMP_LT_OQ>, le: _mm512_cmp_pd_mask::<_CMP_LE_OQ>,
+        eq: _mm512_cmp_pd_mask::<_CMP_EQ_OQ>, unord: _mm512_cmp_pd_mask::<_CMP_UNORD_Q>,
+        mask: |x, y| x & y, |x, y| x | y, |x: __mmask8, y: __mmask8| x & !y,
+        any: |mask| mask != 0,
+        select: |mask, x, y| _mm512_mask_blend_pd(mask, y, x)
+    }
+}
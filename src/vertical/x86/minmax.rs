@@ -0,0 +1,284 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use docfg::docfg;
+use super::*;
+
+impl_minmax! {
+    #[cfg(target_feature = "sse")]
+    f32
+}
+
+impl_minmax! {
+    #[cfg(target_feature = "sse2")]
+    f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* FLOATS */
+macro_rules! impl_float {
+    (
+        $(#[cfg($meta:meta)])?
+        $t:ty as $vec:ty {
+            $min:ident, $max:ident,
+            $nan:expr,
+            $blend:expr
+        }
+    ) => {
+        $(#[cfg($meta)])?
+        impl MinMax<$t> for $vec {
+            #[inline(always)]
+            unsafe fn min (self, rhs: Self) -> Self {
+                // `$min` returns it's second operand if either of them is NaN
+                let nan = $nan(rhs);
+                $blend($min(self, rhs), self, nan)
+            }
+
+            #[inline(always)]
+            unsafe fn max (self, rhs: Self) -> Self {
+                let nan = $nan(rhs);
+                $blend($max(self, rhs), self, nan)
+            }
+
+            #[inline(always)]
+            unsafe fn clamp (self, lo: Self, hi: Self) -> Self {
+                // Keeping `self` as the second operand lets NaN lanes through
+                $max(lo, $min(hi, self))
+            }
+        }
+    };
+}
+
+impl_float! {
+    f32 as __m128 {
+        _mm_min_ps, _mm_max_ps,
+        |x| _mm_cmpunord_ps(x, x),
+        blendv_ps_128
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f32 as __m256 {
+        _mm256_min_ps, _mm256_max_ps,
+        |x| _mm256_cmp_ps::<_CMP_UNORD_Q>(x, x),
+        _mm256_blendv_ps
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f32 as __m512 {
+        _mm512_min_ps, _mm512_max_ps,
+        |x| _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(x, x),
+        |x, y, mask| _mm512_mask_blend_ps(mask, x, y)
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse2")]
+    f64 as __m128d {
+        _mm_min_pd, _mm_max_pd,
+        |x| _mm_cmpunord_pd(x, x),
+        blendv_pd_128
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f64 as __m256d {
+        _mm256_min_pd, _mm256_max_pd,
+        |x| _mm256_cmp_pd::<_CMP_UNORD_Q>(x, x),
+        _mm256_blendv_pd
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f64 as __m512d {
+        _mm512_min_pd, _mm512_max_pd,
+        |x| _mm512_cmp_pd_mask::<_CMP_UNORD_Q>(x, x),
+        |x, y, mask| _mm512_mask_blend_pd(mask, x, y)
+    }
+}
+
+/* INTEGERS */
+macro_rules! impl_int {
+    (
+        #[cfg($meta:meta)]
+        $($t:ty),+ as $vec:ty {
+            $min:expr, $max:expr
+        }
+    ) => {
+        $(
+            #[cfg($meta)]
+            impl MinMax<$t> for $vec {
+                #[inline(always)]
+                unsafe fn min (self, rhs: Self) -> Self {
+                    $min(self, rhs)
+                }
+
+                #[inline(always)]
+                unsafe fn max (self, rhs: Self) -> Self {
+                    $max(self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u8 as __m128i { _mm_min_epu8, _mm_max_epu8 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i16 as __m128i { _mm_min_epi16, _mm_max_epi16 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i8 as __m128i {
+        |x, y| cmp_min_128(x, y, _mm_cmpgt_epi8(x, y)),
+        |x, y| cmp_max_128(x, y, _mm_cmpgt_epi8(x, y))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u16 as __m128i {
+        |x, y| flip_epi16(_mm_min_epi16(flip_epi16(x), flip_epi16(y))),
+        |x, y| flip_epi16(_mm_max_epi16(flip_epi16(x), flip_epi16(y)))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i32 as __m128i {
+        |x, y| cmp_min_128(x, y, _mm_cmpgt_epi32(x, y)),
+        |x, y| cmp_max_128(x, y, _mm_cmpgt_epi32(x, y))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u32 as __m128i {
+        |x, y| cmp_min_128(x, y, _mm_cmpgt_epi32(flip_epi32(x), flip_epi32(y))),
+        |x, y| cmp_max_128(x, y, _mm_cmpgt_epi32(flip_epi32(x), flip_epi32(y)))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i64 as __m128i {
+        |x, y| cmp_min_128(x, y, cmpgt_epi64_128(x, y)),
+        |x, y| cmp_max_128(x, y, cmpgt_epi64_128(x, y))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    u64 as __m128i {
+        |x, y| cmp_min_128(x, y, cmpgt_epi64_128(flip_epi64(x), flip_epi64(y))),
+        |x, y| cmp_max_128(x, y, cmpgt_epi64_128(flip_epi64(x), flip_epi64(y)))
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i8 as __m256i { _mm256_min_epi8, _mm256_max_epi8 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    u8 as __m256i { _mm256_min_epu8, _mm256_max_epu8 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i16 as __m256i { _mm256_min_epi16, _mm256_max_epi16 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    u16 as __m256i { _mm256_min_epu16, _mm256_max_epu16 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i32 as __m256i { _mm256_min_epi32, _mm256_max_epi32 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    u32 as __m256i { _mm256_min_epu32, _mm256_max_epu32 }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+    i64 as __m256i { _mm256_min_epi64, _mm256_max_epi64 }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+    u64 as __m256i { _mm256_min_epu64, _mm256_max_epu64 }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx2", not(target_feature = "avx512vl")))]
+    i64 as __m256i {
+        |x, y| _mm256_blendv_epi8(x, y, _mm256_cmpgt_epi64(x, y)),
+        |x, y| _mm256_blendv_epi8(y, x, _mm256_cmpgt_epi64(x, y))
+    }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx2", not(target_feature = "avx512vl")))]
+    u64 as __m256i {
+        |x, y| _mm256_blendv_epi8(x, y, _mm256_cmpgt_epi64(flip_epi64_256(x), flip_epi64_256(y))),
+        |x, y| _mm256_blendv_epi8(y, x, _mm256_cmpgt_epi64(flip_epi64_256(x), flip_epi64_256(y)))
+    }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i8 as __m512i { _mm512_min_epi8, _mm512_max_epi8 }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    u8 as __m512i { _mm512_min_epu8, _mm512_max_epu8 }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i16 as __m512i { _mm512_min_epi16, _mm512_max_epi16 }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    u16 as __m512i { _mm512_min_epu16, _mm512_max_epu16 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    i32 as __m512i { _mm512_min_epi32, _mm512_max_epi32 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    u32 as __m512i { _mm512_min_epu32, _mm512_max_epu32 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    i64 as __m512i { _mm512_min_epi64, _mm512_max_epi64 }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    u64 as __m512i { _mm512_min_epu64, _mm512_max_epu64 }
+}
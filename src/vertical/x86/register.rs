@@ -0,0 +1,216 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use super::*;
+
+macro_rules! impl_register {
+    (
+        #[cfg($meta:meta)]
+        $vec:ty: $load:ident & $store:ident {
+            $($t:ty $(as $cast:ty)? => $splat:ident $(| $mask:ty: $load_mask:ident & $store_mask:ident)?),+
+        }
+    ) => {
+        $(
+            #[cfg($meta)]
+            impl Register<$t> for $vec {
+                #[inline(always)]
+                unsafe fn load (ptr: *const $t) -> Self {
+                    $load(ptr.cast())
+                }
+
+                #[inline(always)]
+                unsafe fn store (self, ptr: *mut $t) {
+                    $store(ptr.cast(), self)
+                }
+
+                #[inline(always)]
+                unsafe fn splat (value: $t) -> Self {
+                    $splat(value $(as $cast)?)
+                }
+
+                $(
+                    // Tails are loaded and stored with masks, instead of going through memory
+                    #[inline(always)]
+                    unsafe fn load_partial (ptr: *const $t, len: usize) -> Self {
+                        $load_mask(lane_mask(len) as $mask, ptr.cast())
+                    }
+
+                    #[inline(always)]
+                    unsafe fn store_partial (self, ptr: *mut $t, len: usize) {
+                        $store_mask(ptr.cast(), lane_mask(len) as $mask, self)
+                    }
+                )?
+            }
+        )+
+    };
+}
+
+/// Mask selecting the first `len` lanes
+#[allow(dead_code)]
+#[inline(always)]
+fn lane_mask (len: usize) -> u64 {
+    u64::MAX.checked_shr(64 - len as u32).unwrap_or(0)
+}
+
+macro_rules! impl_vectorize {
+    ($($t:ty),+ => $tiers:tt) => {
+        $(
+            impl_vectorize! { @impl $t => $tiers }
+        )+
+    };
+
+    (
+        @impl $t:ty => {
+            $(#[cfg($meta512:meta)])? $ty512:ty as $isa512:literal,
+            $(#[cfg($meta256:meta)])? $ty256:ty as $isa256:literal,
+            $(#[cfg($meta128:meta)])? $ty128:ty as $isa128:literal
+        }
+    ) => {
+        $(#[cfg($meta128)])?
+        impl Vectorize for $t {
+            cfg_if::cfg_if! {
+                if #[cfg(all($($meta512)?))] {
+                    type Vector = $ty512;
+                    const ISA: &'static str = $isa512;
+                } else if #[cfg(all($($meta256)?))] {
+                    type Vector = $ty256;
+                    const ISA: &'static str = $isa256;
+                } else {
+                    type Vector = $ty128;
+                    const ISA: &'static str = $isa128;
+                }
+            }
+
+            cfg_if::cfg_if! {
+                if #[cfg(all($($meta256)?))] {
+                    type Vector256 = $ty256;
+                    const ISA256: &'static str = $isa256;
+                } else {
+                    type Vector256 = $ty128;
+                    const ISA256: &'static str = $isa128;
+                }
+            }
+
+            type Vector128 = $ty128;
+            const ISA128: &'static str = $isa128;
+        }
+    };
+}
+
+/* FLOATS */
+impl_register! {
+    #[cfg(target_feature = "sse")]
+    __m128: _mm_loadu_ps & _mm_storeu_ps {
+        f32 => _mm_set1_ps
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx")]
+    __m256: _mm256_loadu_ps & _mm256_storeu_ps {
+        f32 => _mm256_set1_ps
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx512f")]
+    __m512: _mm512_loadu_ps & _mm512_storeu_ps {
+        f32 => _mm512_set1_ps | __mmask16: _mm512_maskz_loadu_ps & _mm512_mask_storeu_ps
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "sse2")]
+    __m128d: _mm_loadu_pd & _mm_storeu_pd {
+        f64 => _mm_set1_pd
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx")]
+    __m256d: _mm256_loadu_pd & _mm256_storeu_pd {
+        f64 => _mm256_set1_pd
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx512f")]
+    __m512d: _mm512_loadu_pd & _mm512_storeu_pd {
+        f64 => _mm512_set1_pd | __mmask8: _mm512_maskz_loadu_pd & _mm512_mask_storeu_pd
+    }
+}
+
+/* INTEGERS */
+impl_register! {
+    #[cfg(target_feature = "sse2")]
+    __m128i: _mm_loadu_si128 & _mm_storeu_si128 {
+        i8 => _mm_set1_epi8, u8 as i8 => _mm_set1_epi8,
+        i16 => _mm_set1_epi16, u16 as i16 => _mm_set1_epi16,
+        i32 => _mm_set1_epi32, u32 as i32 => _mm_set1_epi32,
+        i64 => _mm_set1_epi64x, u64 as i64 => _mm_set1_epi64x
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx2")]
+    __m256i: _mm256_loadu_si256 & _mm256_storeu_si256 {
+        i8 => _mm256_set1_epi8, u8 as i8 => _mm256_set1_epi8,
+        i16 => _mm256_set1_epi16, u16 as i16 => _mm256_set1_epi16,
+        i32 => _mm256_set1_epi32, u32 as i32 => _mm256_set1_epi32,
+        i64 => _mm256_set1_epi64x, u64 as i64 => _mm256_set1_epi64x
+    }
+}
+
+// Masked loads and stores of 8 and 16-bit lanes need AVX-512BW, as does everything else on them
+impl_register! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    __m512i: _mm512_loadu_si512 & _mm512_storeu_si512 {
+        i8 => _mm512_set1_epi8 | __mmask64: _mm512_maskz_loadu_epi8 & _mm512_mask_storeu_epi8,
+        u8 as i8 => _mm512_set1_epi8 | __mmask64: _mm512_maskz_loadu_epi8 & _mm512_mask_storeu_epi8,
+        i16 => _mm512_set1_epi16 | __mmask32: _mm512_maskz_loadu_epi16 & _mm512_mask_storeu_epi16,
+        u16 as i16 => _mm512_set1_epi16 | __mmask32: _mm512_maskz_loadu_epi16 & _mm512_mask_storeu_epi16
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx512f")]
+    __m512i: _mm512_loadu_si512 & _mm512_storeu_si512 {
+        i32 => _mm512_set1_epi32 | __mmask16: _mm512_maskz_loadu_epi32 & _mm512_mask_storeu_epi32,
+        u32 as i32 => _mm512_set1_epi32 | __mmask16: _mm512_maskz_loadu_epi32 & _mm512_mask_storeu_epi32,
+        i64 => _mm512_set1_epi64 | __mmask8: _mm512_maskz_loadu_epi64 & _mm512_mask_storeu_epi64,
+        u64 as i64 => _mm512_set1_epi64 | __mmask8: _mm512_maskz_loadu_epi64 & _mm512_mask_storeu_epi64
+    }
+}
+
+impl_vectorize! {
+    f32 => {
+        #[cfg(target_feature = "avx512f")] __m512 as "avx512f",
+        #[cfg(target_feature = "avx")] __m256 as "avx",
+        __m128 as "sse"
+    }
+}
+
+impl_vectorize! {
+    f64 => {
+        #[cfg(target_feature = "avx512f")] __m512d as "avx512f",
+        #[cfg(target_feature = "avx")] __m256d as "avx",
+        #[cfg(target_feature = "sse2")] __m128d as "sse2"
+    }
+}
+
+impl_vectorize! {
+    i8, u8, i16, u16 => {
+        #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))] __m512i as "avx512bw",
+        #[cfg(target_feature = "avx2")] __m256i as "avx2",
+        #[cfg(target_feature = "sse2")] __m128i as "sse2"
+    }
+}
+
+impl_vectorize! {
+    i32, u32, i64, u64 => {
+        #[cfg(target_feature = "avx512f")] __m512i as "avx512f",
+        #[cfg(target_feature = "avx2")] __m256i as "avx2",
+        #[cfg(target_feature = "sse2")] __m128i as "sse2"
+    }
+}
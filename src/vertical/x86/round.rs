@@ -0,0 +1,116 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_round! {
+    #[cfg(target_feature = "sse")]
+    f32
+}
+
+impl_round! {
+    #[cfg(target_feature = "sse2")]
+    f64
+}
+
+macro_rules! impl_float {
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty {
+            $round:ident,
+            $splat:ident, $and:ident, $andnot:ident, $or:ident, $add:ident, $sub:ident,
+            $select_half:expr
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Round<$t> for $vec {
+            #[inline(always)]
+            unsafe fn floor (self) -> Self {
+                $round::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn ceil (self) -> Self {
+                $round::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn trunc (self) -> Self {
+                $round::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn round (self) -> Self {
+                // Step away from the truncated value if we were at least half-way to the next integer.
+                // `self - trunc` is exact, so values just under one half don't get rounded up.
+                let sign = $and(self, $splat(-0.0));
+                let trunc = Round::<$t>::trunc(self);
+                let frac = $andnot($splat(-0.0), $sub(self, trunc));
+                let step = $select_half(frac, $or($splat(1.0), sign));
+                return $or($add(trunc, step), sign);
+            }
+
+            #[inline(always)]
+            unsafe fn round_ties_even (self) -> Self {
+                $round::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(self)
+            }
+        }
+    };
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse")]
+    f32 as __m128 {
+        round_ps_128,
+        _mm_set1_ps, _mm_and_ps, _mm_andnot_ps, _mm_or_ps, _mm_add_ps, _mm_sub_ps,
+        |x, y| _mm_and_ps(_mm_cmpge_ps(x, _mm_set1_ps(0.5)), y)
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f32 as __m256 {
+        _mm256_round_ps,
+        _mm256_set1_ps, _mm256_and_ps, _mm256_andnot_ps, _mm256_or_ps, _mm256_add_ps, _mm256_sub_ps,
+        |x, y| _mm256_and_ps(_mm256_cmp_ps::<_CMP_GE_OQ>(x, _mm256_set1_ps(0.5)), y)
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f32 as __m512 {
+        _mm512_roundscale_ps,
+        _mm512_set1_ps, and_ps_512, andnot_ps_512, or_ps_512, _mm512_add_ps, _mm512_sub_ps,
+        |x, y| _mm512_maskz_mov_ps(_mm512_cmp_ps_mask::<_CMP_GE_OQ>(x, _mm512_set1_ps(0.5)), y)
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse2")]
+    f64 as __m128d {
+        round_pd_128,
+        _mm_set1_pd, _mm_and_pd, _mm_andnot_pd, _mm_or_pd, _mm_add_pd, _mm_sub_pd,
+        |x, y| _mm_and_pd(_mm_cmpge_pd(x, _mm_set1_pd(0.5)), y)
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f64 as __m256d {
+        _mm256_round_pd,
+        _mm256_set1_pd, _mm256_and_pd, _mm256_andnot_pd, _mm256_or_pd, _mm256_add_pd, _mm256_sub_pd,
+        |x, y| _mm256_and_pd(_mm256_cmp_pd::<_CMP_GE_OQ>(x, _mm256_set1_pd(0.5)), y)
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f64 as __m512d {
+        _mm512_roundscale_pd,
+        _mm512_set1_pd, and_pd_512, andnot_pd_512, or_pd_512, _mm512_add_pd, _mm512_sub_pd,
+        |x, y| _mm512_maskz_mov_pd(_mm512_cmp_pd_mask::<_CMP_GE_OQ>(x, _mm512_set1_pd(0.5)), y)
+    }
+}
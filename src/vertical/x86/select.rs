@@ -0,0 +1,160 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use crate::sealed::Selector;
+use super::*;
+
+impl_select! {
+    #[cfg(target_feature = "sse2")]
+    f32, f64,
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/* LANE MASKS */
+/// Expands the low bits of `bits` into the lanes of a register, with every bit of lane `i` set if bit `i` is.
+macro_rules! impl_expand {
+    (
+        #[cfg($meta:meta)]
+        $($name:ident -> $vec:ty: $splat:ident($bits:ty) & $sel:expr => $and:ident & $cmpeq:ident),+
+    ) => {
+        $(
+            #[cfg($meta)]
+            #[inline(always)]
+            unsafe fn $name (bits: u64) -> $vec {
+                let sel = $sel;
+                let v = $splat(bits as $bits);
+                return $cmpeq($and(v, sel), sel)
+            }
+        )+
+    };
+}
+
+// 64-bit lanes are expanded as pairs of 32-bit lanes with the same selector
+impl_expand! {
+    #[cfg(target_feature = "sse2")]
+    expand_epi16_128 -> __m128i: _mm_set1_epi16(i16) & _mm_setr_epi16(1, 2, 4, 8, 16, 32, 64, 128) => _mm_and_si128 & _mm_cmpeq_epi16,
+    expand_epi32_128 -> __m128i: _mm_set1_epi32(i32) & _mm_setr_epi32(1, 2, 4, 8) => _mm_and_si128 & _mm_cmpeq_epi32,
+    expand_epi64_128 -> __m128i: _mm_set1_epi32(i32) & _mm_setr_epi32(1, 1, 2, 2) => _mm_and_si128 & _mm_cmpeq_epi32
+}
+
+impl_expand! {
+    #[cfg(target_feature = "avx2")]
+    expand_epi16_256 -> __m256i: _mm256_set1_epi16(i16) & _mm256_setr_epi16(
+        1, 2, 4, 8, 16, 32, 64, 128,
+        256, 512, 1024, 2048, 4096, 8192, 16384, i16::MIN
+    ) => _mm256_and_si256 & _mm256_cmpeq_epi16,
+    expand_epi32_256 -> __m256i: _mm256_set1_epi32(i32) & _mm256_setr_epi32(1, 2, 4, 8, 16, 32, 64, 128) => _mm256_and_si256 & _mm256_cmpeq_epi32,
+    expand_epi64_256 -> __m256i: _mm256_set1_epi32(i32) & _mm256_setr_epi32(1, 1, 2, 2, 4, 4, 8, 8) => _mm256_and_si256 & _mm256_cmpeq_epi32
+}
+
+/// Bit `i` of every byte, to be matched against a byte of the mask broadcast to 8 lanes.
+const BYTE_SELECTOR: i64 = 0x8040201008040201u64 as i64;
+
+/// Broadcasts byte `i` of `bits` to the 8 bytes of lane `i`.
+#[inline(always)]
+const fn spread_byte (bits: u64, i: u32) -> i64 {
+    return (((bits >> (8 * i)) & 0xff) * 0x0101010101010101) as i64
+}
+
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+unsafe fn expand_epi8_128 (bits: u64) -> __m128i {
+    let sel = _mm_set1_epi64x(BYTE_SELECTOR);
+    let v = _mm_set_epi64x(spread_byte(bits, 1), spread_byte(bits, 0));
+    return _mm_cmpeq_epi8(_mm_and_si128(v, sel), sel)
+}
+
+#[cfg(target_feature = "avx2")]
+#[inline(always)]
+unsafe fn expand_epi8_256 (bits: u64) -> __m256i {
+    let sel = _mm256_set1_epi64x(BYTE_SELECTOR);
+    let v = _mm256_set_epi64x(spread_byte(bits, 3), spread_byte(bits, 2), spread_byte(bits, 1), spread_byte(bits, 0));
+    return _mm256_cmpeq_epi8(_mm256_and_si256(v, sel), sel)
+}
+
+// Without AVX2, 256-bit masks are built from two 128-bit halves
+#[cfg(all(target_feature = "avx", not(target_feature = "avx2")))]
+#[inline(always)]
+unsafe fn expand_epi32_256 (bits: u64) -> __m256i {
+    return _mm256_setr_m128i(expand_epi32_128(bits), expand_epi32_128(bits >> 4))
+}
+
+#[cfg(all(target_feature = "avx", not(target_feature = "avx2")))]
+#[inline(always)]
+unsafe fn expand_epi64_256 (bits: u64) -> __m256i {
+    return _mm256_setr_m128i(expand_epi64_128(bits), expand_epi64_128(bits >> 2))
+}
+
+/* IMPLEMENTATIONS */
+macro_rules! impl_blend {
+    (
+        #[cfg($meta:meta)]
+        $($t:ty as $vec:ty => |$x:ident, $y:ident, $bits:ident| $f:expr),+
+    ) => {
+        $(
+            #[cfg($meta)]
+            impl Blend<$t> for $vec {
+                #[inline(always)]
+                unsafe fn blend (self, rhs: Self, bits: u64) -> Self {
+                    let ($x, $y, $bits) = (self, rhs, bits);
+                    $f
+                }
+            }
+        )+
+    };
+}
+
+impl_blend! {
+    #[cfg(target_feature = "sse2")]
+    f32 as __m128 => |x, y, bits| blendv_ps_128(x, y, _mm_castsi128_ps(expand_epi32_128(bits))),
+    f64 as __m128d => |x, y, bits| blendv_pd_128(x, y, _mm_castsi128_pd(expand_epi64_128(bits))),
+    i8 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi8_128(bits)),
+    u8 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi8_128(bits)),
+    i16 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi16_128(bits)),
+    u16 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi16_128(bits)),
+    i32 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi32_128(bits)),
+    u32 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi32_128(bits)),
+    i64 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi64_128(bits)),
+    u64 as __m128i => |x, y, bits| blendv_epi8_128(x, y, expand_epi64_128(bits))
+}
+
+impl_blend! {
+    #[cfg(target_feature = "avx")]
+    f32 as __m256 => |x, y, bits| _mm256_blendv_ps(x, y, _mm256_castsi256_ps(expand_epi32_256(bits))),
+    f64 as __m256d => |x, y, bits| _mm256_blendv_pd(x, y, _mm256_castsi256_pd(expand_epi64_256(bits)))
+}
+
+impl_blend! {
+    #[cfg(target_feature = "avx2")]
+    i8 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi8_256(bits)),
+    u8 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi8_256(bits)),
+    i16 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi16_256(bits)),
+    u16 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi16_256(bits)),
+    i32 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi32_256(bits)),
+    u32 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi32_256(bits)),
+    i64 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi64_256(bits)),
+    u64 as __m256i => |x, y, bits| _mm256_blendv_epi8(x, y, expand_epi64_256(bits))
+}
+
+// AVX-512 blends straight from masks
+impl_blend! {
+    #[cfg(target_feature = "avx512f")]
+    f32 as __m512 => |x, y, bits| _mm512_mask_blend_ps(bits as __mmask16, x, y),
+    f64 as __m512d => |x, y, bits| _mm512_mask_blend_pd(bits as __mmask8, x, y),
+    i32 as __m512i => |x, y, bits| _mm512_mask_blend_epi32(bits as __mmask16, x, y),
+    u32 as __m512i => |x, y, bits| _mm512_mask_blend_epi32(bits as __mmask16, x, y),
+    i64 as __m512i => |x, y, bits| _mm512_mask_blend_epi64(bits as __mmask8, x, y),
+    u64 as __m512i => |x, y, bits| _mm512_mask_blend_epi64(bits as __mmask8, x, y)
+}
+
+impl_blend! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i8 as __m512i => |x, y, bits| _mm512_mask_blend_epi8(bits, x, y),
+    u8 as __m512i => |x, y, bits| _mm512_mask_blend_epi8(bits, x, y),
+    i16 as __m512i => |x, y, bits| _mm512_mask_blend_epi16(bits as __mmask32, x, y),
+    u16 as __m512i => |x, y, bits| _mm512_mask_blend_epi16(bits as __mmask32, x, y)
+}
@@ -0,0 +1,327 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use docfg::docfg;
+use super::*;
+
+impl_shift! {
+    #[cfg(target_feature = "sse2")]
+    i8, u8, i16, u16,
+    i32, u32, i64, u64
+}
+
+/// Shifts the lanes of `x` by their counts, one bit of the counts at a time.
+///
+/// `shift(x, k)` shifts every lane of `x` by `k`, while `select(x, y, k)` picks `y` on the lanes whose count has bit `k` set.
+#[inline(always)]
+unsafe fn shift_lanes<V: Copy> (mut x: V, bits: u32, shift: impl Fn(V, u32) -> V, select: impl Fn(V, V, u32) -> V) -> V {
+    let mut k = 1;
+    while k < bits {
+        x = select(x, shift(x, k), k);
+        k <<= 1;
+    }
+    return x
+}
+
+/// Turns the logical right shift of a signed value into an arithmetic one, given the sign bit shifted in the same way.
+macro_rules! sign_extend {
+    ($x:expr, $sign:expr => $xor:ident & $sub:ident) => {{
+        let sign = $sign;
+        $sub($xor($x, sign), sign)
+    }};
+}
+
+/* LANE SELECTION */
+macro_rules! impl_select_bit {
+    (
+        #[cfg($meta:meta)]
+        $($name:ident: $vec:ty => $splat:ident($bits:ty), $and:ident & $cmpeq:ident & $blend:ident),+
+    ) => {
+        $(
+            /// Picks `y` on the lanes where `counts` has bit `k` set, and `x` otherwise.
+            #[cfg($meta)]
+            #[inline(always)]
+            unsafe fn $name (x: $vec, y: $vec, counts: $vec, k: u32) -> $vec {
+                let bit = $splat(k as $bits);
+                return $blend(x, y, $cmpeq($and(counts, bit), bit))
+            }
+        )+
+    };
+}
+
+impl_select_bit! {
+    #[cfg(target_feature = "sse2")]
+    select_bit_epi8_128: __m128i => _mm_set1_epi8(i8), _mm_and_si128 & _mm_cmpeq_epi8 & blendv_epi8_128,
+    select_bit_epi16_128: __m128i => _mm_set1_epi16(i16), _mm_and_si128 & _mm_cmpeq_epi16 & blendv_epi8_128,
+    select_bit_epi32_128: __m128i => _mm_set1_epi32(i32), _mm_and_si128 & _mm_cmpeq_epi32 & blendv_epi8_128,
+    select_bit_epi64_128: __m128i => _mm_set1_epi64x(i64), _mm_and_si128 & cmpeq_epi64_128 & blendv_epi8_128
+}
+
+impl_select_bit! {
+    #[cfg(target_feature = "avx2")]
+    select_bit_epi8_256: __m256i => _mm256_set1_epi8(i8), _mm256_and_si256 & _mm256_cmpeq_epi8 & _mm256_blendv_epi8,
+    select_bit_epi16_256: __m256i => _mm256_set1_epi16(i16), _mm256_and_si256 & _mm256_cmpeq_epi16 & _mm256_blendv_epi8
+}
+
+#[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+#[inline(always)]
+unsafe fn select_bit_epi8_512 (x: __m512i, y: __m512i, counts: __m512i, k: u32) -> __m512i {
+    return _mm512_mask_blend_epi8(_mm512_test_epi8_mask(counts, _mm512_set1_epi8(k as i8)), x, y)
+}
+
+/* IMPLEMENTATIONS */
+macro_rules! impl_register {
+    (
+        #[cfg($meta:meta)]
+        $si:ident & $us:ident as $vec:ty {
+            shl: |$shl_x:ident, $shl_n:ident| $shl:expr,
+            srl: |$srl_x:ident, $srl_n:ident| $srl:expr,
+            sra: |$sra_x:ident, $sra_n:ident| $sra:expr,
+            sllv: |$sllv_x:ident, $sllv_n:ident| $sllv:expr,
+            srlv: |$srlv_x:ident, $srlv_n:ident| $srlv:expr,
+            srav: |$srav_x:ident, $srav_n:ident| $srav:expr
+            $(, rolv: $rolv:ident, rorv: $rorv:ident)?
+        }
+    ) => {
+        impl_register! {
+            @impl #[cfg($meta)] $si as $vec {
+                shl: |$shl_x, $shl_n| $shl, srl: |$srl_x, $srl_n| $srl, shr: |$sra_x, $sra_n| $sra,
+                sllv: |$sllv_x, $sllv_n| $sllv, srlv: |$srlv_x, $srlv_n| $srlv, shrv: |$srav_x, $srav_n| $srav
+                $(, rolv: $rolv, rorv: $rorv)?
+            }
+        }
+
+        impl_register! {
+            @impl #[cfg($meta)] $us as $vec {
+                shl: |$shl_x, $shl_n| $shl, srl: |$srl_x, $srl_n| $srl, shr: |$srl_x, $srl_n| $srl,
+                sllv: |$sllv_x, $sllv_n| $sllv, srlv: |$srlv_x, $srlv_n| $srlv, shrv: |$srlv_x, $srlv_n| $srlv
+                $(, rolv: $rolv, rorv: $rorv)?
+            }
+        }
+    };
+
+    (
+        @impl #[cfg($meta:meta)]
+        $t:ty as $vec:ty {
+            shl: |$shl_x:ident, $shl_n:ident| $shl:expr,
+            srl: |$srl_x:ident, $srl_n:ident| $srl:expr,
+            shr: |$shr_x:ident, $shr_n:ident| $shr:expr,
+            sllv: |$sllv_x:ident, $sllv_n:ident| $sllv:expr,
+            srlv: |$srlv_x:ident, $srlv_n:ident| $srlv:expr,
+            shrv: |$shrv_x:ident, $shrv_n:ident| $shrv:expr
+            $(, rolv: $rolv:ident, rorv: $rorv:ident)?
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Shift<$t> for $vec {
+            #[inline(always)]
+            unsafe fn shl (self, n: u32) -> Self {
+                let ($shl_x, $shl_n) = (self, n);
+                $shl
+            }
+
+            #[inline(always)]
+            unsafe fn shr (self, n: u32) -> Self {
+                let ($shr_x, $shr_n) = (self, n);
+                $shr
+            }
+
+            #[inline(always)]
+            unsafe fn shr_logical (self, n: u32) -> Self {
+                let ($srl_x, $srl_n) = (self, n);
+                $srl
+            }
+
+            #[inline(always)]
+            unsafe fn shlv (self, counts: Self) -> Self {
+                let ($sllv_x, $sllv_n) = (self, counts);
+                $sllv
+            }
+
+            #[inline(always)]
+            unsafe fn shrv (self, counts: Self) -> Self {
+                let ($shrv_x, $shrv_n) = (self, counts);
+                $shrv
+            }
+
+            #[inline(always)]
+            unsafe fn shrv_logical (self, counts: Self) -> Self {
+                let ($srlv_x, $srlv_n) = (self, counts);
+                $srlv
+            }
+
+            $(
+                #[inline(always)]
+                unsafe fn rotlv (self, counts: Self) -> Self {
+                    $rolv(self, counts)
+                }
+
+                #[inline(always)]
+                unsafe fn rotrv (self, counts: Self) -> Self {
+                    $rorv(self, counts)
+                }
+
+                #[inline(always)]
+                unsafe fn rotl (self, n: u32) -> Self {
+                    $rolv(self, <Self as Register<$t>>::splat(n as $t))
+                }
+
+                #[inline(always)]
+                unsafe fn rotr (self, n: u32) -> Self {
+                    $rorv(self, <Self as Register<$t>>::splat(n as $t))
+                }
+            )?
+        }
+    };
+}
+
+/* 128-BIT */
+// There are no 8-bit shifts, so we shift 16-bit lanes and clear the bits that crossed over from the neighbouring byte
+impl_register! {
+    #[cfg(target_feature = "sse2")]
+    i8 & u8 as __m128i {
+        shl: |x, n| _mm_and_si128(_mm_sll_epi16(x, _mm_cvtsi32_si128(n as i32)), _mm_set1_epi8((u8::MAX << n) as i8)),
+        srl: |x, n| _mm_and_si128(_mm_srl_epi16(x, _mm_cvtsi32_si128(n as i32)), _mm_set1_epi8((u8::MAX >> n) as i8)),
+        sra: |x, n| sign_extend!(Shift::<u8>::shr_logical(x, n), _mm_set1_epi8((0x80u8 >> n) as i8) => _mm_xor_si128 & _mm_sub_epi8),
+        sllv: |x, n| shift_lanes(x, 8, |x, k| Shift::<u8>::shl(x, k), |x, y, k| select_bit_epi8_128(x, y, n, k)),
+        srlv: |x, n| shift_lanes(x, 8, |x, k| Shift::<u8>::shr_logical(x, k), |x, y, k| select_bit_epi8_128(x, y, n, k)),
+        srav: |x, n| shift_lanes(x, 8, |x, k| Shift::<i8>::shr(x, k), |x, y, k| select_bit_epi8_128(x, y, n, k))
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "sse2")]
+    i16 & u16 as __m128i {
+        shl: |x, n| _mm_sll_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm_srl_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| _mm_sra_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        sllv: |x, n| shift_lanes(x, 16, |x, k| Shift::<u16>::shl(x, k), |x, y, k| select_bit_epi16_128(x, y, n, k)),
+        srlv: |x, n| shift_lanes(x, 16, |x, k| Shift::<u16>::shr_logical(x, k), |x, y, k| select_bit_epi16_128(x, y, n, k)),
+        srav: |x, n| shift_lanes(x, 16, |x, k| Shift::<i16>::shr(x, k), |x, y, k| select_bit_epi16_128(x, y, n, k))
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "sse2")]
+    i32 & u32 as __m128i {
+        shl: |x, n| _mm_sll_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm_srl_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| _mm_sra_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        sllv: |x, n| shift_lanes(x, 32, |x, k| Shift::<u32>::shl(x, k), |x, y, k| select_bit_epi32_128(x, y, n, k)),
+        srlv: |x, n| shift_lanes(x, 32, |x, k| Shift::<u32>::shr_logical(x, k), |x, y, k| select_bit_epi32_128(x, y, n, k)),
+        srav: |x, n| shift_lanes(x, 32, |x, k| Shift::<i32>::shr(x, k), |x, y, k| select_bit_epi32_128(x, y, n, k))
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "sse2")]
+    i64 & u64 as __m128i {
+        shl: |x, n| _mm_sll_epi64(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm_srl_epi64(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| sign_extend!(_mm_srl_epi64(x, _mm_cvtsi32_si128(n as i32)), _mm_set1_epi64x((1u64 << 63 >> n) as i64) => _mm_xor_si128 & _mm_sub_epi64),
+        sllv: |x, n| shift_lanes(x, 64, |x, k| Shift::<u64>::shl(x, k), |x, y, k| select_bit_epi64_128(x, y, n, k)),
+        srlv: |x, n| shift_lanes(x, 64, |x, k| Shift::<u64>::shr_logical(x, k), |x, y, k| select_bit_epi64_128(x, y, n, k)),
+        srav: |x, n| shift_lanes(x, 64, |x, k| Shift::<i64>::shr(x, k), |x, y, k| select_bit_epi64_128(x, y, n, k))
+    }
+}
+
+/* 256-BIT */
+impl_register! {
+    #[cfg(target_feature = "avx2")]
+    i8 & u8 as __m256i {
+        shl: |x, n| _mm256_and_si256(_mm256_sll_epi16(x, _mm_cvtsi32_si128(n as i32)), _mm256_set1_epi8((u8::MAX << n) as i8)),
+        srl: |x, n| _mm256_and_si256(_mm256_srl_epi16(x, _mm_cvtsi32_si128(n as i32)), _mm256_set1_epi8((u8::MAX >> n) as i8)),
+        sra: |x, n| sign_extend!(Shift::<u8>::shr_logical(x, n), _mm256_set1_epi8((0x80u8 >> n) as i8) => _mm256_xor_si256 & _mm256_sub_epi8),
+        sllv: |x, n| shift_lanes(x, 8, |x, k| Shift::<u8>::shl(x, k), |x, y, k| select_bit_epi8_256(x, y, n, k)),
+        srlv: |x, n| shift_lanes(x, 8, |x, k| Shift::<u8>::shr_logical(x, k), |x, y, k| select_bit_epi8_256(x, y, n, k)),
+        srav: |x, n| shift_lanes(x, 8, |x, k| Shift::<i8>::shr(x, k), |x, y, k| select_bit_epi8_256(x, y, n, k))
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx2")]
+    i16 & u16 as __m256i {
+        shl: |x, n| _mm256_sll_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm256_srl_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| _mm256_sra_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        sllv: |x, n| shift_lanes(x, 16, |x, k| Shift::<u16>::shl(x, k), |x, y, k| select_bit_epi16_256(x, y, n, k)),
+        srlv: |x, n| shift_lanes(x, 16, |x, k| Shift::<u16>::shr_logical(x, k), |x, y, k| select_bit_epi16_256(x, y, n, k)),
+        srav: |x, n| shift_lanes(x, 16, |x, k| Shift::<i16>::shr(x, k), |x, y, k| select_bit_epi16_256(x, y, n, k))
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx2")]
+    i32 & u32 as __m256i {
+        shl: |x, n| _mm256_sll_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm256_srl_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| _mm256_sra_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        sllv: |x, n| _mm256_sllv_epi32(x, n),
+        srlv: |x, n| _mm256_srlv_epi32(x, n),
+        srav: |x, n| _mm256_srav_epi32(x, n)
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx2")]
+    i64 & u64 as __m256i {
+        shl: |x, n| _mm256_sll_epi64(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm256_srl_epi64(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| sign_extend!(_mm256_srl_epi64(x, _mm_cvtsi32_si128(n as i32)), _mm256_set1_epi64x((1u64 << 63 >> n) as i64) => _mm256_xor_si256 & _mm256_sub_epi64),
+        sllv: |x, n| _mm256_sllv_epi64(x, n),
+        srlv: |x, n| _mm256_srlv_epi64(x, n),
+        srav: |x, n| sign_extend!(_mm256_srlv_epi64(x, n), _mm256_srlv_epi64(_mm256_set1_epi64x(i64::MIN), n) => _mm256_xor_si256 & _mm256_sub_epi64)
+    }
+}
+
+/* 512-BIT */
+impl_register! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i8 & u8 as __m512i {
+        shl: |x, n| _mm512_and_si512(_mm512_sll_epi16(x, _mm_cvtsi32_si128(n as i32)), _mm512_set1_epi8((u8::MAX << n) as i8)),
+        srl: |x, n| _mm512_and_si512(_mm512_srl_epi16(x, _mm_cvtsi32_si128(n as i32)), _mm512_set1_epi8((u8::MAX >> n) as i8)),
+        sra: |x, n| sign_extend!(Shift::<u8>::shr_logical(x, n), _mm512_set1_epi8((0x80u8 >> n) as i8) => _mm512_xor_si512 & _mm512_sub_epi8),
+        sllv: |x, n| shift_lanes(x, 8, |x, k| Shift::<u8>::shl(x, k), |x, y, k| select_bit_epi8_512(x, y, n, k)),
+        srlv: |x, n| shift_lanes(x, 8, |x, k| Shift::<u8>::shr_logical(x, k), |x, y, k| select_bit_epi8_512(x, y, n, k)),
+        srav: |x, n| shift_lanes(x, 8, |x, k| Shift::<i8>::shr(x, k), |x, y, k| select_bit_epi8_512(x, y, n, k))
+    }
+}
+
+impl_register! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i16 & u16 as __m512i {
+        shl: |x, n| _mm512_sll_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm512_srl_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| _mm512_sra_epi16(x, _mm_cvtsi32_si128(n as i32)),
+        sllv: |x, n| _mm512_sllv_epi16(x, n),
+        srlv: |x, n| _mm512_srlv_epi16(x, n),
+        srav: |x, n| _mm512_srav_epi16(x, n)
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx512f")]
+    i32 & u32 as __m512i {
+        shl: |x, n| _mm512_sll_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm512_srl_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| _mm512_sra_epi32(x, _mm_cvtsi32_si128(n as i32)),
+        sllv: |x, n| _mm512_sllv_epi32(x, n),
+        srlv: |x, n| _mm512_srlv_epi32(x, n),
+        srav: |x, n| _mm512_srav_epi32(x, n),
+        rolv: _mm512_rolv_epi32, rorv: _mm512_rorv_epi32
+    }
+}
+
+impl_register! {
+    #[cfg(target_feature = "avx512f")]
+    i64 & u64 as __m512i {
+        shl: |x, n| _mm512_sll_epi64(x, _mm_cvtsi32_si128(n as i32)),
+        srl: |x, n| _mm512_srl_epi64(x, _mm_cvtsi32_si128(n as i32)),
+        sra: |x, n| _mm512_sra_epi64(x, _mm_cvtsi32_si128(n as i32)),
+        sllv: |x, n| _mm512_sllv_epi64(x, n),
+        srlv: |x, n| _mm512_srlv_epi64(x, n),
+        srav: |x, n| _mm512_srav_epi64(x, n),
+        rolv: _mm512_rolv_epi64, rorv: _mm512_rorv_epi64
+    }
+}
@@ -0,0 +1,442 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use super::*;
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse")]
+    VerticalAbs as abs() => Sign::abs for f32
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse2")]
+    VerticalAbs as abs() => Sign::abs for f64, i8, i16, i32, i64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse")]
+    VerticalNeg as neg() => Sign::neg for f32
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse2")]
+    VerticalNeg as neg() => Sign::neg for f64, i8, i16, i32, i64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "sse")]
+    VerticalSqrt as sqrt() => Unary::sqrt for f32
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "sse2")]
+    VerticalSqrt as sqrt() => Unary::sqrt for f64
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "sse")]
+    VerticalRsqrt as rsqrt(refine: bool) => Unary::rsqrt for f32
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_unary_op! {
+    #[cfg(target_feature = "sse2")]
+    VerticalRsqrt as rsqrt(refine: bool) => Unary::rsqrt for f64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse")]
+    VerticalRecip as recip(refine: bool) => Unary::recip for f32
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse2")]
+    VerticalRecip as recip(refine: bool) => Unary::recip for f64
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse")]
+    VerticalSquare as square() => Unary::square for f32
+}
+
+impl_unary_op! {
+    #[cfg(target_feature = "sse2")]
+    VerticalSquare as square() => Unary::square for f64
+}
+
+/* FLOATS */
+macro_rules! impl_float {
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty {
+            $splat:ident, $andnot:ident, $xor:ident,
+            $sub:ident, $mul:ident, $div:ident, $sqrt:ident,
+            rsqrt: $(#[cfg($rsqrt_meta:meta)])? $rsqrt:ident,
+            rcp: $(#[cfg($rcp_meta:meta)])? $rcp:ident,
+            $special:expr, $any:expr, $blend:expr
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Sign<$t> for $vec {
+            #[inline(always)]
+            unsafe fn abs (self) -> Self {
+                $andnot($splat(-0.0), self)
+            }
+
+            #[inline(always)]
+            unsafe fn neg (self) -> Self {
+                $xor($splat(-0.0), self)
+            }
+        }
+
+        #[cfg($meta)]
+        impl Unary<$t> for $vec {
+            #[inline(always)]
+            unsafe fn sqrt (self) -> Self {
+                $sqrt(self)
+            }
+
+            #[inline(always)]
+            unsafe fn square (self) -> Self {
+                $mul(self, self)
+            }
+
+            #[inline(always)]
+            unsafe fn rsqrt (self, refine: bool) -> Self {
+                #[cfg(not(all($($rsqrt_meta)?)))]
+                {
+                    let _ = refine;
+                    return $div($splat(1.0), $sqrt(self));
+                }
+
+                #[cfg(all($($rsqrt_meta)?))]
+                {
+                    // The estimate treats subnormals as zero, so every lane where it's zero, infinite or NaN gets the exact value
+                    let y = $rsqrt(self);
+                    let special = $special(y);
+                    let y = if $any(special) { $blend(y, $div($splat(1.0), $sqrt(self)), special) } else { y };
+                    if !refine { return y }
+
+                    // y * (1.5 - 0.5 * x * y * y), which would turn the exact values into NaN or infinities
+                    let half = $mul($splat(0.5), self);
+                    let r = $mul(y, $sub($splat(1.5), $mul($mul(half, y), y)));
+                    return $blend(r, y, special);
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn recip (self, refine: bool) -> Self {
+                #[cfg(not(all($($rcp_meta)?)))]
+                {
+                    let _ = refine;
+                    return $div($splat(1.0), self);
+                }
+
+                #[cfg(all($($rcp_meta)?))]
+                {
+                    // The estimate treats subnormals as zero, so every lane where it's zero, infinite or NaN gets the exact value
+                    let y = $rcp(self);
+                    let special = $special(y);
+                    let y = if $any(special) { $blend(y, $div($splat(1.0), self), special) } else { y };
+                    if !refine { return y }
+
+                    // y * (2 - x * y), which would turn the exact values into NaN or infinities
+                    let r = $mul(y, $sub($splat(2.0), $mul(self, y)));
+                    return $blend(r, y, special);
+                }
+            }
+        }
+    };
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse")]
+    f32 as __m128 {
+        _mm_set1_ps, _mm_andnot_ps, _mm_xor_ps,
+        _mm_sub_ps, _mm_mul_ps, _mm_div_ps, _mm_sqrt_ps,
+        rsqrt: _mm_rsqrt_ps,
+        rcp: _mm_rcp_ps,
+        |y| _mm_or_ps(_mm_cmpeq_ps(y, _mm_setzero_ps()), _mm_cmpneq_ps(_mm_mul_ps(y, _mm_setzero_ps()), _mm_setzero_ps())),
+        |mask| _mm_movemask_ps(mask) != 0,
+        blendv_ps_128
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f32 as __m256 {
+        _mm256_set1_ps, _mm256_andnot_ps, _mm256_xor_ps,
+        _mm256_sub_ps, _mm256_mul_ps, _mm256_div_ps, _mm256_sqrt_ps,
+        rsqrt: _mm256_rsqrt_ps,
+        rcp: _mm256_rcp_ps,
+        |y| _mm256_or_ps(
+            _mm256_cmp_ps::<_CMP_EQ_OQ>(y, _mm256_setzero_ps()),
+            _mm256_cmp_ps::<_CMP_NEQ_UQ>(_mm256_mul_ps(y, _mm256_setzero_ps()), _mm256_setzero_ps())
+        ),
+        |mask| _mm256_movemask_ps(mask) != 0,
+        _mm256_blendv_ps
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f32 as __m512 {
+        _mm512_set1_ps, andnot_ps_512, xor_ps_512,
+        _mm512_sub_ps, _mm512_mul_ps, _mm512_div_ps, _mm512_sqrt_ps,
+        rsqrt: _mm512_rsqrt14_ps,
+        rcp: _mm512_rcp14_ps,
+        |y| _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(y, _mm512_setzero_ps())
+            | _mm512_cmp_ps_mask::<_CMP_NEQ_UQ>(_mm512_mul_ps(y, _mm512_setzero_ps()), _mm512_setzero_ps()),
+        |mask| mask != 0,
+        |x, y, mask| _mm512_mask_blend_ps(mask, x, y)
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "sse2")]
+    f64 as __m128d {
+        _mm_set1_pd, _mm_andnot_pd, _mm_xor_pd,
+        _mm_sub_pd, _mm_mul_pd, _mm_div_pd, _mm_sqrt_pd,
+        rsqrt: #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))] _mm_rsqrt14_pd,
+        rcp: #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))] _mm_rcp14_pd,
+        |y| _mm_or_pd(_mm_cmpeq_pd(y, _mm_setzero_pd()), _mm_cmpneq_pd(_mm_mul_pd(y, _mm_setzero_pd()), _mm_setzero_pd())),
+        |mask| _mm_movemask_pd(mask) != 0,
+        blendv_pd_128
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx")]
+    f64 as __m256d {
+        _mm256_set1_pd, _mm256_andnot_pd, _mm256_xor_pd,
+        _mm256_sub_pd, _mm256_mul_pd, _mm256_div_pd, _mm256_sqrt_pd,
+        rsqrt: #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))] _mm256_rsqrt14_pd,
+        rcp: #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))] _mm256_rcp14_pd,
+        |y| _mm256_or_pd(
+            _mm256_cmp_pd::<_CMP_EQ_OQ>(y, _mm256_setzero_pd()),
+            _mm256_cmp_pd::<_CMP_NEQ_UQ>(_mm256_mul_pd(y, _mm256_setzero_pd()), _mm256_setzero_pd())
+        ),
+        |mask| _mm256_movemask_pd(mask) != 0,
+        _mm256_blendv_pd
+    }
+}
+
+impl_float! {
+    #[cfg(target_feature = "avx512f")]
+    f64 as __m512d {
+        _mm512_set1_pd, andnot_pd_512, xor_pd_512,
+        _mm512_sub_pd, _mm512_mul_pd, _mm512_div_pd, _mm512_sqrt_pd,
+        rsqrt: _mm512_rsqrt14_pd,
+        rcp: _mm512_rcp14_pd,
+        |y| _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(y, _mm512_setzero_pd())
+            | _mm512_cmp_pd_mask::<_CMP_NEQ_UQ>(_mm512_mul_pd(y, _mm512_setzero_pd()), _mm512_setzero_pd()),
+        |mask| mask != 0,
+        |x, y, mask| _mm512_mask_blend_pd(mask, x, y)
+    }
+}
+
+/* INTEGERS */
+macro_rules! impl_int {
+    (
+        #[cfg($meta:meta)]
+        $t:ty as $vec:ty {
+            $abs:expr, $neg:expr
+        }
+    ) => {
+        #[cfg($meta)]
+        impl Sign<$t> for $vec {
+            #[inline(always)]
+            unsafe fn abs (self) -> Self {
+                $abs(self)
+            }
+
+            #[inline(always)]
+            unsafe fn neg (self) -> Self {
+                $neg(self)
+            }
+        }
+    };
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i8 as __m128i {
+        |x| abs_128(x, _mm_cmpgt_epi8(_mm_setzero_si128(), x), _mm_abs_epi8, _mm_sub_epi8),
+        |x| _mm_sub_epi8(_mm_setzero_si128(), x)
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i16 as __m128i {
+        |x| abs_128(x, _mm_srai_epi16(x, 15), _mm_abs_epi16, _mm_sub_epi16),
+        |x| _mm_sub_epi16(_mm_setzero_si128(), x)
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i32 as __m128i {
+        |x| abs_128(x, _mm_srai_epi32(x, 31), _mm_abs_epi32, _mm_sub_epi32),
+        |x| _mm_sub_epi32(_mm_setzero_si128(), x)
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "sse2")]
+    i64 as __m128i {
+        |x| {
+            #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+            return _mm_abs_epi64(x);
+            #[cfg(not(all(target_feature = "avx512f", target_feature = "avx512vl")))]
+            {
+                // Broadcast the sign of the high halves: [3, 3, 1, 1]
+                let sign = _mm_shuffle_epi32(_mm_srai_epi32(x, 31), 0b11_11_01_01);
+                return _mm_sub_epi64(_mm_xor_si128(x, sign), sign);
+            }
+        },
+        |x| _mm_sub_epi64(_mm_setzero_si128(), x)
+    }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i8 as __m256i { _mm256_abs_epi8, |x| _mm256_sub_epi8(_mm256_setzero_si256(), x) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i16 as __m256i { _mm256_abs_epi16, |x| _mm256_sub_epi16(_mm256_setzero_si256(), x) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i32 as __m256i { _mm256_abs_epi32, |x| _mm256_sub_epi32(_mm256_setzero_si256(), x) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx2")]
+    i64 as __m256i {
+        |x| {
+            #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+            return _mm256_abs_epi64(x);
+            #[cfg(not(all(target_feature = "avx512f", target_feature = "avx512vl")))]
+            {
+                let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), x);
+                return _mm256_sub_epi64(_mm256_xor_si256(x, sign), sign);
+            }
+        },
+        |x| _mm256_sub_epi64(_mm256_setzero_si256(), x)
+    }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i8 as __m512i { _mm512_abs_epi8, |x| _mm512_sub_epi8(_mm512_setzero_si512(), x) }
+}
+
+impl_int! {
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512bw"))]
+    i16 as __m512i { _mm512_abs_epi16, |x| _mm512_sub_epi16(_mm512_setzero_si512(), x) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    i32 as __m512i { _mm512_abs_epi32, |x| _mm512_sub_epi32(_mm512_setzero_si512(), x) }
+}
+
+impl_int! {
+    #[cfg(target_feature = "avx512f")]
+    i64 as __m512i { _mm512_abs_epi64, |x| _mm512_sub_epi64(_mm512_setzero_si512(), x) }
+}
+
+/// Absolute value through `abs` on SSSE3, or through the lanes' sign mask otherwise
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+unsafe fn abs_128 (
+    x: __m128i,
+    #[allow(unused_variables)] sign: __m128i,
+    #[allow(unused_variables)] abs: unsafe fn(__m128i) -> __m128i,
+    #[allow(unused_variables)] sub: unsafe fn(__m128i, __m128i) -> __m128i
+) -> __m128i {
+    #[cfg(target_feature = "ssse3")]
+    return abs(x);
+    #[cfg(not(target_feature = "ssse3"))]
+    return sub(_mm_xor_si128(x, sign), sign);
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::fmt::Debug;
+    use num_traits::Float;
+    use crate::*;
+    use crate::width::for_each_width;
+
+    const UNREFINED: f64 = 1.5 / 4096.0;
+    const REFINED: f64 = 1.0 / 2097152.0;
+
+    /// Checks `op` against `exact` on `values` and on every short prefix of it, to cover the tails of every width
+    fn check<T: Float + Debug> (name: &str, values: &[T], tolerance: f64, op: impl Fn(&mut [T]), exact: impl Fn(T) -> T) {
+        let lens = (0..=70).chain([values.len()]);
+        for len in lens {
+            let mut result = values[..len].to_vec();
+            op(&mut result);
+
+            for (&x, &y) in values.iter().zip(&result) {
+                let (y, expected) = (y.to_f64().unwrap(), exact(x).to_f64().unwrap());
+                // Results in the subnormal range are only checked against the smallest normal value
+                let scale = expected.abs().max(T::min_positive_value().to_f64().unwrap());
+                let close = expected.is_finite() && (y - expected).abs() <= tolerance * scale;
+                assert!(
+                    y == expected || close || (y.is_nan() && expected.is_nan()),
+                    "{name}({x:?}) = {y:?}, expected {expected:?}"
+                );
+            }
+        }
+    }
+
+    fn inputs<T: Float> (from_bits: impl Fn(u64) -> T) -> Vec<T> {
+        let specials = [
+            T::zero(), -T::zero(), T::infinity(), T::neg_infinity(), T::nan(), T::one(), -T::one(),
+            T::min_positive_value(), -T::min_positive_value(), T::max_value(), T::min_value()
+        ];
+
+        // Every exponent, every sign and a few subnormals
+        let mut values = specials.to_vec();
+        values.extend((0..4096u64).map(|i| from_bits(i.wrapping_mul(0x9E37_79B9_7F4A_7C15))));
+        values.extend((1..64u64).map(|i| T::min_positive_value() * T::from(i).unwrap() / T::from(64).unwrap()));
+        return values
+    }
+
+    #[test]
+    fn recip () {
+        let single = inputs(|bits| f32::from_bits((bits >> 32) as u32));
+        let double = inputs(f64::from_bits);
+
+        for_each_width(|_| {
+            for (refine, tolerance) in [(false, UNREFINED), (true, REFINED)] {
+                check("recip", &single, tolerance, |x| x.recip_assign(refine), |x| 1.0 / x);
+                check("recip", &double, tolerance, |x| x.recip_assign(refine), |x| 1.0 / x);
+            }
+        })
+    }
+
+    #[test]
+    fn rsqrt () {
+        let single = inputs(|bits| f32::from_bits((bits >> 32) as u32));
+        let double = inputs(f64::from_bits);
+
+        for_each_width(|_| {
+            for (refine, tolerance) in [(false, UNREFINED), (true, REFINED)] {
+                check("rsqrt", &single, tolerance, |x| x.rsqrt_assign(refine), |x| 1.0 / x.sqrt());
+                check("rsqrt", &double, tolerance, |x| x.rsqrt_assign(refine), |x| 1.0 / x.sqrt());
+            }
+        })
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use docfg::docfg;
+use half::{f16, bf16};
+use crate::sealed::Selector;
+use super::*;
+
+impl_widen! {
+    #[cfg(target_feature = "f16c")]
+    f16
+}
+
+impl_widen! {
+    #[cfg(target_feature = "sse2")]
+    bf16
+}
+
+impl VerticalConvert<bf16> for [f16] {}
+impl VerticalConvert<f16> for [bf16] {}
+
+// Half-precision floats don't have their own comparisons yet
+impl_default! {
+    VerticalCmp =>
+    f16, bf16
+}
+
+impl_sign! {
+    #[cfg(target_feature = "sse2")]
+    f16, bf16
+}
+
+impl_widen_select! {
+    f16, bf16
+}
+
+/* F16 */
+#[cfg(target_feature = "f16c")]
+impl Widen<f16> for __m128 {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const f16) -> Self {
+        _mm_cvtph_ps(_mm_loadl_epi64(ptr.cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut f16) {
+        _mm_storel_epi64(ptr.cast(), _mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(self))
+    }
+}
+
+#[cfg(target_feature = "f16c")]
+impl Widen<f16> for __m256 {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const f16) -> Self {
+        _mm256_cvtph_ps(_mm_loadu_si128(ptr.cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut f16) {
+        _mm_storeu_si128(ptr.cast(), _mm256_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(self))
+    }
+}
+
+#[cfg(target_feature = "avx512f")]
+impl Widen<f16> for __m512 {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const f16) -> Self {
+        _mm512_cvtph_ps(_mm256_loadu_si256(ptr.cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut f16) {
+        _mm256_storeu_si256(ptr.cast(), _mm512_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(self))
+    }
+}
+
+/* BF16 */
+// A `bf16` is the top half of an `f32`
+#[cfg(target_feature = "sse2")]
+impl Widen<bf16> for __m128 {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const bf16) -> Self {
+        _mm_castsi128_ps(_mm_unpacklo_epi16(_mm_setzero_si128(), _mm_loadl_epi64(ptr.cast())))
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut bf16) {
+        let v = bf16_bits(self);
+        _mm_storel_epi64(ptr.cast(), _mm_packs_epi32(v, v))
+    }
+}
+
+#[cfg(target_feature = "avx")]
+impl Widen<bf16> for __m256 {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const bf16) -> Self {
+        let v = _mm_loadu_si128(ptr.cast());
+        let zero = _mm_setzero_si128();
+        _mm256_castsi256_ps(_mm256_setr_m128i(_mm_unpacklo_epi16(zero, v), _mm_unpackhi_epi16(zero, v)))
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut bf16) {
+        let lo = bf16_bits(_mm256_castps256_ps128(self));
+        let hi = bf16_bits(_mm256_extractf128_ps::<1>(self));
+        _mm_storeu_si128(ptr.cast(), _mm_packs_epi32(lo, hi))
+    }
+}
+
+#[cfg(target_feature = "avx512f")]
+impl Widen<bf16> for __m512 {
+    #[inline(always)]
+    unsafe fn load_wide (ptr: *const bf16) -> Self {
+        _mm512_castsi512_ps(_mm512_slli_epi32::<16>(_mm512_cvtepu16_epi32(_mm256_loadu_si256(ptr.cast()))))
+    }
+
+    #[inline(always)]
+    unsafe fn store_narrow (self, ptr: *mut bf16) {
+        // Same as `bf16_bits`
+        let x = _mm512_castps_si512(self);
+        let lsb = _mm512_and_si512(_mm512_srli_epi32::<16>(x), _mm512_set1_epi32(1));
+        let rounded = _mm512_add_epi32(_mm512_add_epi32(x, _mm512_set1_epi32(0x7fff)), lsb);
+        let nan = _mm512_cmpgt_epi32_mask(_mm512_and_si512(x, _mm512_set1_epi32(0x7fffffff)), _mm512_set1_epi32(0x7f800000));
+        let x = _mm512_mask_blend_epi32(nan, rounded, _mm512_or_si512(x, _mm512_set1_epi32(0x00400000)));
+        _mm256_storeu_si256(ptr.cast(), _mm512_cvtepi32_epi16(_mm512_srli_epi32::<16>(x)))
+    }
+}
+
+/// Rounds `f32` lanes to the nearest `bf16`, returning its bits sign-extended to 32 bits (so that they can be packed).
+#[cfg(target_feature = "sse2")]
+#[inline(always)]
+unsafe fn bf16_bits (v: __m128) -> __m128i {
+    let x = _mm_castps_si128(v);
+
+    // Adding just under half an ulp (plus one if the truncated result is odd) rounds ties to even
+    let lsb = _mm_and_si128(_mm_srli_epi32::<16>(x), _mm_set1_epi32(1));
+    let rounded = _mm_add_epi32(_mm_add_epi32(x, _mm_set1_epi32(0x7fff)), lsb);
+
+    // NaNs are made quiet instead, so that rounding can't carry them into infinities
+    let nan = _mm_cmpgt_epi32(_mm_and_si128(x, _mm_set1_epi32(0x7fffffff)), _mm_set1_epi32(0x7f800000));
+    let quiet = _mm_or_si128(x, _mm_set1_epi32(0x00400000));
+    let x = _mm_or_si128(_mm_and_si128(nan, quiet), _mm_andnot_si128(nan, rounded));
+    _mm_srai_epi32::<16>(x)
+}
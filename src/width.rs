@@ -0,0 +1,187 @@
+//! Runtime cap on the width of the registers the kernels run on.
+//!
+//! Kernels are compiled for the widest registers enabled at build time, but they can be told to stick to narrower
+//! ones at runtime, either with [`set_max_width`] or through the `SLICESIMD_MAX_WIDTH` environment variable, which
+//! is read (with the `std` feature) the first time the cap is needed. This keeps AVX-512 builds from slowing down on
+//! CPUs that lower their clock speed on 512-bit instructions, and lets a single build exercise every path.
+//!
+//! - Caps under 128 bits fall back to the scalar implementations.
+//! - On x86, caps of 128 and 256 bits run the vertical operations, [`reduce_add`](crate::HorizontalSlice::reduce_add)
+//!   and [expressions](crate::expr) on SSE and AVX registers respectively. Conversions never go past AVX2, so they
+//!   only drop down to SSE under a cap of 128 bits.
+//! - NEON and WebAssembly only have 128-bit registers, so any cap of at least 128 bits leaves them untouched.
+//! - The portable backend always runs on 256-bit vectors, which LLVM splits into the registers the target has,
+//!   under any cap of at least 128 bits.
+//!
+//! # Example
+//! ```rust
+//! use slicesimd::*;
+//!
+//! width::set_max_width(Some(0));
+//! assert!(kernels().all(|k| !k.is_simd()));
+//!
+//! let mut values = [1, 2, 3];
+//! values.add_assign(&[1, 2, 3]);
+//! assert_eq!(values, [2, 4, 6]);
+//!
+//! width::set_max_width(None);
+//! assert_eq!(width::max_width(), None);
+//! ```
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[allow(unused_imports)]
+use crate::vertical::simd::{Vector, Vectorize};
+
+/// Environment variable the cap is read from, in bits.
+pub const ENV_VAR: &str = "SLICESIMD_MAX_WIDTH";
+
+/// The environment hasn't been read yet.
+const UNSET: usize = usize::MAX;
+const UNCAPPED: usize = usize::MAX - 1;
+
+static MAX_WIDTH: AtomicUsize = AtomicUsize::new(UNSET);
+
+/// Caps the width, in bits, of the registers used from now on, or lifts the cap with `None`.
+///
+/// Kernels pick the widest register that fits in the cap, with anything under 128 bits meaning scalar code.
+/// This overrides the `SLICESIMD_MAX_WIDTH` environment variable.
+#[inline]
+pub fn set_max_width (bits: Option<usize>) {
+    let bits = match bits {
+        Some(bits) => bits.min(UNCAPPED - 1),
+        None => UNCAPPED
+    };
+    MAX_WIDTH.store(bits, Ordering::Relaxed)
+}
+
+/// Returns the current cap on the width of the registers, in bits, or `None` if there isn't any.
+#[inline]
+pub fn max_width () -> Option<usize> {
+    return match cap() {
+        UNCAPPED => None,
+        bits => Some(bits)
+    }
+}
+
+#[inline]
+fn cap () -> usize {
+    let bits = MAX_WIDTH.load(Ordering::Relaxed);
+    if bits != UNSET {
+        return bits
+    }
+
+    // A concurrent call to `set_max_width` takes precedence over the environment
+    let bits = from_env();
+    return match MAX_WIDTH.compare_exchange(UNSET, bits, Ordering::Relaxed, Ordering::Relaxed) {
+        Ok(_) => bits,
+        Err(current) => current
+    }
+}
+
+/// Reads the cap from [`ENV_VAR`], ignoring invalid values.
+#[cfg(feature = "std")]
+fn from_env () -> usize {
+    return match std::env::var(ENV_VAR).ok().and_then(|bits| bits.trim().parse::<usize>().ok()) {
+        Some(bits) => bits.min(UNCAPPED - 1),
+        None => UNCAPPED
+    }
+}
+
+#[cfg(not(feature = "std"))]
+fn from_env () -> usize {
+    return UNCAPPED
+}
+
+/// Checks if the cap rules out every register, leaving only the scalar implementations.
+#[doc(hidden)]
+#[inline]
+pub fn is_scalar () -> bool {
+    return cap() < 128
+}
+
+/// Registers a kernel can run on, below the scalar cap.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Tier {
+    /// The widest register picked by [`Vectorize`].
+    Full,
+    /// [`Vectorize::Vector256`].
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx512f"))]
+    W256,
+    /// [`Vectorize::Vector128`].
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx"))]
+    W128
+}
+
+/// Returns the widest register for `T` allowed by the cap.
+#[allow(dead_code)]
+#[inline]
+pub(crate) fn tier<T: Vectorize> () -> Tier {
+    let cap = cap();
+    if cap >= 8 * core::mem::size_of::<Vector<T>>() {
+        return Tier::Full
+    }
+
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx512f"))]
+    if cap >= 256 {
+        return Tier::W256
+    }
+
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "portable"), target_feature = "avx"))]
+    if cap >= 128 {
+        return Tier::W128
+    }
+
+    return Tier::Full
+}
+
+/// Runs `f` under every cap that picks a different set of kernels, restoring the previous cap afterwards.
+///
+/// The cap is global, so tests that set it take turns.
+#[cfg(all(test, feature = "std"))]
+pub(crate) fn for_each_width (mut f: impl FnMut(Option<usize>)) {
+    static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let previous = max_width();
+    for bits in [None, Some(256), Some(128), Some(0)] {
+        set_max_width(bits);
+        f(bits);
+    }
+    set_max_width(previous)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::*;
+    use super::for_each_width;
+
+    #[test]
+    fn kernels_follow_the_cap () {
+        for_each_width(|bits| {
+            for kernel in kernels() {
+                match bits {
+                    Some(bits) if bits < 128 => assert!(!kernel.is_simd(), "{kernel} under a {bits}-bit cap"),
+                    // The portable vectors have a single width
+                    Some(bits) if !cfg!(feature = "portable") => assert!(kernel.width <= bits, "{kernel} under a {bits}-bit cap"),
+                    _ => {}
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn expressions_follow_the_cap () {
+        for_each_width(|bits| {
+            for len in 0..=70 {
+                let a: Vec<f64> = (0..len).map(|i| i as f64 / 3.0).collect();
+                let b: Vec<f64> = (0..len).map(|i| 1.0 - i as f64 / 7.0).collect();
+
+                let mut out = vec![core::mem::MaybeUninit::uninit(); len];
+                (-(expr(&a) * &b + 2.0) / (expr(&b) - 0.5)).eval_into(&mut out);
+                let expected = a.iter().zip(&b).map(|(&a, &b)| -(a * b + 2.0) / (b - 0.5));
+                assert!(out.iter().map(|x| unsafe { x.assume_init() }).eq(expected), "{len} elements under {bits:?}");
+            }
+        })
+    }
+}